@@ -0,0 +1,138 @@
+#[repr(C)]
+#[doc = "Register block"]
+pub struct RegisterBlock {
+    cdmacr: Cdmacr,
+    cdmasr: Cdmasr,
+    curdesc: Curdesc,
+    curdesc_msb: CurdescMsb,
+    taildesc: Taildesc,
+    taildesc_msb: TaildescMsb,
+    sa: Sa,
+    sa_msb: SaMsb,
+    da: Da,
+    da_msb: DaMsb,
+    btt: Btt,
+}
+impl RegisterBlock {
+    #[doc = "0x00 - CDMA Control register"]
+    #[inline(always)]
+    pub const fn cdmacr(&self) -> &Cdmacr {
+        &self.cdmacr
+    }
+    #[doc = "0x04 - CDMA Status register"]
+    #[inline(always)]
+    pub const fn cdmasr(&self) -> &Cdmasr {
+        &self.cdmasr
+    }
+    #[doc = "0x08 - Current Descriptor Pointer. Lower 32 bits of the address."]
+    #[inline(always)]
+    pub const fn curdesc(&self) -> &Curdesc {
+        &self.curdesc
+    }
+    #[doc = "0x0c - Current Descriptor Pointer. Upper 32 bits of the address."]
+    #[inline(always)]
+    pub const fn curdesc_msb(&self) -> &CurdescMsb {
+        &self.curdesc_msb
+    }
+    #[doc = "0x10 - Tail Descriptor Pointer. Lower 32 bits of the address."]
+    #[inline(always)]
+    pub const fn taildesc(&self) -> &Taildesc {
+        &self.taildesc
+    }
+    #[doc = "0x14 - Tail Descriptor Pointer. Upper 32 bits of the address."]
+    #[inline(always)]
+    pub const fn taildesc_msb(&self) -> &TaildescMsb {
+        &self.taildesc_msb
+    }
+    #[doc = "0x18 - Source Address. Lower 32 bits of the address."]
+    #[inline(always)]
+    pub const fn sa(&self) -> &Sa {
+        &self.sa
+    }
+    #[doc = "0x1c - Source Address. Upper 32 bits of the address."]
+    #[inline(always)]
+    pub const fn sa_msb(&self) -> &SaMsb {
+        &self.sa_msb
+    }
+    #[doc = "0x20 - Destination Address. Lower 32 bits of the address."]
+    #[inline(always)]
+    pub const fn da(&self) -> &Da {
+        &self.da
+    }
+    #[doc = "0x24 - Destination Address. Upper 32 bits of the address."]
+    #[inline(always)]
+    pub const fn da_msb(&self) -> &DaMsb {
+        &self.da_msb
+    }
+    #[doc = "0x28 - Bytes to Transfer. Writing it starts the simple DMA transfer."]
+    #[inline(always)]
+    pub const fn btt(&self) -> &Btt {
+        &self.btt
+    }
+}
+#[doc = "cdmacr (rw) register accessor: CDMA Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`cdmacr::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`cdmacr::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@cdmacr`]
+module"]
+#[doc(alias = "cdmacr")]
+pub type Cdmacr = crate::Reg<cdmacr::CdmacrSpec>;
+#[doc = "CDMA Control register"]
+pub mod cdmacr;
+#[doc = "cdmasr (rw) register accessor: CDMA Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`cdmasr::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`cdmasr::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@cdmasr`]
+module"]
+#[doc(alias = "cdmasr")]
+pub type Cdmasr = crate::Reg<cdmasr::CdmasrSpec>;
+#[doc = "CDMA Status register"]
+pub mod cdmasr;
+#[doc = "curdesc (rw) register accessor: Current Descriptor Pointer. Lower 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`curdesc::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`curdesc::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@curdesc`]
+module"]
+#[doc(alias = "curdesc")]
+pub type Curdesc = crate::Reg<curdesc::CurdescSpec>;
+#[doc = "Current Descriptor Pointer. Lower 32 bits of the address."]
+pub mod curdesc;
+#[doc = "curdesc_msb (rw) register accessor: Current Descriptor Pointer. Upper 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`curdesc_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`curdesc_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@curdesc_msb`]
+module"]
+#[doc(alias = "curdesc_msb")]
+pub type CurdescMsb = crate::Reg<curdesc_msb::CurdescMsbSpec>;
+#[doc = "Current Descriptor Pointer. Upper 32 bits of the address."]
+pub mod curdesc_msb;
+#[doc = "taildesc (rw) register accessor: Tail Descriptor Pointer. Lower 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`taildesc::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`taildesc::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@taildesc`]
+module"]
+#[doc(alias = "taildesc")]
+pub type Taildesc = crate::Reg<taildesc::TaildescSpec>;
+#[doc = "Tail Descriptor Pointer. Lower 32 bits of the address."]
+pub mod taildesc;
+#[doc = "taildesc_msb (rw) register accessor: Tail Descriptor Pointer. Upper 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`taildesc_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`taildesc_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@taildesc_msb`]
+module"]
+#[doc(alias = "taildesc_msb")]
+pub type TaildescMsb = crate::Reg<taildesc_msb::TaildescMsbSpec>;
+#[doc = "Tail Descriptor Pointer. Upper 32 bits of the address."]
+pub mod taildesc_msb;
+#[doc = "sa (rw) register accessor: Source Address. Lower 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sa::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sa::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@sa`]
+module"]
+#[doc(alias = "sa")]
+pub type Sa = crate::Reg<sa::SaSpec>;
+#[doc = "Source Address. Lower 32 bits of the address."]
+pub mod sa;
+#[doc = "sa_msb (rw) register accessor: Source Address. Upper 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sa_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sa_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@sa_msb`]
+module"]
+#[doc(alias = "sa_msb")]
+pub type SaMsb = crate::Reg<sa_msb::SaMsbSpec>;
+#[doc = "Source Address. Upper 32 bits of the address."]
+pub mod sa_msb;
+#[doc = "da (rw) register accessor: Destination Address. Lower 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`da::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`da::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@da`]
+module"]
+#[doc(alias = "da")]
+pub type Da = crate::Reg<da::DaSpec>;
+#[doc = "Destination Address. Lower 32 bits of the address."]
+pub mod da;
+#[doc = "da_msb (rw) register accessor: Destination Address. Upper 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`da_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`da_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@da_msb`]
+module"]
+#[doc(alias = "da_msb")]
+pub type DaMsb = crate::Reg<da_msb::DaMsbSpec>;
+#[doc = "Destination Address. Upper 32 bits of the address."]
+pub mod da_msb;
+#[doc = "btt (rw) register accessor: Bytes to Transfer. Writing it starts the simple DMA transfer.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`btt::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`btt::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@btt`]
+module"]
+#[doc(alias = "btt")]
+pub type Btt = crate::Reg<btt::BttSpec>;
+#[doc = "Bytes to Transfer. Writing it starts the simple DMA transfer."]
+pub mod btt;
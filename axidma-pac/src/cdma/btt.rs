@@ -0,0 +1,40 @@
+#[doc = "Register `btt` reader"]
+pub type R = crate::R<BttSpec>;
+#[doc = "Register `btt` writer"]
+pub type W = crate::W<BttSpec>;
+#[doc = "Field `btt` reader - Indicates the number of bytes to transfer."]
+pub type BttR = crate::FieldReader<u32>;
+#[doc = "Field `btt` writer - Indicates the number of bytes to transfer."]
+pub type BttW<'a, REG> = crate::FieldWriter<'a, REG, 26, u32>;
+impl R {
+    #[doc = "Bits 0:25 - Indicates the number of bytes to transfer."]
+    #[inline(always)]
+    pub fn btt(&self) -> BttR {
+        BttR::new(self.bits & 0x03ff_ffff)
+    }
+}
+impl W {
+    #[doc = "Bits 0:25 - Indicates the number of bytes to transfer."]
+    #[inline(always)]
+    #[must_use]
+    pub fn btt(&mut self) -> BttW<BttSpec> {
+        BttW::new(self, 0)
+    }
+}
+#[doc = "Bytes to Transfer. Writing it starts the simple DMA transfer.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`btt::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`btt::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct BttSpec;
+impl crate::RegisterSpec for BttSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`btt::R`](R) reader structure"]
+impl crate::Readable for BttSpec {}
+#[doc = "`write(|w| ..)` method takes [`btt::W`](W) writer structure"]
+impl crate::Writable for BttSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets btt to value 0"]
+impl crate::Resettable for BttSpec {
+    const RESET_VALUE: u32 = 0;
+}
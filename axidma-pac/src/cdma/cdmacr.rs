@@ -0,0 +1,175 @@
+#[doc = "Register `cdmacr` reader"]
+pub type R = crate::R<CdmacrSpec>;
+#[doc = "Register `cdmacr` writer"]
+pub type W = crate::W<CdmacrSpec>;
+#[doc = "Field `reset` reader - Soft reset for resetting the AXI CDMA core"]
+pub type ResetR = crate::BitReader;
+#[doc = "Field `reset` writer - Soft reset for resetting the AXI CDMA core"]
+pub type ResetW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sg_mode` reader - Scatter Gather mode. 0: simple DMA mode, 1: Scatter Gather mode"]
+pub type SgModeR = crate::BitReader;
+#[doc = "Field `sg_mode` writer - Scatter Gather mode. 0: simple DMA mode, 1: Scatter Gather mode"]
+pub type SgModeW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `key_read` reader - Keyhole Read. The source address is not incremented during the transfer."]
+pub type KeyReadR = crate::BitReader;
+#[doc = "Field `key_read` writer - Keyhole Read. The source address is not incremented during the transfer."]
+pub type KeyReadW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `key_write` reader - Keyhole Write. The destination address is not incremented during the transfer."]
+pub type KeyWriteR = crate::BitReader;
+#[doc = "Field `key_write` writer - Keyhole Write. The destination address is not incremented during the transfer."]
+pub type KeyWriteW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `cyclic_bd_enable` reader - When set to 1, the CDMA operates in Cyclic Buffer Descriptor (BD) mode"]
+pub type CyclicBdEnableR = crate::BitReader;
+#[doc = "Field `cyclic_bd_enable` writer - When set to 1, the CDMA operates in Cyclic Buffer Descriptor (BD) mode"]
+pub type CyclicBdEnableW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `ioc_irq_en` reader - Interrupt on Complete (IOC) Interrupt Enable"]
+pub type IocIrqEnR = crate::BitReader;
+#[doc = "Field `ioc_irq_en` writer - Interrupt on Complete (IOC) Interrupt Enable"]
+pub type IocIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dly_irq_en` reader - Interrupt on Delay Timer Interrupt Enable"]
+pub type DlyIrqEnR = crate::BitReader;
+#[doc = "Field `dly_irq_en` writer - Interrupt on Delay Timer Interrupt Enable"]
+pub type DlyIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `err_irq_en` reader - Interrupt on Error Interrupt Enable"]
+pub type ErrIrqEnR = crate::BitReader;
+#[doc = "Field `err_irq_en` writer - Interrupt on Error Interrupt Enable"]
+pub type ErrIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `irq_threshold` reader - Interrupt Threshold"]
+pub type IrqThresholdR = crate::FieldReader;
+#[doc = "Field `irq_threshold` writer - Interrupt Threshold"]
+pub type IrqThresholdW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+#[doc = "Field `irq_delay` reader - Interrupt Delay Time Out"]
+pub type IrqDelayR = crate::FieldReader;
+#[doc = "Field `irq_delay` writer - Interrupt Delay Time Out"]
+pub type IrqDelayW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+impl R {
+    #[doc = "Bit 2 - Soft reset for resetting the AXI CDMA core"]
+    #[inline(always)]
+    pub fn reset(&self) -> ResetR {
+        ResetR::new(((self.bits >> 2) & 1) != 0)
+    }
+    #[doc = "Bit 3 - Scatter Gather mode. 0: simple DMA mode, 1: Scatter Gather mode"]
+    #[inline(always)]
+    pub fn sg_mode(&self) -> SgModeR {
+        SgModeR::new(((self.bits >> 3) & 1) != 0)
+    }
+    #[doc = "Bit 4 - Keyhole Read. The source address is not incremented during the transfer."]
+    #[inline(always)]
+    pub fn key_read(&self) -> KeyReadR {
+        KeyReadR::new(((self.bits >> 4) & 1) != 0)
+    }
+    #[doc = "Bit 5 - Keyhole Write. The destination address is not incremented during the transfer."]
+    #[inline(always)]
+    pub fn key_write(&self) -> KeyWriteR {
+        KeyWriteR::new(((self.bits >> 5) & 1) != 0)
+    }
+    #[doc = "Bit 6 - When set to 1, the CDMA operates in Cyclic Buffer Descriptor (BD) mode"]
+    #[inline(always)]
+    pub fn cyclic_bd_enable(&self) -> CyclicBdEnableR {
+        CyclicBdEnableR::new(((self.bits >> 6) & 1) != 0)
+    }
+    #[doc = "Bit 12 - Interrupt on Complete (IOC) Interrupt Enable"]
+    #[inline(always)]
+    pub fn ioc_irq_en(&self) -> IocIrqEnR {
+        IocIrqEnR::new(((self.bits >> 12) & 1) != 0)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay Timer Interrupt Enable"]
+    #[inline(always)]
+    pub fn dly_irq_en(&self) -> DlyIrqEnR {
+        DlyIrqEnR::new(((self.bits >> 13) & 1) != 0)
+    }
+    #[doc = "Bit 14 - Interrupt on Error Interrupt Enable"]
+    #[inline(always)]
+    pub fn err_irq_en(&self) -> ErrIrqEnR {
+        ErrIrqEnR::new(((self.bits >> 14) & 1) != 0)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold"]
+    #[inline(always)]
+    pub fn irq_threshold(&self) -> IrqThresholdR {
+        IrqThresholdR::new(((self.bits >> 16) & 0xff) as u8)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Out"]
+    #[inline(always)]
+    pub fn irq_delay(&self) -> IrqDelayR {
+        IrqDelayR::new(((self.bits >> 24) & 0xff) as u8)
+    }
+}
+impl W {
+    #[doc = "Bit 2 - Soft reset for resetting the AXI CDMA core"]
+    #[inline(always)]
+    #[must_use]
+    pub fn reset(&mut self) -> ResetW<CdmacrSpec> {
+        ResetW::new(self, 2)
+    }
+    #[doc = "Bit 3 - Scatter Gather mode. 0: simple DMA mode, 1: Scatter Gather mode"]
+    #[inline(always)]
+    #[must_use]
+    pub fn sg_mode(&mut self) -> SgModeW<CdmacrSpec> {
+        SgModeW::new(self, 3)
+    }
+    #[doc = "Bit 4 - Keyhole Read. The source address is not incremented during the transfer."]
+    #[inline(always)]
+    #[must_use]
+    pub fn key_read(&mut self) -> KeyReadW<CdmacrSpec> {
+        KeyReadW::new(self, 4)
+    }
+    #[doc = "Bit 5 - Keyhole Write. The destination address is not incremented during the transfer."]
+    #[inline(always)]
+    #[must_use]
+    pub fn key_write(&mut self) -> KeyWriteW<CdmacrSpec> {
+        KeyWriteW::new(self, 5)
+    }
+    #[doc = "Bit 6 - When set to 1, the CDMA operates in Cyclic Buffer Descriptor (BD) mode"]
+    #[inline(always)]
+    #[must_use]
+    pub fn cyclic_bd_enable(&mut self) -> CyclicBdEnableW<CdmacrSpec> {
+        CyclicBdEnableW::new(self, 6)
+    }
+    #[doc = "Bit 12 - Interrupt on Complete (IOC) Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn ioc_irq_en(&mut self) -> IocIrqEnW<CdmacrSpec> {
+        IocIrqEnW::new(self, 12)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay Timer Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn dly_irq_en(&mut self) -> DlyIrqEnW<CdmacrSpec> {
+        DlyIrqEnW::new(self, 13)
+    }
+    #[doc = "Bit 14 - Interrupt on Error Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn err_irq_en(&mut self) -> ErrIrqEnW<CdmacrSpec> {
+        ErrIrqEnW::new(self, 14)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_threshold(&mut self) -> IrqThresholdW<CdmacrSpec> {
+        IrqThresholdW::new(self, 16)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Out"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_delay(&mut self) -> IrqDelayW<CdmacrSpec> {
+        IrqDelayW::new(self, 24)
+    }
+}
+#[doc = "CDMA Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`cdmacr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`cdmacr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct CdmacrSpec;
+impl crate::RegisterSpec for CdmacrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`cdmacr::R`](R) reader structure"]
+impl crate::Readable for CdmacrSpec {}
+#[doc = "`write(|w| ..)` method takes [`cdmacr::W`](W) writer structure"]
+impl crate::Writable for CdmacrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets cdmacr to value 0"]
+impl crate::Resettable for CdmacrSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,220 @@
+#[doc = "Register `cdmasr` reader"]
+pub type R = crate::R<CdmasrSpec>;
+#[doc = "Register `cdmasr` writer"]
+pub type W = crate::W<CdmasrSpec>;
+#[doc = "Field `idle` reader - CDMA Idle. Set when the CDMA has completed all the queued transfers."]
+pub type IdleR = crate::BitReader;
+#[doc = "Field `idle` writer - CDMA Idle. Set when the CDMA has completed all the queued transfers."]
+pub type IdleW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sg_incld` reader - Scatter Gather Included"]
+pub type SgIncldR = crate::BitReader;
+#[doc = "Field `sg_incld` writer - Scatter Gather Included"]
+pub type SgIncldW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dma_int_err` reader - DMA Internal Error"]
+pub type DmaIntErrR = crate::BitReader;
+#[doc = "Field `dma_int_err` writer - DMA Internal Error"]
+pub type DmaIntErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dma_slv_err` reader - DMA Slave Error"]
+pub type DmaSlvErrR = crate::BitReader;
+#[doc = "Field `dma_slv_err` writer - DMA Slave Error"]
+pub type DmaSlvErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dma_dec_err` reader - DMA Decode Error"]
+pub type DmaDecErrR = crate::BitReader;
+#[doc = "Field `dma_dec_err` writer - DMA Decode Error"]
+pub type DmaDecErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sg_int_err` reader - Scatter Gather Internal Error"]
+pub type SgIntErrR = crate::BitReader;
+#[doc = "Field `sg_int_err` writer - Scatter Gather Internal Error"]
+pub type SgIntErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sg_slv_err` reader - Scatter Gather Slave Error"]
+pub type SgSlvErrR = crate::BitReader;
+#[doc = "Field `sg_slv_err` writer - Scatter Gather Slave Error"]
+pub type SgSlvErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sg_dec_err` reader - Scatter Gather Decode Error"]
+pub type SgDecErrR = crate::BitReader;
+#[doc = "Field `sg_dec_err` writer - Scatter Gather Decode Error"]
+pub type SgDecErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `ioc_irq` reader - Interrupt on Complete"]
+pub type IocIrqR = crate::BitReader;
+#[doc = "Field `ioc_irq` writer - Interrupt on Complete"]
+pub type IocIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dly_irq` reader - Interrupt on Delay"]
+pub type DlyIrqR = crate::BitReader;
+#[doc = "Field `dly_irq` writer - Interrupt on Delay"]
+pub type DlyIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `err_irq` reader - Interrupt on Error"]
+pub type ErrIrqR = crate::BitReader;
+#[doc = "Field `err_irq` writer - Interrupt on Error"]
+pub type ErrIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `irq_threshold_sts` reader - Interrupt Threshold Status"]
+pub type IrqThresholdStsR = crate::FieldReader;
+#[doc = "Field `irq_threshold_sts` writer - Interrupt Threshold Status"]
+pub type IrqThresholdStsW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+#[doc = "Field `irq_delay_sts` reader - Interrupt Delay Time Status"]
+pub type IrqDelayStsR = crate::FieldReader;
+#[doc = "Field `irq_delay_sts` writer - Interrupt Delay Time Status"]
+pub type IrqDelayStsW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+impl R {
+    #[doc = "Bit 1 - CDMA Idle. Set when the CDMA has completed all the queued transfers."]
+    #[inline(always)]
+    pub fn idle(&self) -> IdleR {
+        IdleR::new(((self.bits >> 1) & 1) != 0)
+    }
+    #[doc = "Bit 3 - Scatter Gather Included"]
+    #[inline(always)]
+    pub fn sg_incld(&self) -> SgIncldR {
+        SgIncldR::new(((self.bits >> 3) & 1) != 0)
+    }
+    #[doc = "Bit 4 - DMA Internal Error"]
+    #[inline(always)]
+    pub fn dma_int_err(&self) -> DmaIntErrR {
+        DmaIntErrR::new(((self.bits >> 4) & 1) != 0)
+    }
+    #[doc = "Bit 5 - DMA Slave Error"]
+    #[inline(always)]
+    pub fn dma_slv_err(&self) -> DmaSlvErrR {
+        DmaSlvErrR::new(((self.bits >> 5) & 1) != 0)
+    }
+    #[doc = "Bit 6 - DMA Decode Error"]
+    #[inline(always)]
+    pub fn dma_dec_err(&self) -> DmaDecErrR {
+        DmaDecErrR::new(((self.bits >> 6) & 1) != 0)
+    }
+    #[doc = "Bit 8 - Scatter Gather Internal Error"]
+    #[inline(always)]
+    pub fn sg_int_err(&self) -> SgIntErrR {
+        SgIntErrR::new(((self.bits >> 8) & 1) != 0)
+    }
+    #[doc = "Bit 9 - Scatter Gather Slave Error"]
+    #[inline(always)]
+    pub fn sg_slv_err(&self) -> SgSlvErrR {
+        SgSlvErrR::new(((self.bits >> 9) & 1) != 0)
+    }
+    #[doc = "Bit 10 - Scatter Gather Decode Error"]
+    #[inline(always)]
+    pub fn sg_dec_err(&self) -> SgDecErrR {
+        SgDecErrR::new(((self.bits >> 10) & 1) != 0)
+    }
+    #[doc = "Bit 12 - Interrupt on Complete"]
+    #[inline(always)]
+    pub fn ioc_irq(&self) -> IocIrqR {
+        IocIrqR::new(((self.bits >> 12) & 1) != 0)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay"]
+    #[inline(always)]
+    pub fn dly_irq(&self) -> DlyIrqR {
+        DlyIrqR::new(((self.bits >> 13) & 1) != 0)
+    }
+    #[doc = "Bit 14 - Interrupt on Error"]
+    #[inline(always)]
+    pub fn err_irq(&self) -> ErrIrqR {
+        ErrIrqR::new(((self.bits >> 14) & 1) != 0)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold Status"]
+    #[inline(always)]
+    pub fn irq_threshold_sts(&self) -> IrqThresholdStsR {
+        IrqThresholdStsR::new(((self.bits >> 16) & 0xff) as u8)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Status"]
+    #[inline(always)]
+    pub fn irq_delay_sts(&self) -> IrqDelayStsR {
+        IrqDelayStsR::new(((self.bits >> 24) & 0xff) as u8)
+    }
+}
+impl W {
+    #[doc = "Bit 1 - CDMA Idle. Set when the CDMA has completed all the queued transfers."]
+    #[inline(always)]
+    #[must_use]
+    pub fn idle(&mut self) -> IdleW<CdmasrSpec> {
+        IdleW::new(self, 1)
+    }
+    #[doc = "Bit 3 - Scatter Gather Included"]
+    #[inline(always)]
+    #[must_use]
+    pub fn sg_incld(&mut self) -> SgIncldW<CdmasrSpec> {
+        SgIncldW::new(self, 3)
+    }
+    #[doc = "Bit 4 - DMA Internal Error"]
+    #[inline(always)]
+    #[must_use]
+    pub fn dma_int_err(&mut self) -> DmaIntErrW<CdmasrSpec> {
+        DmaIntErrW::new(self, 4)
+    }
+    #[doc = "Bit 5 - DMA Slave Error"]
+    #[inline(always)]
+    #[must_use]
+    pub fn dma_slv_err(&mut self) -> DmaSlvErrW<CdmasrSpec> {
+        DmaSlvErrW::new(self, 5)
+    }
+    #[doc = "Bit 6 - DMA Decode Error"]
+    #[inline(always)]
+    #[must_use]
+    pub fn dma_dec_err(&mut self) -> DmaDecErrW<CdmasrSpec> {
+        DmaDecErrW::new(self, 6)
+    }
+    #[doc = "Bit 8 - Scatter Gather Internal Error"]
+    #[inline(always)]
+    #[must_use]
+    pub fn sg_int_err(&mut self) -> SgIntErrW<CdmasrSpec> {
+        SgIntErrW::new(self, 8)
+    }
+    #[doc = "Bit 9 - Scatter Gather Slave Error"]
+    #[inline(always)]
+    #[must_use]
+    pub fn sg_slv_err(&mut self) -> SgSlvErrW<CdmasrSpec> {
+        SgSlvErrW::new(self, 9)
+    }
+    #[doc = "Bit 10 - Scatter Gather Decode Error"]
+    #[inline(always)]
+    #[must_use]
+    pub fn sg_dec_err(&mut self) -> SgDecErrW<CdmasrSpec> {
+        SgDecErrW::new(self, 10)
+    }
+    #[doc = "Bit 12 - Interrupt on Complete"]
+    #[inline(always)]
+    #[must_use]
+    pub fn ioc_irq(&mut self) -> IocIrqW<CdmasrSpec> {
+        IocIrqW::new(self, 12)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay"]
+    #[inline(always)]
+    #[must_use]
+    pub fn dly_irq(&mut self) -> DlyIrqW<CdmasrSpec> {
+        DlyIrqW::new(self, 13)
+    }
+    #[doc = "Bit 14 - Interrupt on Error"]
+    #[inline(always)]
+    #[must_use]
+    pub fn err_irq(&mut self) -> ErrIrqW<CdmasrSpec> {
+        ErrIrqW::new(self, 14)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold Status"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_threshold_sts(&mut self) -> IrqThresholdStsW<CdmasrSpec> {
+        IrqThresholdStsW::new(self, 16)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Status"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_delay_sts(&mut self) -> IrqDelayStsW<CdmasrSpec> {
+        IrqDelayStsW::new(self, 24)
+    }
+}
+#[doc = "CDMA Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`cdmasr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`cdmasr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct CdmasrSpec;
+impl crate::RegisterSpec for CdmasrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`cdmasr::R`](R) reader structure"]
+impl crate::Readable for CdmasrSpec {}
+#[doc = "`write(|w| ..)` method takes [`cdmasr::W`](W) writer structure"]
+impl crate::Writable for CdmasrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets cdmasr to value 0"]
+impl crate::Resettable for CdmasrSpec {
+    const RESET_VALUE: u32 = 0;
+}
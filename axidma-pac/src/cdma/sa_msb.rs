@@ -0,0 +1,40 @@
+#[doc = "Register `sa_msb` reader"]
+pub type R = crate::R<SaMsbSpec>;
+#[doc = "Register `sa_msb` writer"]
+pub type W = crate::W<SaMsbSpec>;
+#[doc = "Field `source_address` reader - Indicates the source address of the simple DMA transfer."]
+pub type SourceAddressR = crate::FieldReader<u32>;
+#[doc = "Field `source_address` writer - Indicates the source address of the simple DMA transfer."]
+pub type SourceAddressW<'a, REG> = crate::FieldWriter<'a, REG, 32, u32>;
+impl R {
+    #[doc = "Bits 0:31 - Indicates the source address of the simple DMA transfer."]
+    #[inline(always)]
+    pub fn source_address(&self) -> SourceAddressR {
+        SourceAddressR::new(self.bits)
+    }
+}
+impl W {
+    #[doc = "Bits 0:31 - Indicates the source address of the simple DMA transfer."]
+    #[inline(always)]
+    #[must_use]
+    pub fn source_address(&mut self) -> SourceAddressW<SaMsbSpec> {
+        SourceAddressW::new(self, 0)
+    }
+}
+#[doc = "Source Address. Upper 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sa_msb::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sa_msb::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct SaMsbSpec;
+impl crate::RegisterSpec for SaMsbSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`sa_msb::R`](R) reader structure"]
+impl crate::Readable for SaMsbSpec {}
+#[doc = "`write(|w| ..)` method takes [`sa_msb::W`](W) writer structure"]
+impl crate::Writable for SaMsbSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets sa_msb to value 0"]
+impl crate::Resettable for SaMsbSpec {
+    const RESET_VALUE: u32 = 0;
+}
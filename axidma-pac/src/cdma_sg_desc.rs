@@ -0,0 +1,103 @@
+#[repr(C)]
+#[doc = "Register block"]
+#[derive(Default)]
+pub struct RegisterBlock {
+    nxt_desc: NxtDesc,
+    nxt_desc_msb: NxtDescMsb,
+    sa: Sa,
+    sa_msb: SaMsb,
+    da: Da,
+    da_msb: DaMsb,
+    control: Control,
+    status: Status,
+}
+impl RegisterBlock {
+    #[doc = "0x00 - Next Descriptor Pointer"]
+    #[inline(always)]
+    pub const fn nxt_desc(&self) -> &NxtDesc {
+        &self.nxt_desc
+    }
+    #[doc = "0x04 - Upper 32 bits of Next Descriptor Pointer"]
+    #[inline(always)]
+    pub const fn nxt_desc_msb(&self) -> &NxtDescMsb {
+        &self.nxt_desc_msb
+    }
+    #[doc = "0x08 - Source Address"]
+    #[inline(always)]
+    pub const fn sa(&self) -> &Sa {
+        &self.sa
+    }
+    #[doc = "0x0c - Upper 32 bits of Source Address"]
+    #[inline(always)]
+    pub const fn sa_msb(&self) -> &SaMsb {
+        &self.sa_msb
+    }
+    #[doc = "0x10 - Destination Address"]
+    #[inline(always)]
+    pub const fn da(&self) -> &Da {
+        &self.da
+    }
+    #[doc = "0x14 - Upper 32 bits of Destination Address"]
+    #[inline(always)]
+    pub const fn da_msb(&self) -> &DaMsb {
+        &self.da_msb
+    }
+    #[doc = "0x18 - Control of BD"]
+    #[inline(always)]
+    pub const fn control(&self) -> &Control {
+        &self.control
+    }
+    #[doc = "0x1c - Status of BD"]
+    #[inline(always)]
+    pub const fn status(&self) -> &Status {
+        &self.status
+    }
+}
+#[doc = "nxt_desc (rw) register accessor: Next Descriptor Pointer\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`nxt_desc::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`nxt_desc::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@nxt_desc`]
+module"]
+#[doc(alias = "nxt_desc")]
+pub type NxtDesc = crate::Reg<nxt_desc::NxtDescSpec>;
+#[doc = "Next Descriptor Pointer"]
+pub mod nxt_desc;
+#[doc = "nxt_desc_msb (rw) register accessor: Upper 32 bits of Next Descriptor Pointer\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`nxt_desc_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`nxt_desc_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@nxt_desc_msb`]
+module"]
+#[doc(alias = "nxt_desc_msb")]
+pub type NxtDescMsb = crate::Reg<nxt_desc_msb::NxtDescMsbSpec>;
+#[doc = "Upper 32 bits of Next Descriptor Pointer"]
+pub mod nxt_desc_msb;
+#[doc = "sa (rw) register accessor: Source Address\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sa::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sa::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@sa`]
+module"]
+#[doc(alias = "sa")]
+pub type Sa = crate::Reg<sa::SaSpec>;
+#[doc = "Source Address"]
+pub mod sa;
+#[doc = "sa_msb (rw) register accessor: Upper 32 bits of Source Address\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sa_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sa_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@sa_msb`]
+module"]
+#[doc(alias = "sa_msb")]
+pub type SaMsb = crate::Reg<sa_msb::SaMsbSpec>;
+#[doc = "Upper 32 bits of Source Address"]
+pub mod sa_msb;
+#[doc = "da (rw) register accessor: Destination Address\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`da::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`da::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@da`]
+module"]
+#[doc(alias = "da")]
+pub type Da = crate::Reg<da::DaSpec>;
+#[doc = "Destination Address"]
+pub mod da;
+#[doc = "da_msb (rw) register accessor: Upper 32 bits of Destination Address\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`da_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`da_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@da_msb`]
+module"]
+#[doc(alias = "da_msb")]
+pub type DaMsb = crate::Reg<da_msb::DaMsbSpec>;
+#[doc = "Upper 32 bits of Destination Address"]
+pub mod da_msb;
+#[doc = "control (rw) register accessor: Control of BD\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`control::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`control::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@control`]
+module"]
+#[doc(alias = "control")]
+pub type Control = crate::Reg<control::ControlSpec>;
+#[doc = "Control of BD"]
+pub mod control;
+#[doc = "status (r) register accessor: Status of BD\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`status::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@status`]
+module"]
+#[doc(alias = "status")]
+pub type Status = crate::Reg<status::StatusSpec>;
+#[doc = "Status of BD"]
+pub mod status;
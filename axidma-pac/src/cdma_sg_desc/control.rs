@@ -0,0 +1,40 @@
+#[doc = "Register `control` reader"]
+pub type R = crate::R<ControlSpec>;
+#[doc = "Register `control` writer"]
+pub type W = crate::W<ControlSpec>;
+#[doc = "Field `btt` reader - Indicates the number of bytes to transfer."]
+pub type BttR = crate::FieldReader<u32>;
+#[doc = "Field `btt` writer - Indicates the number of bytes to transfer."]
+pub type BttW<'a, REG> = crate::FieldWriter<'a, REG, 26, u32>;
+impl R {
+    #[doc = "Bits 0:25 - Indicates the number of bytes to transfer."]
+    #[inline(always)]
+    pub fn btt(&self) -> BttR {
+        BttR::new(self.bits & 0x03ff_ffff)
+    }
+}
+impl W {
+    #[doc = "Bits 0:25 - Indicates the number of bytes to transfer."]
+    #[inline(always)]
+    #[must_use]
+    pub fn btt(&mut self) -> BttW<ControlSpec> {
+        BttW::new(self, 0)
+    }
+}
+#[doc = "Control of BD\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`control::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`control::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct ControlSpec;
+impl crate::RegisterSpec for ControlSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`control::R`](R) reader structure"]
+impl crate::Readable for ControlSpec {}
+#[doc = "`write(|w| ..)` method takes [`control::W`](W) writer structure"]
+impl crate::Writable for ControlSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets control to value 0"]
+impl crate::Resettable for ControlSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,40 @@
+#[doc = "Register `da` reader"]
+pub type R = crate::R<DaSpec>;
+#[doc = "Register `da` writer"]
+pub type W = crate::W<DaSpec>;
+#[doc = "Field `destination_address` reader - Indicates the destination address of the transfer."]
+pub type DestinationAddressR = crate::FieldReader<u32>;
+#[doc = "Field `destination_address` writer - Indicates the destination address of the transfer."]
+pub type DestinationAddressW<'a, REG> = crate::FieldWriter<'a, REG, 32, u32>;
+impl R {
+    #[doc = "Bits 0:31 - Indicates the destination address of the transfer."]
+    #[inline(always)]
+    pub fn destination_address(&self) -> DestinationAddressR {
+        DestinationAddressR::new(self.bits)
+    }
+}
+impl W {
+    #[doc = "Bits 0:31 - Indicates the destination address of the transfer."]
+    #[inline(always)]
+    #[must_use]
+    pub fn destination_address(&mut self) -> DestinationAddressW<DaSpec> {
+        DestinationAddressW::new(self, 0)
+    }
+}
+#[doc = "Destination Address\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`da::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`da::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct DaSpec;
+impl crate::RegisterSpec for DaSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`da::R`](R) reader structure"]
+impl crate::Readable for DaSpec {}
+#[doc = "`write(|w| ..)` method takes [`da::W`](W) writer structure"]
+impl crate::Writable for DaSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets da to value 0"]
+impl crate::Resettable for DaSpec {
+    const RESET_VALUE: u32 = 0;
+}
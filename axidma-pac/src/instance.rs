@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+#[doc = r"A peripheral whose base address is chosen at runtime, for the designs"]
+#[doc = r"with several instances of the same IP, e.g. `Instance<axi_dma::RegisterBlock>`."]
+#[doc = r"Every peripheral has a `from_base` constructor of its instance."]
+pub struct Instance<RB> {
+    base_address: usize,
+    _marker: PhantomData<*const RB>,
+}
+unsafe impl<RB> Send for Instance<RB> {}
+#[doc = r"The registers are accessed with volatile reads and writes through `&self`,"]
+#[doc = r"the owner of the instance coordinates the accesses shared between threads."]
+unsafe impl<RB> Sync for Instance<RB> {}
+impl<RB> Instance<RB> {
+    #[doc = r" Create an instance of the peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must be the address of a register block of type `RB`,"]
+    #[doc = r" and the same rules as for `steal` of the fixed peripherals apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Self {
+        Self {
+            base_address,
+            _marker: PhantomData,
+        }
+    }
+    #[doc = r"Return the pointer to the register block"]
+    #[inline(always)]
+    pub const fn ptr(&self) -> *const RB {
+        self.base_address as *const _
+    }
+    #[doc = r"Return the base address of the register block"]
+    #[inline(always)]
+    pub const fn base_address(&self) -> usize {
+        self.base_address
+    }
+}
+impl<RB> Deref for Instance<RB> {
+    type Target = RB;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.ptr() }
+    }
+}
+impl<RB> core::fmt::Debug for Instance<RB> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Instance")
+            .field("base_address", &self.base_address)
+            .finish()
+    }
+}
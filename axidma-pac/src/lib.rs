@@ -5,7 +5,9 @@ svd2rust release can be generated by cloning the svd2rust [repository], checking
 #![no_std]
 use core::marker::PhantomData;
 use core::ops::Deref;
+mod instance;
 mod sg_desc_impl;
+pub use instance::Instance;
 #[allow(unused_imports)]
 use generic::*;
 #[doc = r"Common register and bit access and modify traits"]
@@ -40,6 +42,16 @@ unsafe impl Send for AxiDma {}
 impl AxiDma {
     #[doc = r"Pointer to the register block"]
     pub const PTR: *const axi_dma::RegisterBlock = 0x6010_0000 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `axi_dma::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<axi_dma::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
     #[doc = r"Return the pointer to the register block"]
     #[inline(always)]
     pub const fn ptr() -> *const axi_dma::RegisterBlock {
@@ -86,6 +98,16 @@ unsafe impl Send for SgDesc {}
 impl SgDesc {
     #[doc = r"Pointer to the register block"]
     pub const PTR: *const sg_desc::RegisterBlock = 0 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `sg_desc::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<sg_desc::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
     #[doc = r"Return the pointer to the register block"]
     #[inline(always)]
     pub const fn ptr() -> *const sg_desc::RegisterBlock {
@@ -132,6 +154,16 @@ unsafe impl Send for Channel {}
 impl Channel {
     #[doc = r"Pointer to the register block"]
     pub const PTR: *const channel::RegisterBlock = 0 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `channel::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<channel::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
     #[doc = r"Return the pointer to the register block"]
     #[inline(always)]
     pub const fn ptr() -> *const channel::RegisterBlock {
@@ -170,6 +202,342 @@ impl core::fmt::Debug for Channel {
 }
 #[doc = "The Registers of channel"]
 pub mod channel;
+#[doc = "AXI Multichannel Direct Memory Access"]
+pub struct Mcdma {
+    _marker: PhantomData<*const ()>,
+}
+unsafe impl Send for Mcdma {}
+impl Mcdma {
+    #[doc = r"Pointer to the register block"]
+    pub const PTR: *const mcdma::RegisterBlock = 0x6010_0000 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `mcdma::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<mcdma::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
+    #[doc = r"Return the pointer to the register block"]
+    #[inline(always)]
+    pub const fn ptr() -> *const mcdma::RegisterBlock {
+        Self::PTR
+    }
+    #[doc = r" Steal an instance of this peripheral"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" Ensure that the new instance of the peripheral cannot be used in a way"]
+    #[doc = r" that may race with any existing instances, for example by only"]
+    #[doc = r" accessing read-only or write-only registers, or by consuming the"]
+    #[doc = r" original peripheral and using critical sections to coordinate"]
+    #[doc = r" access between multiple new instances."]
+    #[doc = r""]
+    #[doc = r" Additionally, other software such as HALs may rely on only one"]
+    #[doc = r" peripheral instance existing to ensure memory safety; ensure"]
+    #[doc = r" no stolen instances are passed to such software."]
+    pub unsafe fn steal() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+impl Deref for Mcdma {
+    type Target = mcdma::RegisterBlock;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*Self::PTR }
+    }
+}
+impl core::fmt::Debug for Mcdma {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Mcdma").finish()
+    }
+}
+#[doc = "AXI Multichannel Direct Memory Access"]
+pub mod mcdma;
+#[doc = "The Registers of a MCDMA channel"]
+pub struct McdmaChannel {
+    _marker: PhantomData<*const ()>,
+}
+unsafe impl Send for McdmaChannel {}
+impl McdmaChannel {
+    #[doc = r"Pointer to the register block"]
+    pub const PTR: *const mcdma_channel::RegisterBlock = 0 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `mcdma_channel::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<mcdma_channel::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
+    #[doc = r"Return the pointer to the register block"]
+    #[inline(always)]
+    pub const fn ptr() -> *const mcdma_channel::RegisterBlock {
+        Self::PTR
+    }
+    #[doc = r" Steal an instance of this peripheral"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" Ensure that the new instance of the peripheral cannot be used in a way"]
+    #[doc = r" that may race with any existing instances, for example by only"]
+    #[doc = r" accessing read-only or write-only registers, or by consuming the"]
+    #[doc = r" original peripheral and using critical sections to coordinate"]
+    #[doc = r" access between multiple new instances."]
+    #[doc = r""]
+    #[doc = r" Additionally, other software such as HALs may rely on only one"]
+    #[doc = r" peripheral instance existing to ensure memory safety; ensure"]
+    #[doc = r" no stolen instances are passed to such software."]
+    pub unsafe fn steal() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+impl Deref for McdmaChannel {
+    type Target = mcdma_channel::RegisterBlock;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*Self::PTR }
+    }
+}
+impl core::fmt::Debug for McdmaChannel {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("McdmaChannel").finish()
+    }
+}
+#[doc = "The Registers of a MCDMA channel"]
+pub mod mcdma_channel;
+#[doc = "MCDMA Scatter Gather Descriptor"]
+pub struct McdmaSgDesc {
+    _marker: PhantomData<*const ()>,
+}
+unsafe impl Send for McdmaSgDesc {}
+impl McdmaSgDesc {
+    #[doc = r"Pointer to the register block"]
+    pub const PTR: *const mcdma_sg_desc::RegisterBlock = 0 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `mcdma_sg_desc::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<mcdma_sg_desc::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
+    #[doc = r"Return the pointer to the register block"]
+    #[inline(always)]
+    pub const fn ptr() -> *const mcdma_sg_desc::RegisterBlock {
+        Self::PTR
+    }
+    #[doc = r" Steal an instance of this peripheral"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" Ensure that the new instance of the peripheral cannot be used in a way"]
+    #[doc = r" that may race with any existing instances, for example by only"]
+    #[doc = r" accessing read-only or write-only registers, or by consuming the"]
+    #[doc = r" original peripheral and using critical sections to coordinate"]
+    #[doc = r" access between multiple new instances."]
+    #[doc = r""]
+    #[doc = r" Additionally, other software such as HALs may rely on only one"]
+    #[doc = r" peripheral instance existing to ensure memory safety; ensure"]
+    #[doc = r" no stolen instances are passed to such software."]
+    pub unsafe fn steal() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+impl Deref for McdmaSgDesc {
+    type Target = mcdma_sg_desc::RegisterBlock;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*Self::PTR }
+    }
+}
+impl core::fmt::Debug for McdmaSgDesc {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("McdmaSgDesc").finish()
+    }
+}
+#[doc = "MCDMA Scatter Gather Descriptor"]
+pub mod mcdma_sg_desc;
+#[doc = "AXI Central Direct Memory Access"]
+pub struct Cdma {
+    _marker: PhantomData<*const ()>,
+}
+unsafe impl Send for Cdma {}
+impl Cdma {
+    #[doc = r"Pointer to the register block"]
+    pub const PTR: *const cdma::RegisterBlock = 0x6020_0000 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `cdma::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<cdma::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
+    #[doc = r"Return the pointer to the register block"]
+    #[inline(always)]
+    pub const fn ptr() -> *const cdma::RegisterBlock {
+        Self::PTR
+    }
+    #[doc = r" Steal an instance of this peripheral"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" Ensure that the new instance of the peripheral cannot be used in a way"]
+    #[doc = r" that may race with any existing instances, for example by only"]
+    #[doc = r" accessing read-only or write-only registers, or by consuming the"]
+    #[doc = r" original peripheral and using critical sections to coordinate"]
+    #[doc = r" access between multiple new instances."]
+    #[doc = r""]
+    #[doc = r" Additionally, other software such as HALs may rely on only one"]
+    #[doc = r" peripheral instance existing to ensure memory safety; ensure"]
+    #[doc = r" no stolen instances are passed to such software."]
+    pub unsafe fn steal() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+impl Deref for Cdma {
+    type Target = cdma::RegisterBlock;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*Self::PTR }
+    }
+}
+impl core::fmt::Debug for Cdma {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Cdma").finish()
+    }
+}
+#[doc = "AXI Central Direct Memory Access"]
+pub mod cdma;
+#[doc = "CDMA Scatter Gather Descriptor"]
+pub struct CdmaSgDesc {
+    _marker: PhantomData<*const ()>,
+}
+unsafe impl Send for CdmaSgDesc {}
+impl CdmaSgDesc {
+    #[doc = r"Pointer to the register block"]
+    pub const PTR: *const cdma_sg_desc::RegisterBlock = 0 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `cdma_sg_desc::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<cdma_sg_desc::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
+    #[doc = r"Return the pointer to the register block"]
+    #[inline(always)]
+    pub const fn ptr() -> *const cdma_sg_desc::RegisterBlock {
+        Self::PTR
+    }
+    #[doc = r" Steal an instance of this peripheral"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" Ensure that the new instance of the peripheral cannot be used in a way"]
+    #[doc = r" that may race with any existing instances, for example by only"]
+    #[doc = r" accessing read-only or write-only registers, or by consuming the"]
+    #[doc = r" original peripheral and using critical sections to coordinate"]
+    #[doc = r" access between multiple new instances."]
+    #[doc = r""]
+    #[doc = r" Additionally, other software such as HALs may rely on only one"]
+    #[doc = r" peripheral instance existing to ensure memory safety; ensure"]
+    #[doc = r" no stolen instances are passed to such software."]
+    pub unsafe fn steal() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+impl Deref for CdmaSgDesc {
+    type Target = cdma_sg_desc::RegisterBlock;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*Self::PTR }
+    }
+}
+impl core::fmt::Debug for CdmaSgDesc {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("CdmaSgDesc").finish()
+    }
+}
+#[doc = "CDMA Scatter Gather Descriptor"]
+pub mod cdma_sg_desc;
+#[doc = "AXI Video Direct Memory Access"]
+pub struct Vdma {
+    _marker: PhantomData<*const ()>,
+}
+unsafe impl Send for Vdma {}
+impl Vdma {
+    #[doc = r"Pointer to the register block"]
+    pub const PTR: *const vdma::RegisterBlock = 0x6030_0000 as *const _;
+    #[doc = r" Create an instance of this peripheral at `base_address`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `base_address` must point to a `vdma::RegisterBlock`, and the same"]
+    #[doc = r" rules as for `steal` apply."]
+    #[inline(always)]
+    pub const unsafe fn from_base(base_address: usize) -> Instance<vdma::RegisterBlock> {
+        Instance::from_base(base_address)
+    }
+    #[doc = r"Return the pointer to the register block"]
+    #[inline(always)]
+    pub const fn ptr() -> *const vdma::RegisterBlock {
+        Self::PTR
+    }
+    #[doc = r" Steal an instance of this peripheral"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" Ensure that the new instance of the peripheral cannot be used in a way"]
+    #[doc = r" that may race with any existing instances, for example by only"]
+    #[doc = r" accessing read-only or write-only registers, or by consuming the"]
+    #[doc = r" original peripheral and using critical sections to coordinate"]
+    #[doc = r" access between multiple new instances."]
+    #[doc = r""]
+    #[doc = r" Additionally, other software such as HALs may rely on only one"]
+    #[doc = r" peripheral instance existing to ensure memory safety; ensure"]
+    #[doc = r" no stolen instances are passed to such software."]
+    pub unsafe fn steal() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+impl Deref for Vdma {
+    type Target = vdma::RegisterBlock;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*Self::PTR }
+    }
+}
+impl core::fmt::Debug for Vdma {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Vdma").finish()
+    }
+}
+#[doc = "AXI Video Direct Memory Access"]
+pub mod vdma;
 #[doc = r" All the peripherals."]
 #[allow(non_snake_case)]
 pub struct Peripherals {
@@ -179,6 +547,18 @@ pub struct Peripherals {
     pub sg_desc: SgDesc,
     #[doc = "CHANNEL"]
     pub channel: Channel,
+    #[doc = "MCDMA"]
+    pub mcdma: Mcdma,
+    #[doc = "MCDMA_CHANNEL"]
+    pub mcdma_channel: McdmaChannel,
+    #[doc = "MCDMA_SG_DESC"]
+    pub mcdma_sg_desc: McdmaSgDesc,
+    #[doc = "CDMA"]
+    pub cdma: Cdma,
+    #[doc = "CDMA_SG_DESC"]
+    pub cdma_sg_desc: CdmaSgDesc,
+    #[doc = "VDMA"]
+    pub vdma: Vdma,
 }
 impl Peripherals {
     #[doc = r" Returns all the peripherals *once*."]
@@ -204,6 +584,24 @@ impl Peripherals {
             channel: Channel {
                 _marker: PhantomData,
             },
+            mcdma: Mcdma {
+                _marker: PhantomData,
+            },
+            mcdma_channel: McdmaChannel {
+                _marker: PhantomData,
+            },
+            mcdma_sg_desc: McdmaSgDesc {
+                _marker: PhantomData,
+            },
+            cdma: Cdma {
+                _marker: PhantomData,
+            },
+            cdma_sg_desc: CdmaSgDesc {
+                _marker: PhantomData,
+            },
+            vdma: Vdma {
+                _marker: PhantomData,
+            },
         }
     }
 }
@@ -0,0 +1,233 @@
+#[repr(C)]
+#[doc = "Register block"]
+pub struct RegisterBlock {
+    mm2s_ccr: Mm2sCcr,
+    mm2s_csr: Mm2sCsr,
+    mm2s_chen: Mm2sChen,
+    mm2s_chser: Mm2sChser,
+    mm2s_err: Mm2sErr,
+    mm2s_ch_schd_type: Mm2sChSchdType,
+    mm2s_wrr_reg1: Mm2sWrrReg1,
+    mm2s_wrr_reg2: Mm2sWrrReg2,
+    mm2s_channels_serviced: Mm2sChannelsServiced,
+    mm2s_arcache_aruser: Mm2sArcacheAruser,
+    mm2s_intr_status: Mm2sIntrStatus,
+    _reserved11: [u8; 0x04d4],
+    s2mm_ccr: S2mmCcr,
+    s2mm_csr: S2mmCsr,
+    s2mm_chen: S2mmChen,
+    s2mm_chser: S2mmChser,
+    s2mm_err: S2mmErr,
+    s2mm_pktdrop: S2mmPktdrop,
+    s2mm_channels_serviced: S2mmChannelsServiced,
+    s2mm_awcache_awuser: S2mmAwcacheAwuser,
+    s2mm_intr_status: S2mmIntrStatus,
+}
+impl RegisterBlock {
+    #[doc = "0x00 - MM2S Common Control register"]
+    #[inline(always)]
+    pub const fn mm2s_ccr(&self) -> &Mm2sCcr {
+        &self.mm2s_ccr
+    }
+    #[doc = "0x04 - MM2S Common Status register"]
+    #[inline(always)]
+    pub const fn mm2s_csr(&self) -> &Mm2sCsr {
+        &self.mm2s_csr
+    }
+    #[doc = "0x08 - MM2S Channel Enable/Disable register"]
+    #[inline(always)]
+    pub const fn mm2s_chen(&self) -> &Mm2sChen {
+        &self.mm2s_chen
+    }
+    #[doc = "0x0c - MM2S Channel In Progress register"]
+    #[inline(always)]
+    pub const fn mm2s_chser(&self) -> &Mm2sChser {
+        &self.mm2s_chser
+    }
+    #[doc = "0x10 - MM2S Error register"]
+    #[inline(always)]
+    pub const fn mm2s_err(&self) -> &Mm2sErr {
+        &self.mm2s_err
+    }
+    #[doc = "0x14 - MM2S Channel Scheduler Type"]
+    #[inline(always)]
+    pub const fn mm2s_ch_schd_type(&self) -> &Mm2sChSchdType {
+        &self.mm2s_ch_schd_type
+    }
+    #[doc = "0x18 - MM2S Weighted Round Robin weights of channel 0 to 7"]
+    #[inline(always)]
+    pub const fn mm2s_wrr_reg1(&self) -> &Mm2sWrrReg1 {
+        &self.mm2s_wrr_reg1
+    }
+    #[doc = "0x1c - MM2S Weighted Round Robin weights of channel 8 to 15"]
+    #[inline(always)]
+    pub const fn mm2s_wrr_reg2(&self) -> &Mm2sWrrReg2 {
+        &self.mm2s_wrr_reg2
+    }
+    #[doc = "0x20 - MM2S Channels Completed register"]
+    #[inline(always)]
+    pub const fn mm2s_channels_serviced(&self) -> &Mm2sChannelsServiced {
+        &self.mm2s_channels_serviced
+    }
+    #[doc = "0x24 - MM2S ARCACHE and ARUSER values for the data and SG interfaces"]
+    #[inline(always)]
+    pub const fn mm2s_arcache_aruser(&self) -> &Mm2sArcacheAruser {
+        &self.mm2s_arcache_aruser
+    }
+    #[doc = "0x28 - MM2S Channel Interrupt Monitor register"]
+    #[inline(always)]
+    pub const fn mm2s_intr_status(&self) -> &Mm2sIntrStatus {
+        &self.mm2s_intr_status
+    }
+    #[doc = "0x500 - S2MM Common Control register"]
+    #[inline(always)]
+    pub const fn s2mm_ccr(&self) -> &S2mmCcr {
+        &self.s2mm_ccr
+    }
+    #[doc = "0x504 - S2MM Common Status register"]
+    #[inline(always)]
+    pub const fn s2mm_csr(&self) -> &S2mmCsr {
+        &self.s2mm_csr
+    }
+    #[doc = "0x508 - S2MM Channel Enable/Disable register"]
+    #[inline(always)]
+    pub const fn s2mm_chen(&self) -> &S2mmChen {
+        &self.s2mm_chen
+    }
+    #[doc = "0x50c - S2MM Channel In Progress register"]
+    #[inline(always)]
+    pub const fn s2mm_chser(&self) -> &S2mmChser {
+        &self.s2mm_chser
+    }
+    #[doc = "0x510 - S2MM Error register"]
+    #[inline(always)]
+    pub const fn s2mm_err(&self) -> &S2mmErr {
+        &self.s2mm_err
+    }
+    #[doc = "0x514 - S2MM Packet Drop Count register"]
+    #[inline(always)]
+    pub const fn s2mm_pktdrop(&self) -> &S2mmPktdrop {
+        &self.s2mm_pktdrop
+    }
+    #[doc = "0x518 - S2MM Channels Completed register"]
+    #[inline(always)]
+    pub const fn s2mm_channels_serviced(&self) -> &S2mmChannelsServiced {
+        &self.s2mm_channels_serviced
+    }
+    #[doc = "0x51c - S2MM AWCACHE and AWUSER values for the data and SG interfaces"]
+    #[inline(always)]
+    pub const fn s2mm_awcache_awuser(&self) -> &S2mmAwcacheAwuser {
+        &self.s2mm_awcache_awuser
+    }
+    #[doc = "0x520 - S2MM Channel Interrupt Monitor register"]
+    #[inline(always)]
+    pub const fn s2mm_intr_status(&self) -> &S2mmIntrStatus {
+        &self.s2mm_intr_status
+    }
+}
+#[doc = "mm2s_ccr (rw) register accessor: MM2S Common Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_ccr::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_ccr::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_ccr`]
+module"]
+#[doc(alias = "mm2s_ccr")]
+pub type Mm2sCcr = crate::Reg<mm2s_ccr::Mm2sCcrSpec>;
+#[doc = "MM2S Common Control register"]
+pub mod mm2s_ccr;
+#[doc = "mm2s_csr (r) register accessor: MM2S Common Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_csr::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_csr`]
+module"]
+#[doc(alias = "mm2s_csr")]
+pub type Mm2sCsr = crate::Reg<mm2s_csr::Mm2sCsrSpec>;
+#[doc = "MM2S Common Status register"]
+pub mod mm2s_csr;
+#[doc = "mm2s_chen (rw) register accessor: MM2S Channel Enable/Disable register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_chen::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_chen::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_chen`]
+module"]
+#[doc(alias = "mm2s_chen")]
+pub type Mm2sChen = crate::Reg<mm2s_chen::Mm2sChenSpec>;
+#[doc = "MM2S Channel Enable/Disable register"]
+pub mod mm2s_chen;
+#[doc = "mm2s_chser (r) register accessor: MM2S Channel In Progress register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_chser::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_chser`]
+module"]
+#[doc(alias = "mm2s_chser")]
+pub type Mm2sChser = crate::Reg<mm2s_chser::Mm2sChserSpec>;
+#[doc = "MM2S Channel In Progress register"]
+pub mod mm2s_chser;
+#[doc = "mm2s_err (r) register accessor: MM2S Error register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_err::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_err`]
+module"]
+#[doc(alias = "mm2s_err")]
+pub type Mm2sErr = crate::Reg<mm2s_err::Mm2sErrSpec>;
+#[doc = "MM2S Error register"]
+pub mod mm2s_err;
+#[doc = "mm2s_ch_schd_type (rw) register accessor: MM2S Channel Scheduler Type\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_ch_schd_type::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_ch_schd_type::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_ch_schd_type`]
+module"]
+#[doc(alias = "mm2s_ch_schd_type")]
+pub type Mm2sChSchdType = crate::Reg<mm2s_ch_schd_type::Mm2sChSchdTypeSpec>;
+#[doc = "MM2S Channel Scheduler Type"]
+pub mod mm2s_ch_schd_type;
+#[doc = "mm2s_wrr_reg1 (rw) register accessor: MM2S Weighted Round Robin weights of channel 0 to 7\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_wrr_reg1::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_wrr_reg1::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_wrr_reg1`]
+module"]
+#[doc(alias = "mm2s_wrr_reg1")]
+pub type Mm2sWrrReg1 = crate::Reg<mm2s_wrr_reg1::Mm2sWrrReg1Spec>;
+#[doc = "MM2S Weighted Round Robin weights of channel 0 to 7"]
+pub mod mm2s_wrr_reg1;
+#[doc = "mm2s_wrr_reg2 (rw) register accessor: MM2S Weighted Round Robin weights of channel 8 to 15\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_wrr_reg2::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_wrr_reg2::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_wrr_reg2`]
+module"]
+#[doc(alias = "mm2s_wrr_reg2")]
+pub type Mm2sWrrReg2 = crate::Reg<mm2s_wrr_reg2::Mm2sWrrReg2Spec>;
+#[doc = "MM2S Weighted Round Robin weights of channel 8 to 15"]
+pub mod mm2s_wrr_reg2;
+#[doc = "mm2s_channels_serviced (r) register accessor: MM2S Channels Completed register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_channels_serviced::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_channels_serviced`]
+module"]
+#[doc(alias = "mm2s_channels_serviced")]
+pub type Mm2sChannelsServiced = crate::Reg<mm2s_channels_serviced::Mm2sChannelsServicedSpec>;
+#[doc = "MM2S Channels Completed register"]
+pub mod mm2s_channels_serviced;
+#[doc = "mm2s_arcache_aruser (rw) register accessor: MM2S ARCACHE and ARUSER values for the data and SG interfaces\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_arcache_aruser::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_arcache_aruser::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_arcache_aruser`]
+module"]
+#[doc(alias = "mm2s_arcache_aruser")]
+pub type Mm2sArcacheAruser = crate::Reg<mm2s_arcache_aruser::Mm2sArcacheAruserSpec>;
+#[doc = "MM2S ARCACHE and ARUSER values for the data and SG interfaces"]
+pub mod mm2s_arcache_aruser;
+#[doc = "mm2s_intr_status (r) register accessor: MM2S Channel Interrupt Monitor register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_intr_status::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_intr_status`]
+module"]
+#[doc(alias = "mm2s_intr_status")]
+pub type Mm2sIntrStatus = crate::Reg<mm2s_intr_status::Mm2sIntrStatusSpec>;
+#[doc = "MM2S Channel Interrupt Monitor register"]
+pub mod mm2s_intr_status;
+#[doc = "S2MM Common Control register"]
+pub use Mm2sCcr as S2mmCcr;
+#[doc = "S2MM Common Control register"]
+pub use mm2s_ccr as s2mm_ccr;
+#[doc = "S2MM Common Status register"]
+pub use Mm2sCsr as S2mmCsr;
+#[doc = "S2MM Common Status register"]
+pub use mm2s_csr as s2mm_csr;
+#[doc = "S2MM Channel Enable/Disable register"]
+pub use Mm2sChen as S2mmChen;
+#[doc = "S2MM Channel Enable/Disable register"]
+pub use mm2s_chen as s2mm_chen;
+#[doc = "S2MM Channel In Progress register"]
+pub use Mm2sChser as S2mmChser;
+#[doc = "S2MM Channel In Progress register"]
+pub use mm2s_chser as s2mm_chser;
+#[doc = "S2MM Error register"]
+pub use Mm2sErr as S2mmErr;
+#[doc = "S2MM Error register"]
+pub use mm2s_err as s2mm_err;
+#[doc = "s2mm_pktdrop (r) register accessor: S2MM Packet Drop Count register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`s2mm_pktdrop::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@s2mm_pktdrop`]
+module"]
+#[doc(alias = "s2mm_pktdrop")]
+pub type S2mmPktdrop = crate::Reg<s2mm_pktdrop::S2mmPktdropSpec>;
+#[doc = "S2MM Packet Drop Count register"]
+pub mod s2mm_pktdrop;
+#[doc = "S2MM Channels Completed register"]
+pub use Mm2sChannelsServiced as S2mmChannelsServiced;
+#[doc = "S2MM Channels Completed register"]
+pub use mm2s_channels_serviced as s2mm_channels_serviced;
+#[doc = "s2mm_awcache_awuser (rw) register accessor: S2MM AWCACHE and AWUSER values for the data and SG interfaces\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`s2mm_awcache_awuser::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`s2mm_awcache_awuser::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@s2mm_awcache_awuser`]
+module"]
+#[doc(alias = "s2mm_awcache_awuser")]
+pub type S2mmAwcacheAwuser = crate::Reg<s2mm_awcache_awuser::S2mmAwcacheAwuserSpec>;
+#[doc = "S2MM AWCACHE and AWUSER values for the data and SG interfaces"]
+pub mod s2mm_awcache_awuser;
+#[doc = "S2MM Channel Interrupt Monitor register"]
+pub use Mm2sIntrStatus as S2mmIntrStatus;
+#[doc = "S2MM Channel Interrupt Monitor register"]
+pub use mm2s_intr_status as s2mm_intr_status;
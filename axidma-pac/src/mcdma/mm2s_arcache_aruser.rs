@@ -0,0 +1,55 @@
+#[doc = "Register `mm2s_arcache_aruser` reader"]
+pub type R = crate::R<Mm2sArcacheAruserSpec>;
+#[doc = "Register `mm2s_arcache_aruser` writer"]
+pub type W = crate::W<Mm2sArcacheAruserSpec>;
+#[doc = "Field `arcache` reader - Value driven on the m_axi_mm2s_arcache and m_axi_sg_arcache signals."]
+pub type ArcacheR = crate::FieldReader;
+#[doc = "Field `arcache` writer - Value driven on the m_axi_mm2s_arcache and m_axi_sg_arcache signals."]
+pub type ArcacheW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `aruser` reader - Value driven on the m_axi_mm2s_aruser and m_axi_sg_aruser signals."]
+pub type AruserR = crate::FieldReader;
+#[doc = "Field `aruser` writer - Value driven on the m_axi_mm2s_aruser and m_axi_sg_aruser signals."]
+pub type AruserW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+impl R {
+    #[doc = "Bits 0:3 - Value driven on the m_axi_mm2s_arcache and m_axi_sg_arcache signals."]
+    #[inline(always)]
+    pub fn arcache(&self) -> ArcacheR {
+        ArcacheR::new((self.bits & 0x0f) as u8)
+    }
+    #[doc = "Bits 8:11 - Value driven on the m_axi_mm2s_aruser and m_axi_sg_aruser signals."]
+    #[inline(always)]
+    pub fn aruser(&self) -> AruserR {
+        AruserR::new(((self.bits >> 8) & 0x0f) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:3 - Value driven on the m_axi_mm2s_arcache and m_axi_sg_arcache signals."]
+    #[inline(always)]
+    #[must_use]
+    pub fn arcache(&mut self) -> ArcacheW<Mm2sArcacheAruserSpec> {
+        ArcacheW::new(self, 0)
+    }
+    #[doc = "Bits 8:11 - Value driven on the m_axi_mm2s_aruser and m_axi_sg_aruser signals."]
+    #[inline(always)]
+    #[must_use]
+    pub fn aruser(&mut self) -> AruserW<Mm2sArcacheAruserSpec> {
+        AruserW::new(self, 8)
+    }
+}
+#[doc = "MM2S ARCACHE and ARUSER values for the data and SG interfaces\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_arcache_aruser::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_arcache_aruser::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sArcacheAruserSpec;
+impl crate::RegisterSpec for Mm2sArcacheAruserSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_arcache_aruser::R`](R) reader structure"]
+impl crate::Readable for Mm2sArcacheAruserSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_arcache_aruser::W`](W) writer structure"]
+impl crate::Writable for Mm2sArcacheAruserSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_arcache_aruser to value 0"]
+impl crate::Resettable for Mm2sArcacheAruserSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,55 @@
+#[doc = "Register `mm2s_ccr` reader"]
+pub type R = crate::R<Mm2sCcrSpec>;
+#[doc = "Register `mm2s_ccr` writer"]
+pub type W = crate::W<Mm2sCcrSpec>;
+#[doc = "Field `run_stop` reader - Run / Stop control for the MCDMA engine of this direction."]
+pub type RunStopR = crate::BitReader;
+#[doc = "Field `run_stop` writer - Run / Stop control for the MCDMA engine of this direction."]
+pub type RunStopW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `reset` reader - Soft reset for resetting the AXI MCDMA core."]
+pub type ResetR = crate::BitReader;
+#[doc = "Field `reset` writer - Soft reset for resetting the AXI MCDMA core."]
+pub type ResetW<'a, REG> = crate::BitWriter<'a, REG>;
+impl R {
+    #[doc = "Bit 0 - Run / Stop control for the MCDMA engine of this direction."]
+    #[inline(always)]
+    pub fn run_stop(&self) -> RunStopR {
+        RunStopR::new((self.bits & 1) != 0)
+    }
+    #[doc = "Bit 2 - Soft reset for resetting the AXI MCDMA core."]
+    #[inline(always)]
+    pub fn reset(&self) -> ResetR {
+        ResetR::new(((self.bits >> 2) & 1) != 0)
+    }
+}
+impl W {
+    #[doc = "Bit 0 - Run / Stop control for the MCDMA engine of this direction."]
+    #[inline(always)]
+    #[must_use]
+    pub fn run_stop(&mut self) -> RunStopW<Mm2sCcrSpec> {
+        RunStopW::new(self, 0)
+    }
+    #[doc = "Bit 2 - Soft reset for resetting the AXI MCDMA core."]
+    #[inline(always)]
+    #[must_use]
+    pub fn reset(&mut self) -> ResetW<Mm2sCcrSpec> {
+        ResetW::new(self, 2)
+    }
+}
+#[doc = "MM2S Common Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_ccr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_ccr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sCcrSpec;
+impl crate::RegisterSpec for Mm2sCcrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_ccr::R`](R) reader structure"]
+impl crate::Readable for Mm2sCcrSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_ccr::W`](W) writer structure"]
+impl crate::Writable for Mm2sCcrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_ccr to value 0"]
+impl crate::Resettable for Mm2sCcrSpec {
+    const RESET_VALUE: u32 = 0;
+}
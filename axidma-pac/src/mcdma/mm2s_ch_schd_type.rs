@@ -0,0 +1,40 @@
+#[doc = "Register `mm2s_ch_schd_type` reader"]
+pub type R = crate::R<Mm2sChSchdTypeSpec>;
+#[doc = "Register `mm2s_ch_schd_type` writer"]
+pub type W = crate::W<Mm2sChSchdTypeSpec>;
+#[doc = "Field `schd_type` reader - Scheduler type. 0: round robin, 1: strict priority, 2: weighted round robin."]
+pub type SchdTypeR = crate::FieldReader;
+#[doc = "Field `schd_type` writer - Scheduler type. 0: round robin, 1: strict priority, 2: weighted round robin."]
+pub type SchdTypeW<'a, REG> = crate::FieldWriter<'a, REG, 2>;
+impl R {
+    #[doc = "Bits 0:1 - Scheduler type. 0: round robin, 1: strict priority, 2: weighted round robin."]
+    #[inline(always)]
+    pub fn schd_type(&self) -> SchdTypeR {
+        SchdTypeR::new((self.bits & 0x03) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:1 - Scheduler type. 0: round robin, 1: strict priority, 2: weighted round robin."]
+    #[inline(always)]
+    #[must_use]
+    pub fn schd_type(&mut self) -> SchdTypeW<Mm2sChSchdTypeSpec> {
+        SchdTypeW::new(self, 0)
+    }
+}
+#[doc = "MM2S Channel Scheduler Type\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_ch_schd_type::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_ch_schd_type::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sChSchdTypeSpec;
+impl crate::RegisterSpec for Mm2sChSchdTypeSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_ch_schd_type::R`](R) reader structure"]
+impl crate::Readable for Mm2sChSchdTypeSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_ch_schd_type::W`](W) writer structure"]
+impl crate::Writable for Mm2sChSchdTypeSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_ch_schd_type to value 0"]
+impl crate::Resettable for Mm2sChSchdTypeSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,22 @@
+#[doc = "Register `mm2s_channels_serviced` reader"]
+pub type R = crate::R<Mm2sChannelsServicedSpec>;
+#[doc = "Field `serviced` reader - Each bit is set once the channel with the same index has been serviced."]
+pub type ServicedR = crate::FieldReader<u16>;
+impl R {
+    #[doc = "Bits 0:15 - Each bit is set once the channel with the same index has been serviced."]
+    #[inline(always)]
+    pub fn serviced(&self) -> ServicedR {
+        ServicedR::new((self.bits & 0xffff) as u16)
+    }
+}
+#[doc = "MM2S Channels Completed register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_channels_serviced::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sChannelsServicedSpec;
+impl crate::RegisterSpec for Mm2sChannelsServicedSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_channels_serviced::R`](R) reader structure"]
+impl crate::Readable for Mm2sChannelsServicedSpec {}
+#[doc = "`reset()` method sets mm2s_channels_serviced to value 0"]
+impl crate::Resettable for Mm2sChannelsServicedSpec {
+    const RESET_VALUE: u32 = 0;
+}
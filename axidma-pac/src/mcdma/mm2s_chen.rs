@@ -0,0 +1,40 @@
+#[doc = "Register `mm2s_chen` reader"]
+pub type R = crate::R<Mm2sChenSpec>;
+#[doc = "Register `mm2s_chen` writer"]
+pub type W = crate::W<Mm2sChenSpec>;
+#[doc = "Field `chen` reader - Channel enable. Each bit enables the channel with the same index."]
+pub type ChenR = crate::FieldReader<u16>;
+#[doc = "Field `chen` writer - Channel enable. Each bit enables the channel with the same index."]
+pub type ChenW<'a, REG> = crate::FieldWriter<'a, REG, 16, u16>;
+impl R {
+    #[doc = "Bits 0:15 - Channel enable. Each bit enables the channel with the same index."]
+    #[inline(always)]
+    pub fn chen(&self) -> ChenR {
+        ChenR::new((self.bits & 0xffff) as u16)
+    }
+}
+impl W {
+    #[doc = "Bits 0:15 - Channel enable. Each bit enables the channel with the same index."]
+    #[inline(always)]
+    #[must_use]
+    pub fn chen(&mut self) -> ChenW<Mm2sChenSpec> {
+        ChenW::new(self, 0)
+    }
+}
+#[doc = "MM2S Channel Enable/Disable register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_chen::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_chen::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sChenSpec;
+impl crate::RegisterSpec for Mm2sChenSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_chen::R`](R) reader structure"]
+impl crate::Readable for Mm2sChenSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_chen::W`](W) writer structure"]
+impl crate::Writable for Mm2sChenSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_chen to value 0"]
+impl crate::Resettable for Mm2sChenSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,22 @@
+#[doc = "Register `mm2s_chser` reader"]
+pub type R = crate::R<Mm2sChserSpec>;
+#[doc = "Field `chser` reader - Channels in progress. Each bit is set while the channel with the same index is being serviced."]
+pub type ChserR = crate::FieldReader<u16>;
+impl R {
+    #[doc = "Bits 0:15 - Channels in progress. Each bit is set while the channel with the same index is being serviced."]
+    #[inline(always)]
+    pub fn chser(&self) -> ChserR {
+        ChserR::new((self.bits & 0xffff) as u16)
+    }
+}
+#[doc = "MM2S Channel In Progress register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_chser::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sChserSpec;
+impl crate::RegisterSpec for Mm2sChserSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_chser::R`](R) reader structure"]
+impl crate::Readable for Mm2sChserSpec {}
+#[doc = "`reset()` method sets mm2s_chser to value 0"]
+impl crate::Resettable for Mm2sChserSpec {
+    const RESET_VALUE: u32 = 0;
+}
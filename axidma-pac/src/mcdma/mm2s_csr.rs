@@ -0,0 +1,29 @@
+#[doc = "Register `mm2s_csr` reader"]
+pub type R = crate::R<Mm2sCsrSpec>;
+#[doc = "Field `halted` reader - The DMA engine of this direction is halted."]
+pub type HaltedR = crate::BitReader;
+#[doc = "Field `idle` reader - The DMA engine of this direction is idle."]
+pub type IdleR = crate::BitReader;
+impl R {
+    #[doc = "Bit 0 - The DMA engine of this direction is halted."]
+    #[inline(always)]
+    pub fn halted(&self) -> HaltedR {
+        HaltedR::new((self.bits & 1) != 0)
+    }
+    #[doc = "Bit 1 - The DMA engine of this direction is idle."]
+    #[inline(always)]
+    pub fn idle(&self) -> IdleR {
+        IdleR::new(((self.bits >> 1) & 1) != 0)
+    }
+}
+#[doc = "MM2S Common Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_csr::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sCsrSpec;
+impl crate::RegisterSpec for Mm2sCsrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_csr::R`](R) reader structure"]
+impl crate::Readable for Mm2sCsrSpec {}
+#[doc = "`reset()` method sets mm2s_csr to value 0"]
+impl crate::Resettable for Mm2sCsrSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,57 @@
+#[doc = "Register `mm2s_err` reader"]
+pub type R = crate::R<Mm2sErrSpec>;
+#[doc = "Field `dma_int_err` reader - DMA Internal Error."]
+pub type DmaIntErrR = crate::BitReader;
+#[doc = "Field `dma_slv_err` reader - DMA Slave Error."]
+pub type DmaSlvErrR = crate::BitReader;
+#[doc = "Field `dma_dec_err` reader - DMA Decode Error."]
+pub type DmaDecErrR = crate::BitReader;
+#[doc = "Field `sg_int_err` reader - Scatter Gather Internal Error."]
+pub type SgIntErrR = crate::BitReader;
+#[doc = "Field `sg_slv_err` reader - Scatter Gather Slave Error."]
+pub type SgSlvErrR = crate::BitReader;
+#[doc = "Field `sg_dec_err` reader - Scatter Gather Decode Error."]
+pub type SgDecErrR = crate::BitReader;
+impl R {
+    #[doc = "Bit 0 - DMA Internal Error."]
+    #[inline(always)]
+    pub fn dma_int_err(&self) -> DmaIntErrR {
+        DmaIntErrR::new((self.bits & 1) != 0)
+    }
+    #[doc = "Bit 1 - DMA Slave Error."]
+    #[inline(always)]
+    pub fn dma_slv_err(&self) -> DmaSlvErrR {
+        DmaSlvErrR::new(((self.bits >> 1) & 1) != 0)
+    }
+    #[doc = "Bit 2 - DMA Decode Error."]
+    #[inline(always)]
+    pub fn dma_dec_err(&self) -> DmaDecErrR {
+        DmaDecErrR::new(((self.bits >> 2) & 1) != 0)
+    }
+    #[doc = "Bit 4 - Scatter Gather Internal Error."]
+    #[inline(always)]
+    pub fn sg_int_err(&self) -> SgIntErrR {
+        SgIntErrR::new(((self.bits >> 4) & 1) != 0)
+    }
+    #[doc = "Bit 5 - Scatter Gather Slave Error."]
+    #[inline(always)]
+    pub fn sg_slv_err(&self) -> SgSlvErrR {
+        SgSlvErrR::new(((self.bits >> 5) & 1) != 0)
+    }
+    #[doc = "Bit 6 - Scatter Gather Decode Error."]
+    #[inline(always)]
+    pub fn sg_dec_err(&self) -> SgDecErrR {
+        SgDecErrR::new(((self.bits >> 6) & 1) != 0)
+    }
+}
+#[doc = "MM2S Error register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_err::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sErrSpec;
+impl crate::RegisterSpec for Mm2sErrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_err::R`](R) reader structure"]
+impl crate::Readable for Mm2sErrSpec {}
+#[doc = "`reset()` method sets mm2s_err to value 0"]
+impl crate::Resettable for Mm2sErrSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,22 @@
+#[doc = "Register `mm2s_intr_status` reader"]
+pub type R = crate::R<Mm2sIntrStatusSpec>;
+#[doc = "Field `intr_status` reader - Each bit is set while the channel with the same index has a pending interrupt."]
+pub type IntrStatusR = crate::FieldReader<u16>;
+impl R {
+    #[doc = "Bits 0:15 - Each bit is set while the channel with the same index has a pending interrupt."]
+    #[inline(always)]
+    pub fn intr_status(&self) -> IntrStatusR {
+        IntrStatusR::new((self.bits & 0xffff) as u16)
+    }
+}
+#[doc = "MM2S Channel Interrupt Monitor register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_intr_status::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sIntrStatusSpec;
+impl crate::RegisterSpec for Mm2sIntrStatusSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_intr_status::R`](R) reader structure"]
+impl crate::Readable for Mm2sIntrStatusSpec {}
+#[doc = "`reset()` method sets mm2s_intr_status to value 0"]
+impl crate::Resettable for Mm2sIntrStatusSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,145 @@
+#[doc = "Register `mm2s_wrr_reg1` reader"]
+pub type R = crate::R<Mm2sWrrReg1Spec>;
+#[doc = "Register `mm2s_wrr_reg1` writer"]
+pub type W = crate::W<Mm2sWrrReg1Spec>;
+#[doc = "Field `ch0_weight` reader - Weight of channel 0."]
+pub type Ch0WeightR = crate::FieldReader;
+#[doc = "Field `ch0_weight` writer - Weight of channel 0."]
+pub type Ch0WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch1_weight` reader - Weight of channel 1."]
+pub type Ch1WeightR = crate::FieldReader;
+#[doc = "Field `ch1_weight` writer - Weight of channel 1."]
+pub type Ch1WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch2_weight` reader - Weight of channel 2."]
+pub type Ch2WeightR = crate::FieldReader;
+#[doc = "Field `ch2_weight` writer - Weight of channel 2."]
+pub type Ch2WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch3_weight` reader - Weight of channel 3."]
+pub type Ch3WeightR = crate::FieldReader;
+#[doc = "Field `ch3_weight` writer - Weight of channel 3."]
+pub type Ch3WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch4_weight` reader - Weight of channel 4."]
+pub type Ch4WeightR = crate::FieldReader;
+#[doc = "Field `ch4_weight` writer - Weight of channel 4."]
+pub type Ch4WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch5_weight` reader - Weight of channel 5."]
+pub type Ch5WeightR = crate::FieldReader;
+#[doc = "Field `ch5_weight` writer - Weight of channel 5."]
+pub type Ch5WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch6_weight` reader - Weight of channel 6."]
+pub type Ch6WeightR = crate::FieldReader;
+#[doc = "Field `ch6_weight` writer - Weight of channel 6."]
+pub type Ch6WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch7_weight` reader - Weight of channel 7."]
+pub type Ch7WeightR = crate::FieldReader;
+#[doc = "Field `ch7_weight` writer - Weight of channel 7."]
+pub type Ch7WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+impl R {
+    #[doc = "Bits 0:3 - Weight of channel 0."]
+    #[inline(always)]
+    pub fn ch0_weight(&self) -> Ch0WeightR {
+        Ch0WeightR::new((self.bits & 0x0f) as u8)
+    }
+    #[doc = "Bits 4:7 - Weight of channel 1."]
+    #[inline(always)]
+    pub fn ch1_weight(&self) -> Ch1WeightR {
+        Ch1WeightR::new(((self.bits >> 4) & 0x0f) as u8)
+    }
+    #[doc = "Bits 8:11 - Weight of channel 2."]
+    #[inline(always)]
+    pub fn ch2_weight(&self) -> Ch2WeightR {
+        Ch2WeightR::new(((self.bits >> 8) & 0x0f) as u8)
+    }
+    #[doc = "Bits 12:15 - Weight of channel 3."]
+    #[inline(always)]
+    pub fn ch3_weight(&self) -> Ch3WeightR {
+        Ch3WeightR::new(((self.bits >> 12) & 0x0f) as u8)
+    }
+    #[doc = "Bits 16:19 - Weight of channel 4."]
+    #[inline(always)]
+    pub fn ch4_weight(&self) -> Ch4WeightR {
+        Ch4WeightR::new(((self.bits >> 16) & 0x0f) as u8)
+    }
+    #[doc = "Bits 20:23 - Weight of channel 5."]
+    #[inline(always)]
+    pub fn ch5_weight(&self) -> Ch5WeightR {
+        Ch5WeightR::new(((self.bits >> 20) & 0x0f) as u8)
+    }
+    #[doc = "Bits 24:27 - Weight of channel 6."]
+    #[inline(always)]
+    pub fn ch6_weight(&self) -> Ch6WeightR {
+        Ch6WeightR::new(((self.bits >> 24) & 0x0f) as u8)
+    }
+    #[doc = "Bits 28:31 - Weight of channel 7."]
+    #[inline(always)]
+    pub fn ch7_weight(&self) -> Ch7WeightR {
+        Ch7WeightR::new(((self.bits >> 28) & 0x0f) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:3 - Weight of channel 0."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch0_weight(&mut self) -> Ch0WeightW<Mm2sWrrReg1Spec> {
+        Ch0WeightW::new(self, 0)
+    }
+    #[doc = "Bits 4:7 - Weight of channel 1."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch1_weight(&mut self) -> Ch1WeightW<Mm2sWrrReg1Spec> {
+        Ch1WeightW::new(self, 4)
+    }
+    #[doc = "Bits 8:11 - Weight of channel 2."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch2_weight(&mut self) -> Ch2WeightW<Mm2sWrrReg1Spec> {
+        Ch2WeightW::new(self, 8)
+    }
+    #[doc = "Bits 12:15 - Weight of channel 3."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch3_weight(&mut self) -> Ch3WeightW<Mm2sWrrReg1Spec> {
+        Ch3WeightW::new(self, 12)
+    }
+    #[doc = "Bits 16:19 - Weight of channel 4."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch4_weight(&mut self) -> Ch4WeightW<Mm2sWrrReg1Spec> {
+        Ch4WeightW::new(self, 16)
+    }
+    #[doc = "Bits 20:23 - Weight of channel 5."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch5_weight(&mut self) -> Ch5WeightW<Mm2sWrrReg1Spec> {
+        Ch5WeightW::new(self, 20)
+    }
+    #[doc = "Bits 24:27 - Weight of channel 6."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch6_weight(&mut self) -> Ch6WeightW<Mm2sWrrReg1Spec> {
+        Ch6WeightW::new(self, 24)
+    }
+    #[doc = "Bits 28:31 - Weight of channel 7."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch7_weight(&mut self) -> Ch7WeightW<Mm2sWrrReg1Spec> {
+        Ch7WeightW::new(self, 28)
+    }
+}
+#[doc = "MM2S Weighted Round Robin weights of channel 0 to 7\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_wrr_reg1::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_wrr_reg1::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sWrrReg1Spec;
+impl crate::RegisterSpec for Mm2sWrrReg1Spec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_wrr_reg1::R`](R) reader structure"]
+impl crate::Readable for Mm2sWrrReg1Spec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_wrr_reg1::W`](W) writer structure"]
+impl crate::Writable for Mm2sWrrReg1Spec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_wrr_reg1 to value 0"]
+impl crate::Resettable for Mm2sWrrReg1Spec {
+    const RESET_VALUE: u32 = 0;
+}
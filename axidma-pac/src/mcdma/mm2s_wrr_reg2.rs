@@ -0,0 +1,145 @@
+#[doc = "Register `mm2s_wrr_reg2` reader"]
+pub type R = crate::R<Mm2sWrrReg2Spec>;
+#[doc = "Register `mm2s_wrr_reg2` writer"]
+pub type W = crate::W<Mm2sWrrReg2Spec>;
+#[doc = "Field `ch8_weight` reader - Weight of channel 8."]
+pub type Ch8WeightR = crate::FieldReader;
+#[doc = "Field `ch8_weight` writer - Weight of channel 8."]
+pub type Ch8WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch9_weight` reader - Weight of channel 9."]
+pub type Ch9WeightR = crate::FieldReader;
+#[doc = "Field `ch9_weight` writer - Weight of channel 9."]
+pub type Ch9WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch10_weight` reader - Weight of channel 10."]
+pub type Ch10WeightR = crate::FieldReader;
+#[doc = "Field `ch10_weight` writer - Weight of channel 10."]
+pub type Ch10WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch11_weight` reader - Weight of channel 11."]
+pub type Ch11WeightR = crate::FieldReader;
+#[doc = "Field `ch11_weight` writer - Weight of channel 11."]
+pub type Ch11WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch12_weight` reader - Weight of channel 12."]
+pub type Ch12WeightR = crate::FieldReader;
+#[doc = "Field `ch12_weight` writer - Weight of channel 12."]
+pub type Ch12WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch13_weight` reader - Weight of channel 13."]
+pub type Ch13WeightR = crate::FieldReader;
+#[doc = "Field `ch13_weight` writer - Weight of channel 13."]
+pub type Ch13WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch14_weight` reader - Weight of channel 14."]
+pub type Ch14WeightR = crate::FieldReader;
+#[doc = "Field `ch14_weight` writer - Weight of channel 14."]
+pub type Ch14WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `ch15_weight` reader - Weight of channel 15."]
+pub type Ch15WeightR = crate::FieldReader;
+#[doc = "Field `ch15_weight` writer - Weight of channel 15."]
+pub type Ch15WeightW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+impl R {
+    #[doc = "Bits 0:3 - Weight of channel 8."]
+    #[inline(always)]
+    pub fn ch8_weight(&self) -> Ch8WeightR {
+        Ch8WeightR::new((self.bits & 0x0f) as u8)
+    }
+    #[doc = "Bits 4:7 - Weight of channel 9."]
+    #[inline(always)]
+    pub fn ch9_weight(&self) -> Ch9WeightR {
+        Ch9WeightR::new(((self.bits >> 4) & 0x0f) as u8)
+    }
+    #[doc = "Bits 8:11 - Weight of channel 10."]
+    #[inline(always)]
+    pub fn ch10_weight(&self) -> Ch10WeightR {
+        Ch10WeightR::new(((self.bits >> 8) & 0x0f) as u8)
+    }
+    #[doc = "Bits 12:15 - Weight of channel 11."]
+    #[inline(always)]
+    pub fn ch11_weight(&self) -> Ch11WeightR {
+        Ch11WeightR::new(((self.bits >> 12) & 0x0f) as u8)
+    }
+    #[doc = "Bits 16:19 - Weight of channel 12."]
+    #[inline(always)]
+    pub fn ch12_weight(&self) -> Ch12WeightR {
+        Ch12WeightR::new(((self.bits >> 16) & 0x0f) as u8)
+    }
+    #[doc = "Bits 20:23 - Weight of channel 13."]
+    #[inline(always)]
+    pub fn ch13_weight(&self) -> Ch13WeightR {
+        Ch13WeightR::new(((self.bits >> 20) & 0x0f) as u8)
+    }
+    #[doc = "Bits 24:27 - Weight of channel 14."]
+    #[inline(always)]
+    pub fn ch14_weight(&self) -> Ch14WeightR {
+        Ch14WeightR::new(((self.bits >> 24) & 0x0f) as u8)
+    }
+    #[doc = "Bits 28:31 - Weight of channel 15."]
+    #[inline(always)]
+    pub fn ch15_weight(&self) -> Ch15WeightR {
+        Ch15WeightR::new(((self.bits >> 28) & 0x0f) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:3 - Weight of channel 8."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch8_weight(&mut self) -> Ch8WeightW<Mm2sWrrReg2Spec> {
+        Ch8WeightW::new(self, 0)
+    }
+    #[doc = "Bits 4:7 - Weight of channel 9."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch9_weight(&mut self) -> Ch9WeightW<Mm2sWrrReg2Spec> {
+        Ch9WeightW::new(self, 4)
+    }
+    #[doc = "Bits 8:11 - Weight of channel 10."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch10_weight(&mut self) -> Ch10WeightW<Mm2sWrrReg2Spec> {
+        Ch10WeightW::new(self, 8)
+    }
+    #[doc = "Bits 12:15 - Weight of channel 11."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch11_weight(&mut self) -> Ch11WeightW<Mm2sWrrReg2Spec> {
+        Ch11WeightW::new(self, 12)
+    }
+    #[doc = "Bits 16:19 - Weight of channel 12."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch12_weight(&mut self) -> Ch12WeightW<Mm2sWrrReg2Spec> {
+        Ch12WeightW::new(self, 16)
+    }
+    #[doc = "Bits 20:23 - Weight of channel 13."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch13_weight(&mut self) -> Ch13WeightW<Mm2sWrrReg2Spec> {
+        Ch13WeightW::new(self, 20)
+    }
+    #[doc = "Bits 24:27 - Weight of channel 14."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch14_weight(&mut self) -> Ch14WeightW<Mm2sWrrReg2Spec> {
+        Ch14WeightW::new(self, 24)
+    }
+    #[doc = "Bits 28:31 - Weight of channel 15."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ch15_weight(&mut self) -> Ch15WeightW<Mm2sWrrReg2Spec> {
+        Ch15WeightW::new(self, 28)
+    }
+}
+#[doc = "MM2S Weighted Round Robin weights of channel 8 to 15\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_wrr_reg2::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_wrr_reg2::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sWrrReg2Spec;
+impl crate::RegisterSpec for Mm2sWrrReg2Spec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_wrr_reg2::R`](R) reader structure"]
+impl crate::Readable for Mm2sWrrReg2Spec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_wrr_reg2::W`](W) writer structure"]
+impl crate::Writable for Mm2sWrrReg2Spec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_wrr_reg2 to value 0"]
+impl crate::Resettable for Mm2sWrrReg2Spec {
+    const RESET_VALUE: u32 = 0;
+}
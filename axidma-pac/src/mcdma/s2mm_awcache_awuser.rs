@@ -0,0 +1,55 @@
+#[doc = "Register `s2mm_awcache_awuser` reader"]
+pub type R = crate::R<S2mmAwcacheAwuserSpec>;
+#[doc = "Register `s2mm_awcache_awuser` writer"]
+pub type W = crate::W<S2mmAwcacheAwuserSpec>;
+#[doc = "Field `awcache` reader - Value driven on the m_axi_s2mm_awcache and m_axi_sg_awcache signals."]
+pub type AwcacheR = crate::FieldReader;
+#[doc = "Field `awcache` writer - Value driven on the m_axi_s2mm_awcache and m_axi_sg_awcache signals."]
+pub type AwcacheW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `awuser` reader - Value driven on the m_axi_s2mm_awuser and m_axi_sg_awuser signals."]
+pub type AwuserR = crate::FieldReader;
+#[doc = "Field `awuser` writer - Value driven on the m_axi_s2mm_awuser and m_axi_sg_awuser signals."]
+pub type AwuserW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+impl R {
+    #[doc = "Bits 0:3 - Value driven on the m_axi_s2mm_awcache and m_axi_sg_awcache signals."]
+    #[inline(always)]
+    pub fn awcache(&self) -> AwcacheR {
+        AwcacheR::new((self.bits & 0x0f) as u8)
+    }
+    #[doc = "Bits 8:11 - Value driven on the m_axi_s2mm_awuser and m_axi_sg_awuser signals."]
+    #[inline(always)]
+    pub fn awuser(&self) -> AwuserR {
+        AwuserR::new(((self.bits >> 8) & 0x0f) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:3 - Value driven on the m_axi_s2mm_awcache and m_axi_sg_awcache signals."]
+    #[inline(always)]
+    #[must_use]
+    pub fn awcache(&mut self) -> AwcacheW<S2mmAwcacheAwuserSpec> {
+        AwcacheW::new(self, 0)
+    }
+    #[doc = "Bits 8:11 - Value driven on the m_axi_s2mm_awuser and m_axi_sg_awuser signals."]
+    #[inline(always)]
+    #[must_use]
+    pub fn awuser(&mut self) -> AwuserW<S2mmAwcacheAwuserSpec> {
+        AwuserW::new(self, 8)
+    }
+}
+#[doc = "S2MM AWCACHE and AWUSER values for the data and SG interfaces\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`s2mm_awcache_awuser::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`s2mm_awcache_awuser::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct S2mmAwcacheAwuserSpec;
+impl crate::RegisterSpec for S2mmAwcacheAwuserSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`s2mm_awcache_awuser::R`](R) reader structure"]
+impl crate::Readable for S2mmAwcacheAwuserSpec {}
+#[doc = "`write(|w| ..)` method takes [`s2mm_awcache_awuser::W`](W) writer structure"]
+impl crate::Writable for S2mmAwcacheAwuserSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets s2mm_awcache_awuser to value 0"]
+impl crate::Resettable for S2mmAwcacheAwuserSpec {
+    const RESET_VALUE: u32 = 0;
+}
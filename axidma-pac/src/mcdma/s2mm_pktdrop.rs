@@ -0,0 +1,22 @@
+#[doc = "Register `s2mm_pktdrop` reader"]
+pub type R = crate::R<S2mmPktdropSpec>;
+#[doc = "Field `pktdrop` reader - Number of packets dropped because the destination channel was disabled or had no descriptor."]
+pub type PktdropR = crate::FieldReader<u32>;
+impl R {
+    #[doc = "Bits 0:31 - Number of packets dropped because the destination channel was disabled or had no descriptor."]
+    #[inline(always)]
+    pub fn pktdrop(&self) -> PktdropR {
+        PktdropR::new(self.bits)
+    }
+}
+#[doc = "S2MM Packet Drop Count register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`s2mm_pktdrop::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct S2mmPktdropSpec;
+impl crate::RegisterSpec for S2mmPktdropSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`s2mm_pktdrop::R`](R) reader structure"]
+impl crate::Readable for S2mmPktdropSpec {}
+#[doc = "`reset()` method sets s2mm_pktdrop to value 0"]
+impl crate::Resettable for S2mmPktdropSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,90 @@
+#[repr(C)]
+#[doc = "Register block"]
+pub struct RegisterBlock {
+    cr: Cr,
+    sr: Sr,
+    curdesc: Curdesc,
+    curdesc_msb: CurdescMsb,
+    taildesc: Taildesc,
+    taildesc_msb: TaildescMsb,
+    pktcount_stat: PktcountStat,
+}
+impl RegisterBlock {
+    #[doc = "0x00 - Channel Control register"]
+    #[inline(always)]
+    pub const fn cr(&self) -> &Cr {
+        &self.cr
+    }
+    #[doc = "0x04 - Channel Status register"]
+    #[inline(always)]
+    pub const fn sr(&self) -> &Sr {
+        &self.sr
+    }
+    #[doc = "0x08 - Current Descriptor Pointer. Lower 32 bits of the address."]
+    #[inline(always)]
+    pub const fn curdesc(&self) -> &Curdesc {
+        &self.curdesc
+    }
+    #[doc = "0x0c - Current Descriptor Pointer. Upper 32 bits of the address."]
+    #[inline(always)]
+    pub const fn curdesc_msb(&self) -> &CurdescMsb {
+        &self.curdesc_msb
+    }
+    #[doc = "0x10 - Tail Descriptor Pointer. Lower 32 bits of the address."]
+    #[inline(always)]
+    pub const fn taildesc(&self) -> &Taildesc {
+        &self.taildesc
+    }
+    #[doc = "0x14 - Tail Descriptor Pointer. Upper 32 bits of the address."]
+    #[inline(always)]
+    pub const fn taildesc_msb(&self) -> &TaildescMsb {
+        &self.taildesc_msb
+    }
+    #[doc = "0x18 - Channel Packet Processed count"]
+    #[inline(always)]
+    pub const fn pktcount_stat(&self) -> &PktcountStat {
+        &self.pktcount_stat
+    }
+}
+#[doc = "cr (rw) register accessor: Channel Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`cr::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`cr::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@cr`]
+module"]
+#[doc(alias = "cr")]
+pub type Cr = crate::Reg<cr::CrSpec>;
+#[doc = "Channel Control register"]
+pub mod cr;
+#[doc = "sr (rw) register accessor: Channel Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sr::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sr::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@sr`]
+module"]
+#[doc(alias = "sr")]
+pub type Sr = crate::Reg<sr::SrSpec>;
+#[doc = "Channel Status register"]
+pub mod sr;
+#[doc = "curdesc (rw) register accessor: Current Descriptor Pointer. Lower 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`curdesc::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`curdesc::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@curdesc`]
+module"]
+#[doc(alias = "curdesc")]
+pub type Curdesc = crate::Reg<curdesc::CurdescSpec>;
+#[doc = "Current Descriptor Pointer. Lower 32 bits of the address."]
+pub mod curdesc;
+#[doc = "curdesc_msb (rw) register accessor: Current Descriptor Pointer. Upper 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`curdesc_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`curdesc_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@curdesc_msb`]
+module"]
+#[doc(alias = "curdesc_msb")]
+pub type CurdescMsb = crate::Reg<curdesc_msb::CurdescMsbSpec>;
+#[doc = "Current Descriptor Pointer. Upper 32 bits of the address."]
+pub mod curdesc_msb;
+#[doc = "taildesc (rw) register accessor: Tail Descriptor Pointer. Lower 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`taildesc::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`taildesc::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@taildesc`]
+module"]
+#[doc(alias = "taildesc")]
+pub type Taildesc = crate::Reg<taildesc::TaildescSpec>;
+#[doc = "Tail Descriptor Pointer. Lower 32 bits of the address."]
+pub mod taildesc;
+#[doc = "taildesc_msb (rw) register accessor: Tail Descriptor Pointer. Upper 32 bits of the address.\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`taildesc_msb::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`taildesc_msb::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@taildesc_msb`]
+module"]
+#[doc(alias = "taildesc_msb")]
+pub type TaildescMsb = crate::Reg<taildesc_msb::TaildescMsbSpec>;
+#[doc = "Tail Descriptor Pointer. Upper 32 bits of the address."]
+pub mod taildesc_msb;
+#[doc = "pktcount_stat (r) register accessor: Channel Packet Processed count\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`pktcount_stat::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@pktcount_stat`]
+module"]
+#[doc(alias = "pktcount_stat")]
+pub type PktcountStat = crate::Reg<pktcount_stat::PktcountStatSpec>;
+#[doc = "Channel Packet Processed count"]
+pub mod pktcount_stat;
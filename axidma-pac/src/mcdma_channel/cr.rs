@@ -0,0 +1,130 @@
+#[doc = "Register `cr` reader"]
+pub type R = crate::R<CrSpec>;
+#[doc = "Register `cr` writer"]
+pub type W = crate::W<CrSpec>;
+#[doc = "Field `fetch` reader - Channel fetch. The channel fetches descriptors once it is set and the channel is enabled."]
+pub type FetchR = crate::BitReader;
+#[doc = "Field `fetch` writer - Channel fetch. The channel fetches descriptors once it is set and the channel is enabled."]
+pub type FetchW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `oth_ch_err_irq_en` reader - Interrupt on error of another channel enable."]
+pub type OthChErrIrqEnR = crate::BitReader;
+#[doc = "Field `oth_ch_err_irq_en` writer - Interrupt on error of another channel enable."]
+pub type OthChErrIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `ioc_irq_en` reader - Interrupt on Complete (IOC) Interrupt Enable"]
+pub type IocIrqEnR = crate::BitReader;
+#[doc = "Field `ioc_irq_en` writer - Interrupt on Complete (IOC) Interrupt Enable"]
+pub type IocIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dly_irq_en` reader - Interrupt on Delay Timer Interrupt Enable"]
+pub type DlyIrqEnR = crate::BitReader;
+#[doc = "Field `dly_irq_en` writer - Interrupt on Delay Timer Interrupt Enable"]
+pub type DlyIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `err_irq_en` reader - Interrupt on Error Interrupt Enable"]
+pub type ErrIrqEnR = crate::BitReader;
+#[doc = "Field `err_irq_en` writer - Interrupt on Error Interrupt Enable"]
+pub type ErrIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `irq_threshold` reader - Interrupt Threshold"]
+pub type IrqThresholdR = crate::FieldReader;
+#[doc = "Field `irq_threshold` writer - Interrupt Threshold"]
+pub type IrqThresholdW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+#[doc = "Field `irq_delay` reader - Interrupt Delay Time Out"]
+pub type IrqDelayR = crate::FieldReader;
+#[doc = "Field `irq_delay` writer - Interrupt Delay Time Out"]
+pub type IrqDelayW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+impl R {
+    #[doc = "Bit 0 - Channel fetch. The channel fetches descriptors once it is set and the channel is enabled."]
+    #[inline(always)]
+    pub fn fetch(&self) -> FetchR {
+        FetchR::new((self.bits & 1) != 0)
+    }
+    #[doc = "Bit 4 - Interrupt on error of another channel enable."]
+    #[inline(always)]
+    pub fn oth_ch_err_irq_en(&self) -> OthChErrIrqEnR {
+        OthChErrIrqEnR::new(((self.bits >> 4) & 1) != 0)
+    }
+    #[doc = "Bit 5 - Interrupt on Complete (IOC) Interrupt Enable"]
+    #[inline(always)]
+    pub fn ioc_irq_en(&self) -> IocIrqEnR {
+        IocIrqEnR::new(((self.bits >> 5) & 1) != 0)
+    }
+    #[doc = "Bit 6 - Interrupt on Delay Timer Interrupt Enable"]
+    #[inline(always)]
+    pub fn dly_irq_en(&self) -> DlyIrqEnR {
+        DlyIrqEnR::new(((self.bits >> 6) & 1) != 0)
+    }
+    #[doc = "Bit 7 - Interrupt on Error Interrupt Enable"]
+    #[inline(always)]
+    pub fn err_irq_en(&self) -> ErrIrqEnR {
+        ErrIrqEnR::new(((self.bits >> 7) & 1) != 0)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold"]
+    #[inline(always)]
+    pub fn irq_threshold(&self) -> IrqThresholdR {
+        IrqThresholdR::new(((self.bits >> 16) & 0xff) as u8)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Out"]
+    #[inline(always)]
+    pub fn irq_delay(&self) -> IrqDelayR {
+        IrqDelayR::new(((self.bits >> 24) & 0xff) as u8)
+    }
+}
+impl W {
+    #[doc = "Bit 0 - Channel fetch. The channel fetches descriptors once it is set and the channel is enabled."]
+    #[inline(always)]
+    #[must_use]
+    pub fn fetch(&mut self) -> FetchW<CrSpec> {
+        FetchW::new(self, 0)
+    }
+    #[doc = "Bit 4 - Interrupt on error of another channel enable."]
+    #[inline(always)]
+    #[must_use]
+    pub fn oth_ch_err_irq_en(&mut self) -> OthChErrIrqEnW<CrSpec> {
+        OthChErrIrqEnW::new(self, 4)
+    }
+    #[doc = "Bit 5 - Interrupt on Complete (IOC) Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn ioc_irq_en(&mut self) -> IocIrqEnW<CrSpec> {
+        IocIrqEnW::new(self, 5)
+    }
+    #[doc = "Bit 6 - Interrupt on Delay Timer Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn dly_irq_en(&mut self) -> DlyIrqEnW<CrSpec> {
+        DlyIrqEnW::new(self, 6)
+    }
+    #[doc = "Bit 7 - Interrupt on Error Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn err_irq_en(&mut self) -> ErrIrqEnW<CrSpec> {
+        ErrIrqEnW::new(self, 7)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_threshold(&mut self) -> IrqThresholdW<CrSpec> {
+        IrqThresholdW::new(self, 16)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Out"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_delay(&mut self) -> IrqDelayW<CrSpec> {
+        IrqDelayW::new(self, 24)
+    }
+}
+#[doc = "Channel Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`cr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`cr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct CrSpec;
+impl crate::RegisterSpec for CrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`cr::R`](R) reader structure"]
+impl crate::Readable for CrSpec {}
+#[doc = "`write(|w| ..)` method takes [`cr::W`](W) writer structure"]
+impl crate::Writable for CrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets cr to value 0"]
+impl crate::Resettable for CrSpec {
+    const RESET_VALUE: u32 = 0;
+}
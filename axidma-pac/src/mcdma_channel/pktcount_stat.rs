@@ -0,0 +1,22 @@
+#[doc = "Register `pktcount_stat` reader"]
+pub type R = crate::R<PktcountStatSpec>;
+#[doc = "Field `pktcount` reader - Number of packets processed by the channel."]
+pub type PktcountR = crate::FieldReader<u32>;
+impl R {
+    #[doc = "Bits 0:31 - Number of packets processed by the channel."]
+    #[inline(always)]
+    pub fn pktcount(&self) -> PktcountR {
+        PktcountR::new(self.bits)
+    }
+}
+#[doc = "Channel Packet Processed count\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`pktcount_stat::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct PktcountStatSpec;
+impl crate::RegisterSpec for PktcountStatSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`pktcount_stat::R`](R) reader structure"]
+impl crate::Readable for PktcountStatSpec {}
+#[doc = "`reset()` method sets pktcount_stat to value 0"]
+impl crate::Resettable for PktcountStatSpec {
+    const RESET_VALUE: u32 = 0;
+}
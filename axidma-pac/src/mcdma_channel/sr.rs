@@ -0,0 +1,130 @@
+#[doc = "Register `sr` reader"]
+pub type R = crate::R<SrSpec>;
+#[doc = "Register `sr` writer"]
+pub type W = crate::W<SrSpec>;
+#[doc = "Field `idle` reader - Channel idle. Set when the channel has no descriptor left to process."]
+pub type IdleR = crate::BitReader;
+#[doc = "Field `idle` writer - Channel idle. Set when the channel has no descriptor left to process."]
+pub type IdleW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `oth_ch_err_irq` reader - Error detected on another channel."]
+pub type OthChErrIrqR = crate::BitReader;
+#[doc = "Field `oth_ch_err_irq` writer - Error detected on another channel."]
+pub type OthChErrIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `ioc_irq` reader - Interrupt on Complete."]
+pub type IocIrqR = crate::BitReader;
+#[doc = "Field `ioc_irq` writer - Interrupt on Complete."]
+pub type IocIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dly_irq` reader - Interrupt on Delay."]
+pub type DlyIrqR = crate::BitReader;
+#[doc = "Field `dly_irq` writer - Interrupt on Delay."]
+pub type DlyIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `err_irq` reader - Interrupt on Error."]
+pub type ErrIrqR = crate::BitReader;
+#[doc = "Field `err_irq` writer - Interrupt on Error."]
+pub type ErrIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `irq_threshold_sts` reader - Interrupt Threshold Status"]
+pub type IrqThresholdStsR = crate::FieldReader;
+#[doc = "Field `irq_threshold_sts` writer - Interrupt Threshold Status"]
+pub type IrqThresholdStsW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+#[doc = "Field `irq_delay_sts` reader - Interrupt Delay Time Status"]
+pub type IrqDelayStsR = crate::FieldReader;
+#[doc = "Field `irq_delay_sts` writer - Interrupt Delay Time Status"]
+pub type IrqDelayStsW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+impl R {
+    #[doc = "Bit 0 - Channel idle. Set when the channel has no descriptor left to process."]
+    #[inline(always)]
+    pub fn idle(&self) -> IdleR {
+        IdleR::new((self.bits & 1) != 0)
+    }
+    #[doc = "Bit 4 - Error detected on another channel."]
+    #[inline(always)]
+    pub fn oth_ch_err_irq(&self) -> OthChErrIrqR {
+        OthChErrIrqR::new(((self.bits >> 4) & 1) != 0)
+    }
+    #[doc = "Bit 5 - Interrupt on Complete."]
+    #[inline(always)]
+    pub fn ioc_irq(&self) -> IocIrqR {
+        IocIrqR::new(((self.bits >> 5) & 1) != 0)
+    }
+    #[doc = "Bit 6 - Interrupt on Delay."]
+    #[inline(always)]
+    pub fn dly_irq(&self) -> DlyIrqR {
+        DlyIrqR::new(((self.bits >> 6) & 1) != 0)
+    }
+    #[doc = "Bit 7 - Interrupt on Error."]
+    #[inline(always)]
+    pub fn err_irq(&self) -> ErrIrqR {
+        ErrIrqR::new(((self.bits >> 7) & 1) != 0)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold Status"]
+    #[inline(always)]
+    pub fn irq_threshold_sts(&self) -> IrqThresholdStsR {
+        IrqThresholdStsR::new(((self.bits >> 16) & 0xff) as u8)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Status"]
+    #[inline(always)]
+    pub fn irq_delay_sts(&self) -> IrqDelayStsR {
+        IrqDelayStsR::new(((self.bits >> 24) & 0xff) as u8)
+    }
+}
+impl W {
+    #[doc = "Bit 0 - Channel idle. Set when the channel has no descriptor left to process."]
+    #[inline(always)]
+    #[must_use]
+    pub fn idle(&mut self) -> IdleW<SrSpec> {
+        IdleW::new(self, 0)
+    }
+    #[doc = "Bit 4 - Error detected on another channel."]
+    #[inline(always)]
+    #[must_use]
+    pub fn oth_ch_err_irq(&mut self) -> OthChErrIrqW<SrSpec> {
+        OthChErrIrqW::new(self, 4)
+    }
+    #[doc = "Bit 5 - Interrupt on Complete."]
+    #[inline(always)]
+    #[must_use]
+    pub fn ioc_irq(&mut self) -> IocIrqW<SrSpec> {
+        IocIrqW::new(self, 5)
+    }
+    #[doc = "Bit 6 - Interrupt on Delay."]
+    #[inline(always)]
+    #[must_use]
+    pub fn dly_irq(&mut self) -> DlyIrqW<SrSpec> {
+        DlyIrqW::new(self, 6)
+    }
+    #[doc = "Bit 7 - Interrupt on Error."]
+    #[inline(always)]
+    #[must_use]
+    pub fn err_irq(&mut self) -> ErrIrqW<SrSpec> {
+        ErrIrqW::new(self, 7)
+    }
+    #[doc = "Bits 16:23 - Interrupt Threshold Status"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_threshold_sts(&mut self) -> IrqThresholdStsW<SrSpec> {
+        IrqThresholdStsW::new(self, 16)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Time Status"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_delay_sts(&mut self) -> IrqDelayStsW<SrSpec> {
+        IrqDelayStsW::new(self, 24)
+    }
+}
+#[doc = "Channel Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct SrSpec;
+impl crate::RegisterSpec for SrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`sr::R`](R) reader structure"]
+impl crate::Readable for SrSpec {}
+#[doc = "`write(|w| ..)` method takes [`sr::W`](W) writer structure"]
+impl crate::Writable for SrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets sr to value 0"]
+impl crate::Resettable for SrSpec {
+    const RESET_VALUE: u32 = 0;
+}
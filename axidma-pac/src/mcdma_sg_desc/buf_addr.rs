@@ -0,0 +1,40 @@
+#[doc = "Register `buf_addr` reader"]
+pub type R = crate::R<BufAddrSpec>;
+#[doc = "Register `buf_addr` writer"]
+pub type W = crate::W<BufAddrSpec>;
+#[doc = "Field `buf_addr` reader - Provides the location of the data to transfer from Memory Map to Stream."]
+pub type BufAddrR = crate::FieldReader<u32>;
+#[doc = "Field `buf_addr` writer - Provides the location of the data to transfer from Memory Map to Stream."]
+pub type BufAddrW<'a, REG> = crate::FieldWriter<'a, REG, 32, u32>;
+impl R {
+    #[doc = "Bits 0:31 - Provides the location of the data to transfer from Memory Map to Stream."]
+    #[inline(always)]
+    pub fn buf_addr(&self) -> BufAddrR {
+        BufAddrR::new(self.bits)
+    }
+}
+impl W {
+    #[doc = "Bits 0:31 - Provides the location of the data to transfer from Memory Map to Stream."]
+    #[inline(always)]
+    #[must_use]
+    pub fn buf_addr(&mut self) -> BufAddrW<BufAddrSpec> {
+        BufAddrW::new(self, 0)
+    }
+}
+#[doc = "Buffer Address\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`buf_addr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`buf_addr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct BufAddrSpec;
+impl crate::RegisterSpec for BufAddrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`buf_addr::R`](R) reader structure"]
+impl crate::Readable for BufAddrSpec {}
+#[doc = "`write(|w| ..)` method takes [`buf_addr::W`](W) writer structure"]
+impl crate::Writable for BufAddrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets buf_addr to value 0"]
+impl crate::Resettable for BufAddrSpec {
+    const RESET_VALUE: u32 = 0;
+}
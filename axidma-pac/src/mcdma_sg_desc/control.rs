@@ -0,0 +1,70 @@
+#[doc = "Register `control` reader"]
+pub type R = crate::R<ControlSpec>;
+#[doc = "Register `control` writer"]
+pub type W = crate::W<ControlSpec>;
+#[doc = "Field `buf_len` reader - Indicates the amount of space in bytes of the stream."]
+pub type BufLenR = crate::FieldReader<u32>;
+#[doc = "Field `buf_len` writer - Indicates the amount of space in bytes of the stream."]
+pub type BufLenW<'a, REG> = crate::FieldWriter<'a, REG, 26, u32>;
+#[doc = "Field `eop` reader - End of Packet. Flag indicating the last buffer of the packet."]
+pub type EopR = crate::BitReader;
+#[doc = "Field `eop` writer - End of Packet. Flag indicating the last buffer of the packet."]
+pub type EopW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sop` reader - Start of Packet. Flag indicating the first buffer of the packet."]
+pub type SopR = crate::BitReader;
+#[doc = "Field `sop` writer - Start of Packet. Flag indicating the first buffer of the packet."]
+pub type SopW<'a, REG> = crate::BitWriter<'a, REG>;
+impl R {
+    #[doc = "Bits 0:25 - Indicates the amount of space in bytes of the stream."]
+    #[inline(always)]
+    pub fn buf_len(&self) -> BufLenR {
+        BufLenR::new(self.bits & 0x03ff_ffff)
+    }
+    #[doc = "Bit 30 - End of Packet. Flag indicating the last buffer of the packet."]
+    #[inline(always)]
+    pub fn eop(&self) -> EopR {
+        EopR::new(((self.bits >> 30) & 1) != 0)
+    }
+    #[doc = "Bit 31 - Start of Packet. Flag indicating the first buffer of the packet."]
+    #[inline(always)]
+    pub fn sop(&self) -> SopR {
+        SopR::new(((self.bits >> 31) & 1) != 0)
+    }
+}
+impl W {
+    #[doc = "Bits 0:25 - Indicates the amount of space in bytes of the stream."]
+    #[inline(always)]
+    #[must_use]
+    pub fn buf_len(&mut self) -> BufLenW<ControlSpec> {
+        BufLenW::new(self, 0)
+    }
+    #[doc = "Bit 30 - End of Packet. Flag indicating the last buffer of the packet."]
+    #[inline(always)]
+    #[must_use]
+    pub fn eop(&mut self) -> EopW<ControlSpec> {
+        EopW::new(self, 30)
+    }
+    #[doc = "Bit 31 - Start of Packet. Flag indicating the first buffer of the packet."]
+    #[inline(always)]
+    #[must_use]
+    pub fn sop(&mut self) -> SopW<ControlSpec> {
+        SopW::new(self, 31)
+    }
+}
+#[doc = "Control of BD\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`control::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`control::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct ControlSpec;
+impl crate::RegisterSpec for ControlSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`control::R`](R) reader structure"]
+impl crate::Readable for ControlSpec {}
+#[doc = "`write(|w| ..)` method takes [`control::W`](W) writer structure"]
+impl crate::Writable for ControlSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets control to value 0"]
+impl crate::Resettable for ControlSpec {
+    const RESET_VALUE: u32 = 0;
+}
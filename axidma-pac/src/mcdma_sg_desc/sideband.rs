@@ -0,0 +1,70 @@
+#[doc = "Register `sideband` reader"]
+pub type R = crate::R<SidebandSpec>;
+#[doc = "Register `sideband` writer"]
+pub type W = crate::W<SidebandSpec>;
+#[doc = "Field `tuser` reader - TUSER value driven on (MM2S) or captured from (S2MM) the stream."]
+pub type TuserR = crate::FieldReader<u16>;
+#[doc = "Field `tuser` writer - TUSER value driven on (MM2S) or captured from (S2MM) the stream."]
+pub type TuserW<'a, REG> = crate::FieldWriter<'a, REG, 16, u16>;
+#[doc = "Field `tdest` reader - TDEST value driven on (MM2S) or captured from (S2MM) the stream."]
+pub type TdestR = crate::FieldReader;
+#[doc = "Field `tdest` writer - TDEST value driven on (MM2S) or captured from (S2MM) the stream."]
+pub type TdestW<'a, REG> = crate::FieldWriter<'a, REG, 5>;
+#[doc = "Field `tid` reader - TID value driven on (MM2S) or captured from (S2MM) the stream."]
+pub type TidR = crate::FieldReader;
+#[doc = "Field `tid` writer - TID value driven on (MM2S) or captured from (S2MM) the stream."]
+pub type TidW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+impl R {
+    #[doc = "Bits 0:15 - TUSER value driven on (MM2S) or captured from (S2MM) the stream."]
+    #[inline(always)]
+    pub fn tuser(&self) -> TuserR {
+        TuserR::new((self.bits & 0xffff) as u16)
+    }
+    #[doc = "Bits 16:20 - TDEST value driven on (MM2S) or captured from (S2MM) the stream."]
+    #[inline(always)]
+    pub fn tdest(&self) -> TdestR {
+        TdestR::new(((self.bits >> 16) & 0x1f) as u8)
+    }
+    #[doc = "Bits 24:31 - TID value driven on (MM2S) or captured from (S2MM) the stream."]
+    #[inline(always)]
+    pub fn tid(&self) -> TidR {
+        TidR::new(((self.bits >> 24) & 0xff) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:15 - TUSER value driven on (MM2S) or captured from (S2MM) the stream."]
+    #[inline(always)]
+    #[must_use]
+    pub fn tuser(&mut self) -> TuserW<SidebandSpec> {
+        TuserW::new(self, 0)
+    }
+    #[doc = "Bits 16:20 - TDEST value driven on (MM2S) or captured from (S2MM) the stream."]
+    #[inline(always)]
+    #[must_use]
+    pub fn tdest(&mut self) -> TdestW<SidebandSpec> {
+        TdestW::new(self, 16)
+    }
+    #[doc = "Bits 24:31 - TID value driven on (MM2S) or captured from (S2MM) the stream."]
+    #[inline(always)]
+    #[must_use]
+    pub fn tid(&mut self) -> TidW<SidebandSpec> {
+        TidW::new(self, 24)
+    }
+}
+#[doc = "Stream sideband signals of BD\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`sideband::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`sideband::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct SidebandSpec;
+impl crate::RegisterSpec for SidebandSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`sideband::R`](R) reader structure"]
+impl crate::Readable for SidebandSpec {}
+#[doc = "`write(|w| ..)` method takes [`sideband::W`](W) writer structure"]
+impl crate::Writable for SidebandSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets sideband to value 0"]
+impl crate::Resettable for SidebandSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,64 @@
+#[doc = "Register `status` reader"]
+pub type R = crate::R<StatusSpec>;
+#[doc = "Field `tfer_bytes` reader - This value indicates the amount of data transferred by this descriptor."]
+pub type TferBytesR = crate::FieldReader<u32>;
+#[doc = "Field `rxeop` reader - End of Packet. Set by S2MM when the buffer holds the last part of a packet."]
+pub type RxeopR = crate::BitReader;
+#[doc = "Field `rxsop` reader - Start of Packet. Set by S2MM when the buffer holds the first part of a packet."]
+pub type RxsopR = crate::BitReader;
+#[doc = "Field `dma_int_err` reader - DMA Internal Error."]
+pub type DmaIntErrR = crate::BitReader;
+#[doc = "Field `dma_slv_err` reader - DMA Slave Error."]
+pub type DmaSlvErrR = crate::BitReader;
+#[doc = "Field `dma_dec_err` reader - DMA Decode Error."]
+pub type DmaDecErrR = crate::BitReader;
+#[doc = "Field `cmplt` reader - Completed. The DMA Engine has completed the transfer described by this descriptor."]
+pub type CmpltR = crate::BitReader;
+impl R {
+    #[doc = "Bits 0:25 - This value indicates the amount of data transferred by this descriptor."]
+    #[inline(always)]
+    pub fn tfer_bytes(&self) -> TferBytesR {
+        TferBytesR::new(self.bits & 0x03ff_ffff)
+    }
+    #[doc = "Bit 26 - End of Packet. Set by S2MM when the buffer holds the last part of a packet."]
+    #[inline(always)]
+    pub fn rxeop(&self) -> RxeopR {
+        RxeopR::new(((self.bits >> 26) & 1) != 0)
+    }
+    #[doc = "Bit 27 - Start of Packet. Set by S2MM when the buffer holds the first part of a packet."]
+    #[inline(always)]
+    pub fn rxsop(&self) -> RxsopR {
+        RxsopR::new(((self.bits >> 27) & 1) != 0)
+    }
+    #[doc = "Bit 28 - DMA Internal Error."]
+    #[inline(always)]
+    pub fn dma_int_err(&self) -> DmaIntErrR {
+        DmaIntErrR::new(((self.bits >> 28) & 1) != 0)
+    }
+    #[doc = "Bit 29 - DMA Slave Error."]
+    #[inline(always)]
+    pub fn dma_slv_err(&self) -> DmaSlvErrR {
+        DmaSlvErrR::new(((self.bits >> 29) & 1) != 0)
+    }
+    #[doc = "Bit 30 - DMA Decode Error."]
+    #[inline(always)]
+    pub fn dma_dec_err(&self) -> DmaDecErrR {
+        DmaDecErrR::new(((self.bits >> 30) & 1) != 0)
+    }
+    #[doc = "Bit 31 - Completed. The DMA Engine has completed the transfer described by this descriptor."]
+    #[inline(always)]
+    pub fn cmplt(&self) -> CmpltR {
+        CmpltR::new(((self.bits >> 31) & 1) != 0)
+    }
+}
+#[doc = "Status of BD\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`status::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct StatusSpec;
+impl crate::RegisterSpec for StatusSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`status::R`](R) reader structure"]
+impl crate::Readable for StatusSpec {}
+#[doc = "`reset()` method sets status to value 0"]
+impl crate::Resettable for StatusSpec {
+    const RESET_VALUE: u32 = 0;
+}
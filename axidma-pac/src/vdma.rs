@@ -0,0 +1,201 @@
+#[repr(C)]
+#[doc = "Register block"]
+pub struct RegisterBlock {
+    mm2s_vdmacr: Mm2sVdmacr,
+    mm2s_vdmasr: Mm2sVdmasr,
+    _reserved2: [u8; 0x10],
+    mm2s_frmstore: Mm2sFrmstore,
+    _reserved3: [u8; 0x0c],
+    park_ptr_reg: ParkPtrReg,
+    version: Version,
+    s2mm_vdmacr: S2mmVdmacr,
+    s2mm_vdmasr: S2mmVdmasr,
+    _reserved7: [u8; 0x10],
+    s2mm_frmstore: S2mmFrmstore,
+    _reserved8: [u8; 0x04],
+    mm2s_vsize: Mm2sVsize,
+    mm2s_hsize: Mm2sHsize,
+    mm2s_frmdly_stride: Mm2sFrmdlyStride,
+    mm2s_start_address: [Mm2sStartAddress; 16],
+    _reserved12: [u8; 0x04],
+    s2mm_vsize: S2mmVsize,
+    s2mm_hsize: S2mmHsize,
+    s2mm_frmdly_stride: S2mmFrmdlyStride,
+    s2mm_start_address: [S2mmStartAddress; 16],
+}
+impl RegisterBlock {
+    #[doc = "0x00 - MM2S VDMA Control register"]
+    #[inline(always)]
+    pub const fn mm2s_vdmacr(&self) -> &Mm2sVdmacr {
+        &self.mm2s_vdmacr
+    }
+    #[doc = "0x04 - MM2S VDMA Status register"]
+    #[inline(always)]
+    pub const fn mm2s_vdmasr(&self) -> &Mm2sVdmasr {
+        &self.mm2s_vdmasr
+    }
+    #[doc = "0x18 - MM2S Frame Store register"]
+    #[inline(always)]
+    pub const fn mm2s_frmstore(&self) -> &Mm2sFrmstore {
+        &self.mm2s_frmstore
+    }
+    #[doc = "0x28 - Park Pointer register"]
+    #[inline(always)]
+    pub const fn park_ptr_reg(&self) -> &ParkPtrReg {
+        &self.park_ptr_reg
+    }
+    #[doc = "0x2c - Video DMA Version register"]
+    #[inline(always)]
+    pub const fn version(&self) -> &Version {
+        &self.version
+    }
+    #[doc = "0x30 - S2MM VDMA Control register"]
+    #[inline(always)]
+    pub const fn s2mm_vdmacr(&self) -> &S2mmVdmacr {
+        &self.s2mm_vdmacr
+    }
+    #[doc = "0x34 - S2MM VDMA Status register"]
+    #[inline(always)]
+    pub const fn s2mm_vdmasr(&self) -> &S2mmVdmasr {
+        &self.s2mm_vdmasr
+    }
+    #[doc = "0x48 - S2MM Frame Store register"]
+    #[inline(always)]
+    pub const fn s2mm_frmstore(&self) -> &S2mmFrmstore {
+        &self.s2mm_frmstore
+    }
+    #[doc = "0x50 - MM2S Vertical Size register"]
+    #[inline(always)]
+    pub const fn mm2s_vsize(&self) -> &Mm2sVsize {
+        &self.mm2s_vsize
+    }
+    #[doc = "0x54 - MM2S Horizontal Size register"]
+    #[inline(always)]
+    pub const fn mm2s_hsize(&self) -> &Mm2sHsize {
+        &self.mm2s_hsize
+    }
+    #[doc = "0x58 - MM2S Frame Delay and Stride register"]
+    #[inline(always)]
+    pub const fn mm2s_frmdly_stride(&self) -> &Mm2sFrmdlyStride {
+        &self.mm2s_frmdly_stride
+    }
+    #[doc = "0x5c..0x9c - MM2S Start Address of frame store \\[%s\\]"]
+    #[inline(always)]
+    pub const fn mm2s_start_address(&self, n: usize) -> &Mm2sStartAddress {
+        &self.mm2s_start_address[n]
+    }
+    #[doc = "Iterator for array of:"]
+    #[doc = "0x5c..0x9c - MM2S Start Address of frame store \\[%s\\]"]
+    #[inline(always)]
+    pub fn mm2s_start_address_iter(&self) -> impl Iterator<Item = &Mm2sStartAddress> {
+        self.mm2s_start_address.iter()
+    }
+    #[doc = "0xa0 - S2MM Vertical Size register"]
+    #[inline(always)]
+    pub const fn s2mm_vsize(&self) -> &S2mmVsize {
+        &self.s2mm_vsize
+    }
+    #[doc = "0xa4 - S2MM Horizontal Size register"]
+    #[inline(always)]
+    pub const fn s2mm_hsize(&self) -> &S2mmHsize {
+        &self.s2mm_hsize
+    }
+    #[doc = "0xa8 - S2MM Frame Delay and Stride register"]
+    #[inline(always)]
+    pub const fn s2mm_frmdly_stride(&self) -> &S2mmFrmdlyStride {
+        &self.s2mm_frmdly_stride
+    }
+    #[doc = "0xac..0xec - S2MM Start Address of frame store \\[%s\\]"]
+    #[inline(always)]
+    pub const fn s2mm_start_address(&self, n: usize) -> &S2mmStartAddress {
+        &self.s2mm_start_address[n]
+    }
+    #[doc = "Iterator for array of:"]
+    #[doc = "0xac..0xec - S2MM Start Address of frame store \\[%s\\]"]
+    #[inline(always)]
+    pub fn s2mm_start_address_iter(&self) -> impl Iterator<Item = &S2mmStartAddress> {
+        self.s2mm_start_address.iter()
+    }
+}
+#[doc = "mm2s_vdmacr (rw) register accessor: MM2S VDMA Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_vdmacr::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_vdmacr::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_vdmacr`]
+module"]
+#[doc(alias = "mm2s_vdmacr")]
+pub type Mm2sVdmacr = crate::Reg<mm2s_vdmacr::Mm2sVdmacrSpec>;
+#[doc = "MM2S VDMA Control register"]
+pub mod mm2s_vdmacr;
+#[doc = "mm2s_vdmasr (rw) register accessor: MM2S VDMA Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_vdmasr::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_vdmasr::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_vdmasr`]
+module"]
+#[doc(alias = "mm2s_vdmasr")]
+pub type Mm2sVdmasr = crate::Reg<mm2s_vdmasr::Mm2sVdmasrSpec>;
+#[doc = "MM2S VDMA Status register"]
+pub mod mm2s_vdmasr;
+#[doc = "mm2s_frmstore (rw) register accessor: MM2S Frame Store register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_frmstore::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_frmstore::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_frmstore`]
+module"]
+#[doc(alias = "mm2s_frmstore")]
+pub type Mm2sFrmstore = crate::Reg<mm2s_frmstore::Mm2sFrmstoreSpec>;
+#[doc = "MM2S Frame Store register"]
+pub mod mm2s_frmstore;
+#[doc = "park_ptr_reg (rw) register accessor: Park Pointer register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`park_ptr_reg::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`park_ptr_reg::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@park_ptr_reg`]
+module"]
+#[doc(alias = "park_ptr_reg")]
+pub type ParkPtrReg = crate::Reg<park_ptr_reg::ParkPtrRegSpec>;
+#[doc = "Park Pointer register"]
+pub mod park_ptr_reg;
+#[doc = "version (r) register accessor: Video DMA Version register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`version::R`].  See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@version`]
+module"]
+#[doc(alias = "version")]
+pub type Version = crate::Reg<version::VersionSpec>;
+#[doc = "Video DMA Version register"]
+pub mod version;
+#[doc = "S2MM VDMA Control register"]
+pub use Mm2sVdmacr as S2mmVdmacr;
+#[doc = "S2MM VDMA Control register"]
+pub use mm2s_vdmacr as s2mm_vdmacr;
+#[doc = "S2MM VDMA Status register"]
+pub use Mm2sVdmasr as S2mmVdmasr;
+#[doc = "S2MM VDMA Status register"]
+pub use mm2s_vdmasr as s2mm_vdmasr;
+#[doc = "S2MM Frame Store register"]
+pub use Mm2sFrmstore as S2mmFrmstore;
+#[doc = "S2MM Frame Store register"]
+pub use mm2s_frmstore as s2mm_frmstore;
+#[doc = "mm2s_vsize (rw) register accessor: MM2S Vertical Size register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_vsize::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_vsize::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_vsize`]
+module"]
+#[doc(alias = "mm2s_vsize")]
+pub type Mm2sVsize = crate::Reg<mm2s_vsize::Mm2sVsizeSpec>;
+#[doc = "MM2S Vertical Size register"]
+pub mod mm2s_vsize;
+#[doc = "mm2s_hsize (rw) register accessor: MM2S Horizontal Size register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_hsize::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_hsize::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_hsize`]
+module"]
+#[doc(alias = "mm2s_hsize")]
+pub type Mm2sHsize = crate::Reg<mm2s_hsize::Mm2sHsizeSpec>;
+#[doc = "MM2S Horizontal Size register"]
+pub mod mm2s_hsize;
+#[doc = "mm2s_frmdly_stride (rw) register accessor: MM2S Frame Delay and Stride register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_frmdly_stride::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_frmdly_stride::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_frmdly_stride`]
+module"]
+#[doc(alias = "mm2s_frmdly_stride")]
+pub type Mm2sFrmdlyStride = crate::Reg<mm2s_frmdly_stride::Mm2sFrmdlyStrideSpec>;
+#[doc = "MM2S Frame Delay and Stride register"]
+pub mod mm2s_frmdly_stride;
+#[doc = "mm2s_start_address[%s] (rw) register accessor: MM2S Start Address of frame store \\[%s\\]\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_start_address::R`].  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_start_address::W`]. You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api).\n\nFor information about available fields see [`mod@mm2s_start_address`]
+module"]
+#[doc(alias = "mm2s_start_address[%s]")]
+pub type Mm2sStartAddress = crate::Reg<mm2s_start_address::Mm2sStartAddressSpec>;
+#[doc = "MM2S Start Address of frame store \\[%s\\]"]
+pub mod mm2s_start_address;
+#[doc = "S2MM Vertical Size register"]
+pub use Mm2sVsize as S2mmVsize;
+#[doc = "S2MM Vertical Size register"]
+pub use mm2s_vsize as s2mm_vsize;
+#[doc = "S2MM Horizontal Size register"]
+pub use Mm2sHsize as S2mmHsize;
+#[doc = "S2MM Horizontal Size register"]
+pub use mm2s_hsize as s2mm_hsize;
+#[doc = "S2MM Frame Delay and Stride register"]
+pub use Mm2sFrmdlyStride as S2mmFrmdlyStride;
+#[doc = "S2MM Frame Delay and Stride register"]
+pub use mm2s_frmdly_stride as s2mm_frmdly_stride;
+#[doc = "S2MM Start Address of frame store \\[%s\\]"]
+pub use Mm2sStartAddress as S2mmStartAddress;
+#[doc = "S2MM Start Address of frame store \\[%s\\]"]
+pub use mm2s_start_address as s2mm_start_address;
@@ -0,0 +1,55 @@
+#[doc = "Register `mm2s_frmdly_stride` reader"]
+pub type R = crate::R<Mm2sFrmdlyStrideSpec>;
+#[doc = "Register `mm2s_frmdly_stride` writer"]
+pub type W = crate::W<Mm2sFrmdlyStrideSpec>;
+#[doc = "Field `stride` reader - Number of bytes between the first pixels of two adjacent lines."]
+pub type StrideR = crate::FieldReader<u16>;
+#[doc = "Field `stride` writer - Number of bytes between the first pixels of two adjacent lines."]
+pub type StrideW<'a, REG> = crate::FieldWriter<'a, REG, 16, u16>;
+#[doc = "Field `frame_delay` reader - Frame delay behind the genlock master."]
+pub type FrameDelayR = crate::FieldReader;
+#[doc = "Field `frame_delay` writer - Frame delay behind the genlock master."]
+pub type FrameDelayW<'a, REG> = crate::FieldWriter<'a, REG, 5>;
+impl R {
+    #[doc = "Bits 0:15 - Number of bytes between the first pixels of two adjacent lines."]
+    #[inline(always)]
+    pub fn stride(&self) -> StrideR {
+        StrideR::new((self.bits & 0xffff) as u16)
+    }
+    #[doc = "Bits 24:28 - Frame delay behind the genlock master."]
+    #[inline(always)]
+    pub fn frame_delay(&self) -> FrameDelayR {
+        FrameDelayR::new(((self.bits >> 24) & 0x1f) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:15 - Number of bytes between the first pixels of two adjacent lines."]
+    #[inline(always)]
+    #[must_use]
+    pub fn stride(&mut self) -> StrideW<Mm2sFrmdlyStrideSpec> {
+        StrideW::new(self, 0)
+    }
+    #[doc = "Bits 24:28 - Frame delay behind the genlock master."]
+    #[inline(always)]
+    #[must_use]
+    pub fn frame_delay(&mut self) -> FrameDelayW<Mm2sFrmdlyStrideSpec> {
+        FrameDelayW::new(self, 24)
+    }
+}
+#[doc = "MM2S Frame Delay and Stride register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_frmdly_stride::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_frmdly_stride::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sFrmdlyStrideSpec;
+impl crate::RegisterSpec for Mm2sFrmdlyStrideSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_frmdly_stride::R`](R) reader structure"]
+impl crate::Readable for Mm2sFrmdlyStrideSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_frmdly_stride::W`](W) writer structure"]
+impl crate::Writable for Mm2sFrmdlyStrideSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_frmdly_stride to value 0"]
+impl crate::Resettable for Mm2sFrmdlyStrideSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,40 @@
+#[doc = "Register `mm2s_frmstore` reader"]
+pub type R = crate::R<Mm2sFrmstoreSpec>;
+#[doc = "Register `mm2s_frmstore` writer"]
+pub type W = crate::W<Mm2sFrmstoreSpec>;
+#[doc = "Field `num_frm_store` reader - Number of frame stores used by the channel."]
+pub type NumFrmStoreR = crate::FieldReader;
+#[doc = "Field `num_frm_store` writer - Number of frame stores used by the channel."]
+pub type NumFrmStoreW<'a, REG> = crate::FieldWriter<'a, REG, 5>;
+impl R {
+    #[doc = "Bits 0:4 - Number of frame stores used by the channel."]
+    #[inline(always)]
+    pub fn num_frm_store(&self) -> NumFrmStoreR {
+        NumFrmStoreR::new((self.bits & 0x1f) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:4 - Number of frame stores used by the channel."]
+    #[inline(always)]
+    #[must_use]
+    pub fn num_frm_store(&mut self) -> NumFrmStoreW<Mm2sFrmstoreSpec> {
+        NumFrmStoreW::new(self, 0)
+    }
+}
+#[doc = "MM2S Frame Store register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_frmstore::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_frmstore::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sFrmstoreSpec;
+impl crate::RegisterSpec for Mm2sFrmstoreSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_frmstore::R`](R) reader structure"]
+impl crate::Readable for Mm2sFrmstoreSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_frmstore::W`](W) writer structure"]
+impl crate::Writable for Mm2sFrmstoreSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_frmstore to value 0"]
+impl crate::Resettable for Mm2sFrmstoreSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,40 @@
+#[doc = "Register `mm2s_hsize` reader"]
+pub type R = crate::R<Mm2sHsizeSpec>;
+#[doc = "Register `mm2s_hsize` writer"]
+pub type W = crate::W<Mm2sHsizeSpec>;
+#[doc = "Field `horizontal_size` reader - Number of bytes of a line."]
+pub type HorizontalSizeR = crate::FieldReader<u16>;
+#[doc = "Field `horizontal_size` writer - Number of bytes of a line."]
+pub type HorizontalSizeW<'a, REG> = crate::FieldWriter<'a, REG, 16, u16>;
+impl R {
+    #[doc = "Bits 0:15 - Number of bytes of a line."]
+    #[inline(always)]
+    pub fn horizontal_size(&self) -> HorizontalSizeR {
+        HorizontalSizeR::new((self.bits & 0xffff) as u16)
+    }
+}
+impl W {
+    #[doc = "Bits 0:15 - Number of bytes of a line."]
+    #[inline(always)]
+    #[must_use]
+    pub fn horizontal_size(&mut self) -> HorizontalSizeW<Mm2sHsizeSpec> {
+        HorizontalSizeW::new(self, 0)
+    }
+}
+#[doc = "MM2S Horizontal Size register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_hsize::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_hsize::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sHsizeSpec;
+impl crate::RegisterSpec for Mm2sHsizeSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_hsize::R`](R) reader structure"]
+impl crate::Readable for Mm2sHsizeSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_hsize::W`](W) writer structure"]
+impl crate::Writable for Mm2sHsizeSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_hsize to value 0"]
+impl crate::Resettable for Mm2sHsizeSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,41 @@
+#[doc = "Register `mm2s_start_address[%s]` reader"]
+pub type R = crate::R<Mm2sStartAddressSpec>;
+#[doc = "Register `mm2s_start_address[%s]` writer"]
+pub type W = crate::W<Mm2sStartAddressSpec>;
+#[doc = "Field `start_address` reader - Start address of the frame store."]
+pub type StartAddressR = crate::FieldReader<u32>;
+#[doc = "Field `start_address` writer - Start address of the frame store."]
+pub type StartAddressW<'a, REG> = crate::FieldWriter<'a, REG, 32, u32>;
+impl R {
+    #[doc = "Bits 0:31 - Start address of the frame store."]
+    #[inline(always)]
+    pub fn start_address(&self) -> StartAddressR {
+        StartAddressR::new(self.bits)
+    }
+}
+impl W {
+    #[doc = "Bits 0:31 - Start address of the frame store."]
+    #[inline(always)]
+    #[must_use]
+    pub fn start_address(&mut self) -> StartAddressW<Mm2sStartAddressSpec> {
+        StartAddressW::new(self, 0)
+    }
+}
+#[doc = "MM2S Start Address of frame store \\[%s\\]\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_start_address::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_start_address::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sStartAddressSpec;
+impl crate::RegisterSpec for Mm2sStartAddressSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_start_address::R`](R) reader structure"]
+impl crate::Readable for Mm2sStartAddressSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_start_address::W`](W) writer structure"]
+impl crate::Writable for Mm2sStartAddressSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_start_address[%s]
+to value 0"]
+impl crate::Resettable for Mm2sStartAddressSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,220 @@
+#[doc = "Register `mm2s_vdmacr` reader"]
+pub type R = crate::R<Mm2sVdmacrSpec>;
+#[doc = "Register `mm2s_vdmacr` writer"]
+pub type W = crate::W<Mm2sVdmacrSpec>;
+#[doc = "Field `run_stop` reader - Run / Stop control for controlling running and stopping of the VDMA channel."]
+pub type RunStopR = crate::BitReader;
+#[doc = "Field `run_stop` writer - Run / Stop control for controlling running and stopping of the VDMA channel."]
+pub type RunStopW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `circular_park` reader - 0: Park mode, the channel stays on the frame in the park pointer register. 1: Circular mode, the channel cycles through all the frame stores."]
+pub type CircularParkR = crate::BitReader;
+#[doc = "Field `circular_park` writer - 0: Park mode, the channel stays on the frame in the park pointer register. 1: Circular mode, the channel cycles through all the frame stores."]
+pub type CircularParkW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `reset` reader - Soft reset for resetting the VDMA channel"]
+pub type ResetR = crate::BitReader;
+#[doc = "Field `reset` writer - Soft reset for resetting the VDMA channel"]
+pub type ResetW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `genlock_en` reader - Enables Genlock synchronization with the other channel or an external master."]
+pub type GenlockEnR = crate::BitReader;
+#[doc = "Field `genlock_en` writer - Enables Genlock synchronization with the other channel or an external master."]
+pub type GenlockEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `frame_cnt_en` reader - Frame Count Enable. The channel halts after IRQFrameCount frames have been transferred."]
+pub type FrameCntEnR = crate::BitReader;
+#[doc = "Field `frame_cnt_en` writer - Frame Count Enable. The channel halts after IRQFrameCount frames have been transferred."]
+pub type FrameCntEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `genlock_src` reader - Genlock Source. 0: external genlock, 1: internal genlock."]
+pub type GenlockSrcR = crate::BitReader;
+#[doc = "Field `genlock_src` writer - Genlock Source. 0: external genlock, 1: internal genlock."]
+pub type GenlockSrcW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `rd_pntr_num` reader - Master in control, which selects the genlock master in Dynamic Genlock mode."]
+pub type RdPntrNumR = crate::FieldReader;
+#[doc = "Field `rd_pntr_num` writer - Master in control, which selects the genlock master in Dynamic Genlock mode."]
+pub type RdPntrNumW<'a, REG> = crate::FieldWriter<'a, REG, 4>;
+#[doc = "Field `frm_cnt_irq_en` reader - Frame Count Interrupt Enable"]
+pub type FrmCntIrqEnR = crate::BitReader;
+#[doc = "Field `frm_cnt_irq_en` writer - Frame Count Interrupt Enable"]
+pub type FrmCntIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dly_cnt_irq_en` reader - Interrupt on Delay Count Interrupt Enable"]
+pub type DlyCntIrqEnR = crate::BitReader;
+#[doc = "Field `dly_cnt_irq_en` writer - Interrupt on Delay Count Interrupt Enable"]
+pub type DlyCntIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `err_irq_en` reader - Interrupt on Error Interrupt Enable"]
+pub type ErrIrqEnR = crate::BitReader;
+#[doc = "Field `err_irq_en` writer - Interrupt on Error Interrupt Enable"]
+pub type ErrIrqEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `repeat_en` reader - Repeat the previous frame when an error occurs, instead of skipping to the next one."]
+pub type RepeatEnR = crate::BitReader;
+#[doc = "Field `repeat_en` writer - Repeat the previous frame when an error occurs, instead of skipping to the next one."]
+pub type RepeatEnW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `irq_frame_count` reader - Interrupt Frame Count. Number of frames after which a frame count interrupt is raised."]
+pub type IrqFrameCountR = crate::FieldReader;
+#[doc = "Field `irq_frame_count` writer - Interrupt Frame Count. Number of frames after which a frame count interrupt is raised."]
+pub type IrqFrameCountW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+#[doc = "Field `irq_delay_count` reader - Interrupt Delay Count. Number of video lines without new frame after which a delay interrupt is raised."]
+pub type IrqDelayCountR = crate::FieldReader;
+#[doc = "Field `irq_delay_count` writer - Interrupt Delay Count. Number of video lines without new frame after which a delay interrupt is raised."]
+pub type IrqDelayCountW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+impl R {
+    #[doc = "Bit 0 - Run / Stop control for controlling running and stopping of the VDMA channel."]
+    #[inline(always)]
+    pub fn run_stop(&self) -> RunStopR {
+        RunStopR::new((self.bits & 1) != 0)
+    }
+    #[doc = "Bit 1 - 0: Park mode, the channel stays on the frame in the park pointer register. 1: Circular mode, the channel cycles through all the frame stores."]
+    #[inline(always)]
+    pub fn circular_park(&self) -> CircularParkR {
+        CircularParkR::new(((self.bits >> 1) & 1) != 0)
+    }
+    #[doc = "Bit 2 - Soft reset for resetting the VDMA channel"]
+    #[inline(always)]
+    pub fn reset(&self) -> ResetR {
+        ResetR::new(((self.bits >> 2) & 1) != 0)
+    }
+    #[doc = "Bit 3 - Enables Genlock synchronization with the other channel or an external master."]
+    #[inline(always)]
+    pub fn genlock_en(&self) -> GenlockEnR {
+        GenlockEnR::new(((self.bits >> 3) & 1) != 0)
+    }
+    #[doc = "Bit 4 - Frame Count Enable. The channel halts after IRQFrameCount frames have been transferred."]
+    #[inline(always)]
+    pub fn frame_cnt_en(&self) -> FrameCntEnR {
+        FrameCntEnR::new(((self.bits >> 4) & 1) != 0)
+    }
+    #[doc = "Bit 7 - Genlock Source. 0: external genlock, 1: internal genlock."]
+    #[inline(always)]
+    pub fn genlock_src(&self) -> GenlockSrcR {
+        GenlockSrcR::new(((self.bits >> 7) & 1) != 0)
+    }
+    #[doc = "Bits 8:11 - Master in control, which selects the genlock master in Dynamic Genlock mode."]
+    #[inline(always)]
+    pub fn rd_pntr_num(&self) -> RdPntrNumR {
+        RdPntrNumR::new(((self.bits >> 8) & 0x0f) as u8)
+    }
+    #[doc = "Bit 12 - Frame Count Interrupt Enable"]
+    #[inline(always)]
+    pub fn frm_cnt_irq_en(&self) -> FrmCntIrqEnR {
+        FrmCntIrqEnR::new(((self.bits >> 12) & 1) != 0)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay Count Interrupt Enable"]
+    #[inline(always)]
+    pub fn dly_cnt_irq_en(&self) -> DlyCntIrqEnR {
+        DlyCntIrqEnR::new(((self.bits >> 13) & 1) != 0)
+    }
+    #[doc = "Bit 14 - Interrupt on Error Interrupt Enable"]
+    #[inline(always)]
+    pub fn err_irq_en(&self) -> ErrIrqEnR {
+        ErrIrqEnR::new(((self.bits >> 14) & 1) != 0)
+    }
+    #[doc = "Bit 15 - Repeat the previous frame when an error occurs, instead of skipping to the next one."]
+    #[inline(always)]
+    pub fn repeat_en(&self) -> RepeatEnR {
+        RepeatEnR::new(((self.bits >> 15) & 1) != 0)
+    }
+    #[doc = "Bits 16:23 - Interrupt Frame Count. Number of frames after which a frame count interrupt is raised."]
+    #[inline(always)]
+    pub fn irq_frame_count(&self) -> IrqFrameCountR {
+        IrqFrameCountR::new(((self.bits >> 16) & 0xff) as u8)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Count. Number of video lines without new frame after which a delay interrupt is raised."]
+    #[inline(always)]
+    pub fn irq_delay_count(&self) -> IrqDelayCountR {
+        IrqDelayCountR::new(((self.bits >> 24) & 0xff) as u8)
+    }
+}
+impl W {
+    #[doc = "Bit 0 - Run / Stop control for controlling running and stopping of the VDMA channel."]
+    #[inline(always)]
+    #[must_use]
+    pub fn run_stop(&mut self) -> RunStopW<Mm2sVdmacrSpec> {
+        RunStopW::new(self, 0)
+    }
+    #[doc = "Bit 1 - 0: Park mode, the channel stays on the frame in the park pointer register. 1: Circular mode, the channel cycles through all the frame stores."]
+    #[inline(always)]
+    #[must_use]
+    pub fn circular_park(&mut self) -> CircularParkW<Mm2sVdmacrSpec> {
+        CircularParkW::new(self, 1)
+    }
+    #[doc = "Bit 2 - Soft reset for resetting the VDMA channel"]
+    #[inline(always)]
+    #[must_use]
+    pub fn reset(&mut self) -> ResetW<Mm2sVdmacrSpec> {
+        ResetW::new(self, 2)
+    }
+    #[doc = "Bit 3 - Enables Genlock synchronization with the other channel or an external master."]
+    #[inline(always)]
+    #[must_use]
+    pub fn genlock_en(&mut self) -> GenlockEnW<Mm2sVdmacrSpec> {
+        GenlockEnW::new(self, 3)
+    }
+    #[doc = "Bit 4 - Frame Count Enable. The channel halts after IRQFrameCount frames have been transferred."]
+    #[inline(always)]
+    #[must_use]
+    pub fn frame_cnt_en(&mut self) -> FrameCntEnW<Mm2sVdmacrSpec> {
+        FrameCntEnW::new(self, 4)
+    }
+    #[doc = "Bit 7 - Genlock Source. 0: external genlock, 1: internal genlock."]
+    #[inline(always)]
+    #[must_use]
+    pub fn genlock_src(&mut self) -> GenlockSrcW<Mm2sVdmacrSpec> {
+        GenlockSrcW::new(self, 7)
+    }
+    #[doc = "Bits 8:11 - Master in control, which selects the genlock master in Dynamic Genlock mode."]
+    #[inline(always)]
+    #[must_use]
+    pub fn rd_pntr_num(&mut self) -> RdPntrNumW<Mm2sVdmacrSpec> {
+        RdPntrNumW::new(self, 8)
+    }
+    #[doc = "Bit 12 - Frame Count Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn frm_cnt_irq_en(&mut self) -> FrmCntIrqEnW<Mm2sVdmacrSpec> {
+        FrmCntIrqEnW::new(self, 12)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay Count Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn dly_cnt_irq_en(&mut self) -> DlyCntIrqEnW<Mm2sVdmacrSpec> {
+        DlyCntIrqEnW::new(self, 13)
+    }
+    #[doc = "Bit 14 - Interrupt on Error Interrupt Enable"]
+    #[inline(always)]
+    #[must_use]
+    pub fn err_irq_en(&mut self) -> ErrIrqEnW<Mm2sVdmacrSpec> {
+        ErrIrqEnW::new(self, 14)
+    }
+    #[doc = "Bit 15 - Repeat the previous frame when an error occurs, instead of skipping to the next one."]
+    #[inline(always)]
+    #[must_use]
+    pub fn repeat_en(&mut self) -> RepeatEnW<Mm2sVdmacrSpec> {
+        RepeatEnW::new(self, 15)
+    }
+    #[doc = "Bits 16:23 - Interrupt Frame Count. Number of frames after which a frame count interrupt is raised."]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_frame_count(&mut self) -> IrqFrameCountW<Mm2sVdmacrSpec> {
+        IrqFrameCountW::new(self, 16)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Count. Number of video lines without new frame after which a delay interrupt is raised."]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_delay_count(&mut self) -> IrqDelayCountW<Mm2sVdmacrSpec> {
+        IrqDelayCountW::new(self, 24)
+    }
+}
+#[doc = "MM2S VDMA Control register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_vdmacr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_vdmacr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sVdmacrSpec;
+impl crate::RegisterSpec for Mm2sVdmacrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_vdmacr::R`](R) reader structure"]
+impl crate::Readable for Mm2sVdmacrSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_vdmacr::W`](W) writer structure"]
+impl crate::Writable for Mm2sVdmacrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_vdmacr to value 0"]
+impl crate::Resettable for Mm2sVdmacrSpec {
+    const RESET_VALUE: u32 = 0;
+}
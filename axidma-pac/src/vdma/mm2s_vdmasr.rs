@@ -0,0 +1,220 @@
+#[doc = "Register `mm2s_vdmasr` reader"]
+pub type R = crate::R<Mm2sVdmasrSpec>;
+#[doc = "Register `mm2s_vdmasr` writer"]
+pub type W = crate::W<Mm2sVdmasrSpec>;
+#[doc = "Field `halted` reader - The VDMA channel is halted."]
+pub type HaltedR = crate::BitReader;
+#[doc = "Field `halted` writer - The VDMA channel is halted."]
+pub type HaltedW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `vdma_int_err` reader - VDMA Internal Error."]
+pub type VdmaIntErrR = crate::BitReader;
+#[doc = "Field `vdma_int_err` writer - VDMA Internal Error."]
+pub type VdmaIntErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `vdma_slv_err` reader - VDMA Slave Error."]
+pub type VdmaSlvErrR = crate::BitReader;
+#[doc = "Field `vdma_slv_err` writer - VDMA Slave Error."]
+pub type VdmaSlvErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `vdma_dec_err` reader - VDMA Decode Error."]
+pub type VdmaDecErrR = crate::BitReader;
+#[doc = "Field `vdma_dec_err` writer - VDMA Decode Error."]
+pub type VdmaDecErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sof_early_err` reader - Start of Frame Early Error (S2MM only)."]
+pub type SofEarlyErrR = crate::BitReader;
+#[doc = "Field `sof_early_err` writer - Start of Frame Early Error (S2MM only)."]
+pub type SofEarlyErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `eol_early_err` reader - End of Line Early Error (S2MM only)."]
+pub type EolEarlyErrR = crate::BitReader;
+#[doc = "Field `eol_early_err` writer - End of Line Early Error (S2MM only)."]
+pub type EolEarlyErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `sof_late_err` reader - Start of Frame Late Error (S2MM only)."]
+pub type SofLateErrR = crate::BitReader;
+#[doc = "Field `sof_late_err` writer - Start of Frame Late Error (S2MM only)."]
+pub type SofLateErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `frm_cnt_irq` reader - Frame Count Interrupt."]
+pub type FrmCntIrqR = crate::BitReader;
+#[doc = "Field `frm_cnt_irq` writer - Frame Count Interrupt."]
+pub type FrmCntIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `dly_cnt_irq` reader - Interrupt on Delay Count."]
+pub type DlyCntIrqR = crate::BitReader;
+#[doc = "Field `dly_cnt_irq` writer - Interrupt on Delay Count."]
+pub type DlyCntIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `err_irq` reader - Interrupt on Error."]
+pub type ErrIrqR = crate::BitReader;
+#[doc = "Field `err_irq` writer - Interrupt on Error."]
+pub type ErrIrqW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `eol_late_err` reader - End of Line Late Error (S2MM only)."]
+pub type EolLateErrR = crate::BitReader;
+#[doc = "Field `eol_late_err` writer - End of Line Late Error (S2MM only)."]
+pub type EolLateErrW<'a, REG> = crate::BitWriter<'a, REG>;
+#[doc = "Field `irq_frame_cnt_sts` reader - Interrupt Frame Count Status"]
+pub type IrqFrameCntStsR = crate::FieldReader;
+#[doc = "Field `irq_frame_cnt_sts` writer - Interrupt Frame Count Status"]
+pub type IrqFrameCntStsW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+#[doc = "Field `irq_delay_cnt_sts` reader - Interrupt Delay Count Status"]
+pub type IrqDelayCntStsR = crate::FieldReader;
+#[doc = "Field `irq_delay_cnt_sts` writer - Interrupt Delay Count Status"]
+pub type IrqDelayCntStsW<'a, REG> = crate::FieldWriter<'a, REG, 8>;
+impl R {
+    #[doc = "Bit 0 - The VDMA channel is halted."]
+    #[inline(always)]
+    pub fn halted(&self) -> HaltedR {
+        HaltedR::new((self.bits & 1) != 0)
+    }
+    #[doc = "Bit 4 - VDMA Internal Error."]
+    #[inline(always)]
+    pub fn vdma_int_err(&self) -> VdmaIntErrR {
+        VdmaIntErrR::new(((self.bits >> 4) & 1) != 0)
+    }
+    #[doc = "Bit 5 - VDMA Slave Error."]
+    #[inline(always)]
+    pub fn vdma_slv_err(&self) -> VdmaSlvErrR {
+        VdmaSlvErrR::new(((self.bits >> 5) & 1) != 0)
+    }
+    #[doc = "Bit 6 - VDMA Decode Error."]
+    #[inline(always)]
+    pub fn vdma_dec_err(&self) -> VdmaDecErrR {
+        VdmaDecErrR::new(((self.bits >> 6) & 1) != 0)
+    }
+    #[doc = "Bit 7 - Start of Frame Early Error (S2MM only)."]
+    #[inline(always)]
+    pub fn sof_early_err(&self) -> SofEarlyErrR {
+        SofEarlyErrR::new(((self.bits >> 7) & 1) != 0)
+    }
+    #[doc = "Bit 8 - End of Line Early Error (S2MM only)."]
+    #[inline(always)]
+    pub fn eol_early_err(&self) -> EolEarlyErrR {
+        EolEarlyErrR::new(((self.bits >> 8) & 1) != 0)
+    }
+    #[doc = "Bit 11 - Start of Frame Late Error (S2MM only)."]
+    #[inline(always)]
+    pub fn sof_late_err(&self) -> SofLateErrR {
+        SofLateErrR::new(((self.bits >> 11) & 1) != 0)
+    }
+    #[doc = "Bit 12 - Frame Count Interrupt."]
+    #[inline(always)]
+    pub fn frm_cnt_irq(&self) -> FrmCntIrqR {
+        FrmCntIrqR::new(((self.bits >> 12) & 1) != 0)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay Count."]
+    #[inline(always)]
+    pub fn dly_cnt_irq(&self) -> DlyCntIrqR {
+        DlyCntIrqR::new(((self.bits >> 13) & 1) != 0)
+    }
+    #[doc = "Bit 14 - Interrupt on Error."]
+    #[inline(always)]
+    pub fn err_irq(&self) -> ErrIrqR {
+        ErrIrqR::new(((self.bits >> 14) & 1) != 0)
+    }
+    #[doc = "Bit 15 - End of Line Late Error (S2MM only)."]
+    #[inline(always)]
+    pub fn eol_late_err(&self) -> EolLateErrR {
+        EolLateErrR::new(((self.bits >> 15) & 1) != 0)
+    }
+    #[doc = "Bits 16:23 - Interrupt Frame Count Status"]
+    #[inline(always)]
+    pub fn irq_frame_cnt_sts(&self) -> IrqFrameCntStsR {
+        IrqFrameCntStsR::new(((self.bits >> 16) & 0xff) as u8)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Count Status"]
+    #[inline(always)]
+    pub fn irq_delay_cnt_sts(&self) -> IrqDelayCntStsR {
+        IrqDelayCntStsR::new(((self.bits >> 24) & 0xff) as u8)
+    }
+}
+impl W {
+    #[doc = "Bit 0 - The VDMA channel is halted."]
+    #[inline(always)]
+    #[must_use]
+    pub fn halted(&mut self) -> HaltedW<Mm2sVdmasrSpec> {
+        HaltedW::new(self, 0)
+    }
+    #[doc = "Bit 4 - VDMA Internal Error."]
+    #[inline(always)]
+    #[must_use]
+    pub fn vdma_int_err(&mut self) -> VdmaIntErrW<Mm2sVdmasrSpec> {
+        VdmaIntErrW::new(self, 4)
+    }
+    #[doc = "Bit 5 - VDMA Slave Error."]
+    #[inline(always)]
+    #[must_use]
+    pub fn vdma_slv_err(&mut self) -> VdmaSlvErrW<Mm2sVdmasrSpec> {
+        VdmaSlvErrW::new(self, 5)
+    }
+    #[doc = "Bit 6 - VDMA Decode Error."]
+    #[inline(always)]
+    #[must_use]
+    pub fn vdma_dec_err(&mut self) -> VdmaDecErrW<Mm2sVdmasrSpec> {
+        VdmaDecErrW::new(self, 6)
+    }
+    #[doc = "Bit 7 - Start of Frame Early Error (S2MM only)."]
+    #[inline(always)]
+    #[must_use]
+    pub fn sof_early_err(&mut self) -> SofEarlyErrW<Mm2sVdmasrSpec> {
+        SofEarlyErrW::new(self, 7)
+    }
+    #[doc = "Bit 8 - End of Line Early Error (S2MM only)."]
+    #[inline(always)]
+    #[must_use]
+    pub fn eol_early_err(&mut self) -> EolEarlyErrW<Mm2sVdmasrSpec> {
+        EolEarlyErrW::new(self, 8)
+    }
+    #[doc = "Bit 11 - Start of Frame Late Error (S2MM only)."]
+    #[inline(always)]
+    #[must_use]
+    pub fn sof_late_err(&mut self) -> SofLateErrW<Mm2sVdmasrSpec> {
+        SofLateErrW::new(self, 11)
+    }
+    #[doc = "Bit 12 - Frame Count Interrupt."]
+    #[inline(always)]
+    #[must_use]
+    pub fn frm_cnt_irq(&mut self) -> FrmCntIrqW<Mm2sVdmasrSpec> {
+        FrmCntIrqW::new(self, 12)
+    }
+    #[doc = "Bit 13 - Interrupt on Delay Count."]
+    #[inline(always)]
+    #[must_use]
+    pub fn dly_cnt_irq(&mut self) -> DlyCntIrqW<Mm2sVdmasrSpec> {
+        DlyCntIrqW::new(self, 13)
+    }
+    #[doc = "Bit 14 - Interrupt on Error."]
+    #[inline(always)]
+    #[must_use]
+    pub fn err_irq(&mut self) -> ErrIrqW<Mm2sVdmasrSpec> {
+        ErrIrqW::new(self, 14)
+    }
+    #[doc = "Bit 15 - End of Line Late Error (S2MM only)."]
+    #[inline(always)]
+    #[must_use]
+    pub fn eol_late_err(&mut self) -> EolLateErrW<Mm2sVdmasrSpec> {
+        EolLateErrW::new(self, 15)
+    }
+    #[doc = "Bits 16:23 - Interrupt Frame Count Status"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_frame_cnt_sts(&mut self) -> IrqFrameCntStsW<Mm2sVdmasrSpec> {
+        IrqFrameCntStsW::new(self, 16)
+    }
+    #[doc = "Bits 24:31 - Interrupt Delay Count Status"]
+    #[inline(always)]
+    #[must_use]
+    pub fn irq_delay_cnt_sts(&mut self) -> IrqDelayCntStsW<Mm2sVdmasrSpec> {
+        IrqDelayCntStsW::new(self, 24)
+    }
+}
+#[doc = "MM2S VDMA Status register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_vdmasr::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_vdmasr::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sVdmasrSpec;
+impl crate::RegisterSpec for Mm2sVdmasrSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_vdmasr::R`](R) reader structure"]
+impl crate::Readable for Mm2sVdmasrSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_vdmasr::W`](W) writer structure"]
+impl crate::Writable for Mm2sVdmasrSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_vdmasr to value 0"]
+impl crate::Resettable for Mm2sVdmasrSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,40 @@
+#[doc = "Register `mm2s_vsize` reader"]
+pub type R = crate::R<Mm2sVsizeSpec>;
+#[doc = "Register `mm2s_vsize` writer"]
+pub type W = crate::W<Mm2sVsizeSpec>;
+#[doc = "Field `vertical_size` reader - Number of lines of a frame. Writing it starts the channel."]
+pub type VerticalSizeR = crate::FieldReader<u16>;
+#[doc = "Field `vertical_size` writer - Number of lines of a frame. Writing it starts the channel."]
+pub type VerticalSizeW<'a, REG> = crate::FieldWriter<'a, REG, 13, u16>;
+impl R {
+    #[doc = "Bits 0:12 - Number of lines of a frame. Writing it starts the channel."]
+    #[inline(always)]
+    pub fn vertical_size(&self) -> VerticalSizeR {
+        VerticalSizeR::new((self.bits & 0x1fff) as u16)
+    }
+}
+impl W {
+    #[doc = "Bits 0:12 - Number of lines of a frame. Writing it starts the channel."]
+    #[inline(always)]
+    #[must_use]
+    pub fn vertical_size(&mut self) -> VerticalSizeW<Mm2sVsizeSpec> {
+        VerticalSizeW::new(self, 0)
+    }
+}
+#[doc = "MM2S Vertical Size register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`mm2s_vsize::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`mm2s_vsize::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct Mm2sVsizeSpec;
+impl crate::RegisterSpec for Mm2sVsizeSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`mm2s_vsize::R`](R) reader structure"]
+impl crate::Readable for Mm2sVsizeSpec {}
+#[doc = "`write(|w| ..)` method takes [`mm2s_vsize::W`](W) writer structure"]
+impl crate::Writable for Mm2sVsizeSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets mm2s_vsize to value 0"]
+impl crate::Resettable for Mm2sVsizeSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,85 @@
+#[doc = "Register `park_ptr_reg` reader"]
+pub type R = crate::R<ParkPtrRegSpec>;
+#[doc = "Register `park_ptr_reg` writer"]
+pub type W = crate::W<ParkPtrRegSpec>;
+#[doc = "Field `rd_frm_ptr_ref` reader - The MM2S frame store to park on."]
+pub type RdFrmPtrRefR = crate::FieldReader;
+#[doc = "Field `rd_frm_ptr_ref` writer - The MM2S frame store to park on."]
+pub type RdFrmPtrRefW<'a, REG> = crate::FieldWriter<'a, REG, 5>;
+#[doc = "Field `wr_frm_ptr_ref` reader - The S2MM frame store to park on."]
+pub type WrFrmPtrRefR = crate::FieldReader;
+#[doc = "Field `wr_frm_ptr_ref` writer - The S2MM frame store to park on."]
+pub type WrFrmPtrRefW<'a, REG> = crate::FieldWriter<'a, REG, 5>;
+#[doc = "Field `rd_frm_store` reader - The MM2S frame store which is being worked on."]
+pub type RdFrmStoreR = crate::FieldReader;
+#[doc = "Field `rd_frm_store` writer - The MM2S frame store which is being worked on."]
+pub type RdFrmStoreW<'a, REG> = crate::FieldWriter<'a, REG, 5>;
+#[doc = "Field `wr_frm_store` reader - The S2MM frame store which is being worked on."]
+pub type WrFrmStoreR = crate::FieldReader;
+#[doc = "Field `wr_frm_store` writer - The S2MM frame store which is being worked on."]
+pub type WrFrmStoreW<'a, REG> = crate::FieldWriter<'a, REG, 5>;
+impl R {
+    #[doc = "Bits 0:4 - The MM2S frame store to park on."]
+    #[inline(always)]
+    pub fn rd_frm_ptr_ref(&self) -> RdFrmPtrRefR {
+        RdFrmPtrRefR::new((self.bits & 0x1f) as u8)
+    }
+    #[doc = "Bits 8:12 - The S2MM frame store to park on."]
+    #[inline(always)]
+    pub fn wr_frm_ptr_ref(&self) -> WrFrmPtrRefR {
+        WrFrmPtrRefR::new(((self.bits >> 8) & 0x1f) as u8)
+    }
+    #[doc = "Bits 16:20 - The MM2S frame store which is being worked on."]
+    #[inline(always)]
+    pub fn rd_frm_store(&self) -> RdFrmStoreR {
+        RdFrmStoreR::new(((self.bits >> 16) & 0x1f) as u8)
+    }
+    #[doc = "Bits 24:28 - The S2MM frame store which is being worked on."]
+    #[inline(always)]
+    pub fn wr_frm_store(&self) -> WrFrmStoreR {
+        WrFrmStoreR::new(((self.bits >> 24) & 0x1f) as u8)
+    }
+}
+impl W {
+    #[doc = "Bits 0:4 - The MM2S frame store to park on."]
+    #[inline(always)]
+    #[must_use]
+    pub fn rd_frm_ptr_ref(&mut self) -> RdFrmPtrRefW<ParkPtrRegSpec> {
+        RdFrmPtrRefW::new(self, 0)
+    }
+    #[doc = "Bits 8:12 - The S2MM frame store to park on."]
+    #[inline(always)]
+    #[must_use]
+    pub fn wr_frm_ptr_ref(&mut self) -> WrFrmPtrRefW<ParkPtrRegSpec> {
+        WrFrmPtrRefW::new(self, 8)
+    }
+    #[doc = "Bits 16:20 - The MM2S frame store which is being worked on."]
+    #[inline(always)]
+    #[must_use]
+    pub fn rd_frm_store(&mut self) -> RdFrmStoreW<ParkPtrRegSpec> {
+        RdFrmStoreW::new(self, 16)
+    }
+    #[doc = "Bits 24:28 - The S2MM frame store which is being worked on."]
+    #[inline(always)]
+    #[must_use]
+    pub fn wr_frm_store(&mut self) -> WrFrmStoreW<ParkPtrRegSpec> {
+        WrFrmStoreW::new(self, 24)
+    }
+}
+#[doc = "Park Pointer register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`park_ptr_reg::R`](R).  You can [`reset`](crate::generic::Reg::reset), [`write`](crate::generic::Reg::write), [`write_with_zero`](crate::generic::Reg::write_with_zero) this register using [`park_ptr_reg::W`](W). You can also [`modify`](crate::generic::Reg::modify) this register. See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct ParkPtrRegSpec;
+impl crate::RegisterSpec for ParkPtrRegSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`park_ptr_reg::R`](R) reader structure"]
+impl crate::Readable for ParkPtrRegSpec {}
+#[doc = "`write(|w| ..)` method takes [`park_ptr_reg::W`](W) writer structure"]
+impl crate::Writable for ParkPtrRegSpec {
+    type Safety = crate::Unsafe;
+    const ZERO_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+    const ONE_TO_MODIFY_FIELDS_BITMAP: u32 = 0;
+}
+#[doc = "`reset()` method sets park_ptr_reg to value 0"]
+impl crate::Resettable for ParkPtrRegSpec {
+    const RESET_VALUE: u32 = 0;
+}
@@ -0,0 +1,36 @@
+#[doc = "Register `version` reader"]
+pub type R = crate::R<VersionSpec>;
+#[doc = "Field `xilinx_internal` reader - Xilinx internal use."]
+pub type XilinxInternalR = crate::FieldReader<u16>;
+#[doc = "Field `minor` reader - Minor version."]
+pub type MinorR = crate::FieldReader;
+#[doc = "Field `major` reader - Major version."]
+pub type MajorR = crate::FieldReader;
+impl R {
+    #[doc = "Bits 0:15 - Xilinx internal use."]
+    #[inline(always)]
+    pub fn xilinx_internal(&self) -> XilinxInternalR {
+        XilinxInternalR::new((self.bits & 0xffff) as u16)
+    }
+    #[doc = "Bits 20:27 - Minor version."]
+    #[inline(always)]
+    pub fn minor(&self) -> MinorR {
+        MinorR::new(((self.bits >> 20) & 0xff) as u8)
+    }
+    #[doc = "Bits 28:31 - Major version."]
+    #[inline(always)]
+    pub fn major(&self) -> MajorR {
+        MajorR::new(((self.bits >> 28) & 0x0f) as u8)
+    }
+}
+#[doc = "Video DMA Version register\n\nYou can [`read`](crate::generic::Reg::read) this register and get [`version::R`](R).  See [API](https://docs.rs/svd2rust/#read--modify--write-api)."]
+pub struct VersionSpec;
+impl crate::RegisterSpec for VersionSpec {
+    type Ux = u32;
+}
+#[doc = "`read()` method returns [`version::R`](R) reader structure"]
+impl crate::Readable for VersionSpec {}
+#[doc = "`reset()` method sets version to value 0"]
+impl crate::Resettable for VersionSpec {
+    const RESET_VALUE: u32 = 0;
+}
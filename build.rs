@@ -0,0 +1,38 @@
+//! Generate the configurations of the AxiDMA instances from the `xparameters.h`
+//! exported by Vivado/Vitis.
+//!
+//! Set `AXIDMA_XPARAMETERS` to the path of the header. Every `XPAR_AXIDMA_<n>_*`
+//! instance becomes a `const` `ValidAxiDmaConfig` in `hw::xparameters`, and the
+//! instance 0 replaces the built-in default configuration. A missing or
+//! invalid parameter fails the build, see `build/xparameters.rs` for the
+//! parser and `src/limits.rs` for the checks shared with
+//! `AxiDmaConfig::validate`.
+
+#[path = "src/limits.rs"]
+mod limits;
+#[path = "build/xparameters.rs"]
+mod xparameters;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build/xparameters.rs");
+    println!("cargo:rerun-if-changed=src/limits.rs");
+    println!("cargo:rerun-if-env-changed=AXIDMA_XPARAMETERS");
+    println!("cargo::rustc-check-cfg=cfg(axidma_xparameters)");
+    let Some(path) = env::var_os("AXIDMA_XPARAMETERS") else {
+        return;
+    };
+    let path = PathBuf::from(path);
+    println!("cargo:rerun-if-changed={}", path.display());
+    let header = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("axidma: cannot read {}: {}", path.display(), e));
+
+    let out = xparameters::generate(&header);
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(out_dir.join("xparameters.rs"), out).unwrap();
+    println!("cargo:rustc-cfg=axidma_xparameters");
+}
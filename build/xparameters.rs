@@ -0,0 +1,177 @@
+//! Parse the `xparameters.h` exported by Vivado/Vitis into `const` AxiDMA
+//! configurations.
+//!
+//! This module is shared by `build.rs` and `tests/xparameters.rs`, which
+//! include it by path next to `src/limits.rs`. Every hardware parameter must be
+//! present in the header, a missing one panics with the name of the define
+//! instead of falling back to a default value. Vitis spells some suffixes in
+//! lower case (`c_addr_width`, `c_sg_length_width`), so the suffixes are matched
+//! case-insensitively.
+//!
+//! Only the channel offsets may be absent: Vitis does not export them, they
+//! default to the register layout of the IP (`XAXIDMA_TX_OFFSET` and
+//! `XAXIDMA_RX_OFFSET`) unless `XPAR_AXIDMA_<n>_TX_CHANNEL_OFFSET` or
+//! `XPAR_AXIDMA_<n>_RX_CHANNEL_OFFSET` is defined.
+
+use crate::limits::{ChannelParams, EngineParams, CHANNEL_REG_SIZE};
+use std::collections::BTreeMap;
+
+/// The parameters of an instance, keyed by the upper-case suffix
+type Params = BTreeMap<String, u64>;
+
+/// Emit the `const` configurations of every `XPAR_AXIDMA_<n>_*` instance of
+/// `header`, panicking on a missing or invalid parameter
+pub fn generate(header: &str) -> String {
+    let defines = parse_defines(header);
+    let mut instances: BTreeMap<usize, Params> = BTreeMap::new();
+    for name in defines.keys() {
+        let Some(rest) = name.strip_prefix("XPAR_AXIDMA_") else {
+            continue;
+        };
+        let Some((id, param)) = rest.split_once('_') else {
+            continue;
+        };
+        let Ok(id) = id.parse::<usize>() else {
+            continue;
+        };
+        if let Some(value) = resolve(&defines, name, 0) {
+            instances
+                .entry(id)
+                .or_default()
+                .insert(param.to_ascii_uppercase(), value);
+        }
+    }
+    if !instances.contains_key(&0) {
+        panic!("axidma: no XPAR_AXIDMA_0_* instance");
+    }
+
+    let mut out = String::from("// Generated by build.rs from xparameters.h, do not edit.\n\n");
+    for (id, params) in instances.iter() {
+        out += &config(*id, params);
+    }
+    out += &format!(
+        "/// The configurations of all the AxiDMA instances\n\
+         pub const XPAR_AXIDMA_CONFIGS: [ValidAxiDmaConfig; {}] = [{}];\n",
+        instances.len(),
+        instances
+            .keys()
+            .map(|id| format!("XPAR_AXIDMA_{}_CONFIG", id))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    out
+}
+
+/// Collect the `#define NAME VALUE` lines
+fn parse_defines(header: &str) -> BTreeMap<&str, &str> {
+    let mut defines = BTreeMap::new();
+    for line in header.lines() {
+        let line = line.split("/*").next().unwrap().split("//").next().unwrap();
+        let mut words = line.split_whitespace();
+        if words.next() != Some("#define") {
+            continue;
+        }
+        if let (Some(name), Some(value)) = (words.next(), words.next()) {
+            defines.insert(name, value);
+        }
+    }
+    defines
+}
+
+/// Evaluate a define, which is a number or the name of another define
+fn resolve(defines: &BTreeMap<&str, &str>, name: &str, depth: usize) -> Option<u64> {
+    let value = defines.get(name)?.trim_matches(|c| c == '(' || c == ')');
+    let digits = value.trim_end_matches(['U', 'u', 'L', 'l']);
+    let number = match digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => digits.parse::<u64>().ok(),
+    };
+    match number {
+        Some(number) => Some(number),
+        None if depth < 8 => resolve(defines, value, depth + 1),
+        None => None,
+    }
+}
+
+/// Emit the configuration of an instance
+fn config(id: usize, params: &Params) -> String {
+    // `param` is spelled as Vitis emits it, for the panic message
+    let required = |param: &str| {
+        params
+            .get(&param.to_ascii_uppercase())
+            .copied()
+            .unwrap_or_else(|| panic!("axidma: XPAR_AXIDMA_{}_{} is missing", id, param))
+    };
+    let flag = |param: &str| required(param) != 0;
+    let channel = |dir: &str, name: &str, offset: usize| {
+        flag(&format!("INCLUDE_{}", dir)).then(|| ChannelParams {
+            offset: params
+                .get(&format!("{}_CHANNEL_OFFSET", name))
+                .map_or(offset, |offset| *offset as usize),
+            data_width: required(&format!("M_AXI_{}_DATA_WIDTH", dir)) as usize,
+            burst_size: required(&format!("{}_BURST_SIZE", dir)) as usize,
+            has_dre: flag(&format!("INCLUDE_{}_DRE", dir)),
+        })
+    };
+    let base_address = required("BASEADDR");
+    let has_sg = flag("INCLUDE_SG");
+    let engine = EngineParams {
+        has_sg,
+        is_micro_dma: flag("MICRO_DMA"),
+        sg_length_width: required("c_sg_length_width") as usize,
+        addr_width: required("c_addr_width") as isize,
+        tx: channel("MM2S", "TX", 0),
+        rx: channel("S2MM", "RX", CHANNEL_REG_SIZE),
+    };
+    let has_sts_cntrl_strm = flag("SG_INCLUDE_STSCNTRL_STRM");
+    if let Err(err) = engine.check() {
+        panic!("axidma: XPAR_AXIDMA_{} is invalid, {}", id, err);
+    }
+    // The absent channels keep the defaults of `AxiDmaConfigBuilder`
+    let absent = ChannelParams {
+        offset: 0,
+        data_width: 32,
+        burst_size: 16,
+        has_dre: false,
+    };
+    let tx = engine.tx.as_ref().unwrap_or(&absent);
+    let rx = engine.rx.as_ref().unwrap_or(&absent);
+    format!(
+        "/// The configuration of the AxiDMA instance {id}\n\
+         pub const XPAR_AXIDMA_{id}_CONFIG: ValidAxiDmaConfig =\n    \
+         ValidAxiDmaConfig::new_unchecked(AxiDmaConfig {{\n    \
+         base_address: 0x{:x},\n    \
+         rx_channel_offset: 0x{:x},\n    \
+         tx_channel_offset: 0x{:x},\n    \
+         has_sts_cntrl_strm: {},\n    \
+         is_micro_dma: {},\n    \
+         has_mm2s: {},\n    \
+         has_mm2s_dre: {},\n    \
+         mm2s_data_width: {},\n    \
+         mm2s_burst_size: {},\n    \
+         has_s2mm: {},\n    \
+         has_s2mm_dre: {},\n    \
+         s2mm_data_width: {},\n    \
+         s2mm_burst_size: {},\n    \
+         has_sg: {},\n    \
+         sg_length_width: {},\n    \
+         addr_width: {},\n\
+         }});\n\n",
+        base_address,
+        engine.rx.as_ref().map_or(CHANNEL_REG_SIZE, |rx| rx.offset),
+        tx.offset,
+        has_sts_cntrl_strm,
+        engine.is_micro_dma,
+        engine.tx.is_some(),
+        tx.has_dre,
+        tx.data_width,
+        tx.burst_size,
+        engine.rx.is_some(),
+        rx.has_dre,
+        rx.data_width,
+        rx.burst_size,
+        has_sg,
+        engine.sg_length_width,
+        engine.addr_width,
+    )
+}
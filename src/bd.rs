@@ -27,21 +27,49 @@
 //!
 //!
 //! Not Completed funtions:
-//!     - [ ] Multiple Channel
-//!     - [ ] Micro Mode
+//!     - [x] Multiple Channel
+//!     - [x] Micro Mode
 
 use crate::AxiDMAErr;
 use crate::AxiDMAResult;
 use crate::BufPtr;
+use crate::CompletionStatus;
+use axidma_pac::cdma_sg_desc;
+use axidma_pac::mcdma_sg_desc;
 use axidma_pac::sg_desc::RegisterBlock;
 
+/// The operations on a buffer descriptor that the BD ring relies on.
+/// Every DMA engine with a Scatter Gather core has its own BD layout,
+/// but the ring management is the same.
+pub trait Descriptor {
+    /// The address of the BD seen by the hardware
+    fn desc_addr(&self) -> usize;
+    /// Clear the BD
+    fn clear(&self);
+    /// Clear the status written by the hardware, so that a stale completion
+    /// is never seen again
+    fn clear_status(&self);
+    /// Link the next BD
+    fn set_next_desc_addr(&self, addr: usize);
+    /// Mark the BD as the first one of a packet
+    fn set_sof(&self);
+    /// Mark the BD as the last one of a packet
+    fn set_eof(&self);
+    /// Whether the hardware has completed the BD
+    fn is_cmplt(&self) -> bool;
+    /// Whether the BD is the last one of a packet
+    fn is_eof(&self) -> bool;
+}
+
 /// The AxiDmaBD is the type for a buffer descriptor (BD).
 #[repr(C, align(64))]
 pub struct AxiDmaBD {
     pub desc: RegisterBlock,
     pub sw_id: u32,
     pub has_sts_cntrl: bool,
+    /// Whether the channel has the data realignment engine
     pub has_dre: bool,
+    /// The number of bytes of a data word of the channel
     pub word_len: u32,
 }
 
@@ -56,17 +84,129 @@ impl AxiDmaBD {
             word_len,
         }
     }
-    /// Clear the BD
-    pub fn clear(&self) {
+
+    /// Set the BD's buffer address.
+    /// Any alignment is accepted if the channel has the data realignment engine,
+    /// otherwise the buffer must be aligned with the data word.
+    pub fn set_buf(&self, buf: &BufPtr) -> AxiDMAResult {
+        let addr = buf.as_ptr() as usize;
+        if !self.is_aligned(addr) {
+            log::error!(
+                "buf is not aligned with {} byte without dre {:#X}",
+                self.word_len,
+                addr
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let addr_lsb = (addr & 0xFFFF_FFFF) as _;
+        let addr_msb = (addr >> 32) as _;
+        trace!("bd::set_buf: addr: {:x}, len: {}", addr, buf.len());
+        unsafe {
+            self.desc.buf_addr().write(|w| w.buf_addr().bits(addr_lsb));
+            self.desc
+                .buf_addr_msb()
+                .write(|w| w.buf_addr().bits(addr_msb));
+            self.desc
+                .control()
+                .modify(|_, w| w.buf_len().bits(buf.len() as _));
+        }
+        Ok(())
+    }
+
+    /// Set the user application words, which are sent on the control stream
+    /// with the first BD of a packet.
+    pub fn set_app(&self, app: &[u32; 5]) -> AxiDMAResult {
+        if !self.has_sts_cntrl {
+            log::error!("bd::set_app: no status/control stream");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        for (reg, word) in self.desc.app_iter().zip(app.iter()) {
+            unsafe { reg.write(|w| w.app().bits(*word)) };
+        }
+        Ok(())
+    }
+
+    /// Get the user application words, which are received from the status
+    /// stream with the last BD of a packet.
+    pub fn app(&self) -> Option<[u32; 5]> {
+        if !self.has_sts_cntrl {
+            return None;
+        }
+        let mut app = [0; 5];
+        for (word, reg) in app.iter_mut().zip(self.desc.app_iter()) {
+            *word = reg.read().app().bits();
+        }
+        Some(app)
+    }
+
+    /// The number of bytes transferred by the hardware
+    pub fn transferred_len(&self) -> usize {
+        self.desc.status().read().tfer_bytes().bits() as _
+    }
+
+    /// The error reported by the hardware in the status of the BD
+    pub fn completion_status(&self) -> CompletionStatus {
+        let status = self.desc.status().read();
+        if status.dma_int_err().is_detected() {
+            CompletionStatus::InternalErr
+        } else if status.dma_slv_err().is_detected() {
+            CompletionStatus::SlaveErr
+        } else if status.dma_dec_err().is_detected() {
+            CompletionStatus::DecodeErr
+        } else {
+            CompletionStatus::Ok
+        }
+    }
+
+    /// Whether the address or the length meets the alignment of the channel
+    pub fn is_aligned(&self, value: usize) -> bool {
+        self.has_dre || self.word_len == 0 || value & (self.word_len as usize - 1) == 0
+    }
+
+    /// Dump the fields of a BD.
+    #[allow(unused)]
+    pub fn dump(&self) {
+        let d = &self.desc;
+        info!(
+            "NXT_DESC_MSB: 0x{:x}, NXT_DESC: 0x{:x}",
+            d.nxt_desc_msb().read().bits(),
+            d.nxt_desc().read().bits()
+        );
+        info!(
+            "BUF_ADDR_MSB: 0x{:x}, BUF_ADDR: 0x{:x}",
+            d.buf_addr_msb().read().bits(),
+            d.buf_addr().read().bits()
+        );
+        info!(
+            "CONTROL: 0x{:x}, STATUS: 0x{:x}",
+            d.control().read().bits(),
+            d.status().read().bits()
+        );
+    }
+}
+
+impl Descriptor for AxiDmaBD {
+    fn desc_addr(&self) -> usize {
+        &self.desc as *const _ as usize
+    }
+
+    fn clear_status(&self) {
+        // The status is written by the hardware, it is read-only in the PAC.
+        unsafe { core::ptr::write_volatile(self.desc.status().as_ptr(), 0) };
+    }
+
+    fn clear(&self) {
         self.desc.buf_addr().reset();
         self.desc.buf_addr_msb().reset();
         self.desc.control().reset();
+        // The status is written by the hardware, it is read-only in the PAC.
+        unsafe { core::ptr::write_volatile(self.desc.status().as_ptr(), 0) };
         for app in self.desc.app_iter() {
             app.reset();
         }
     }
-    /// Link the next BD
-    pub fn set_next_desc_addr(&self, addr: usize) {
+
+    fn set_next_desc_addr(&self, addr: usize) {
         let addr_lsb = ((addr & 0xFFFF_FFFF) >> 6) as _;
         let addr_msb = (addr >> 32) as _;
         unsafe {
@@ -79,17 +219,58 @@ impl AxiDmaBD {
         }
     }
 
+    fn set_sof(&self) {
+        self.desc.control().modify(|_, w| w.sof().set_bit());
+    }
+
+    fn set_eof(&self) {
+        self.desc.control().modify(|_, w| w.eof().set_bit());
+    }
+
+    fn is_cmplt(&self) -> bool {
+        self.desc.status().read().cmplt().is_true()
+    }
+
+    fn is_eof(&self) -> bool {
+        self.desc.control().read().eof().is_true() || self.desc.status().read().rxeof().is_true()
+    }
+}
+
+/// The McdmaBD is the type for a buffer descriptor (BD) of the AXI MCDMA.
+/// Besides the fields of [`AxiDmaBD`], it carries the TDEST/TID/TUSER
+/// sideband of the stream.
+#[repr(C, align(64))]
+pub struct McdmaBD {
+    pub desc: mcdma_sg_desc::RegisterBlock,
+    pub sw_id: u32,
+    pub has_sts_cntrl: bool,
+    /// The number of bytes of a data word of the channel
+    pub word_len: u32,
+}
+
+impl McdmaBD {
+    /// Create a new BD which is not linked into any ring.
+    pub fn new(has_sts_cntrl: bool, word_len: u32) -> Self {
+        Self {
+            desc: mcdma_sg_desc::RegisterBlock::default(),
+            sw_id: 0,
+            has_sts_cntrl,
+            word_len,
+        }
+    }
+
     /// Set the BD's buffer address.
-    /// Note: this function not support dre.
+    /// The MCDMA has no data realignment engine, the buffer must be aligned
+    /// with the data word.
     pub fn set_buf(&self, buf: &BufPtr) -> AxiDMAResult {
         let addr = buf.as_ptr() as usize;
-        if addr & 0x3 != 0 {
-            log::error!("buf is not aligned with 4 byte {:#X}", addr);
+        if self.word_len != 0 && addr & (self.word_len as usize - 1) != 0 {
+            log::error!("buf is not aligned with {} byte {:#X}", self.word_len, addr);
             return Err(AxiDMAErr::InValidParam);
         }
         let addr_lsb = (addr & 0xFFFF_FFFF) as _;
         let addr_msb = (addr >> 32) as _;
-        trace!("bd::set_buf: addr: {:x}, len: {}", addr, buf.len());
+        trace!("mcdma_bd::set_buf: addr: {:x}, len: {}", addr, buf.len());
         unsafe {
             self.desc.buf_addr().write(|w| w.buf_addr().bits(addr_lsb));
             self.desc
@@ -101,6 +282,26 @@ impl AxiDmaBD {
         }
         Ok(())
     }
+
+    /// Set the TID, TDEST and TUSER driven on the stream (MM2S only).
+    pub fn set_sideband(&self, tid: u8, tdest: u8, tuser: u16) {
+        unsafe {
+            self.desc
+                .sideband()
+                .write(|w| w.tid().bits(tid).tdest().bits(tdest).tuser().bits(tuser));
+        }
+    }
+
+    /// Get the TID, TDEST and TUSER captured from the stream (S2MM only).
+    pub fn sideband(&self) -> (u8, u8, u16) {
+        let sideband = self.desc.sideband().read();
+        (
+            sideband.tid().bits(),
+            sideband.tdest().bits(),
+            sideband.tuser().bits(),
+        )
+    }
+
     /// Dump the fields of a BD.
     #[allow(unused)]
     pub fn dump(&self) {
@@ -115,6 +316,126 @@ impl AxiDmaBD {
             d.buf_addr_msb().read().bits(),
             d.buf_addr().read().bits()
         );
+        info!(
+            "CONTROL: 0x{:x}, SIDEBAND: 0x{:x}, STATUS: 0x{:x}",
+            d.control().read().bits(),
+            d.sideband().read().bits(),
+            d.status().read().bits()
+        );
+    }
+}
+
+impl Descriptor for McdmaBD {
+    fn desc_addr(&self) -> usize {
+        &self.desc as *const _ as usize
+    }
+
+    fn clear_status(&self) {
+        // The status is written by the hardware, it is read-only in the PAC.
+        unsafe { core::ptr::write_volatile(self.desc.status().as_ptr(), 0) };
+    }
+
+    fn clear(&self) {
+        self.desc.buf_addr().reset();
+        self.desc.buf_addr_msb().reset();
+        self.desc.control().reset();
+        // The status is written by the hardware, it is read-only in the PAC.
+        unsafe { core::ptr::write_volatile(self.desc.status().as_ptr(), 0) };
+        self.desc.sideband().reset();
+        for app in self.desc.app_iter() {
+            app.reset();
+        }
+    }
+
+    fn set_next_desc_addr(&self, addr: usize) {
+        let addr_lsb = ((addr & 0xFFFF_FFFF) >> 6) as _;
+        let addr_msb = (addr >> 32) as _;
+        unsafe {
+            self.desc
+                .nxt_desc()
+                .write(|w| w.nxt_desc_ptr().bits(addr_lsb));
+            self.desc
+                .nxt_desc_msb()
+                .write(|w| w.nxt_desc_ptr().bits(addr_msb));
+        }
+    }
+
+    fn set_sof(&self) {
+        self.desc.control().modify(|_, w| w.sop().set_bit());
+    }
+
+    fn set_eof(&self) {
+        self.desc.control().modify(|_, w| w.eop().set_bit());
+    }
+
+    fn is_cmplt(&self) -> bool {
+        self.desc.status().read().cmplt().bit_is_set()
+    }
+
+    fn is_eof(&self) -> bool {
+        self.desc.control().read().eop().bit_is_set()
+            || self.desc.status().read().rxeop().bit_is_set()
+    }
+}
+
+/// The CdmaBD is the type for a buffer descriptor (BD) of the AXI CDMA.
+/// A BD describes a whole memory to memory transfer, so there is no SOF/EOF.
+#[repr(C, align(64))]
+pub struct CdmaBD {
+    pub desc: cdma_sg_desc::RegisterBlock,
+    pub sw_id: u32,
+}
+
+impl CdmaBD {
+    /// Create a new BD which is not linked into any ring.
+    pub fn new() -> Self {
+        Self {
+            desc: cdma_sg_desc::RegisterBlock::default(),
+            sw_id: 0,
+        }
+    }
+
+    /// Set the source address, the destination address and the length of the transfer.
+    pub fn set_transfer(&self, src: usize, dst: usize, len: usize) {
+        trace!(
+            "cdma_bd::set_transfer: src: {:x}, dst: {:x}, len: {}",
+            src,
+            dst,
+            len
+        );
+        unsafe {
+            self.desc
+                .sa()
+                .write(|w| w.source_address().bits((src & 0xFFFF_FFFF) as _));
+            self.desc
+                .sa_msb()
+                .write(|w| w.source_address().bits((src >> 32) as _));
+            self.desc
+                .da()
+                .write(|w| w.destination_address().bits((dst & 0xFFFF_FFFF) as _));
+            self.desc
+                .da_msb()
+                .write(|w| w.destination_address().bits((dst >> 32) as _));
+            self.desc.control().write(|w| w.btt().bits(len as _));
+        }
+    }
+
+    /// Dump the fields of a BD.
+    #[allow(unused)]
+    pub fn dump(&self) {
+        let d = &self.desc;
+        info!(
+            "NXT_DESC_MSB: 0x{:x}, NXT_DESC: 0x{:x}",
+            d.nxt_desc_msb().read().bits(),
+            d.nxt_desc().read().bits()
+        );
+        info!(
+            "SA_MSB: 0x{:x}, SA: 0x{:x}, DA_MSB: 0x{:x}, DA: 0x{:x}",
+            d.sa_msb().read().bits(),
+            d.sa().read().bits(),
+            d.da_msb().read().bits(),
+            d.da().read().bits()
+        );
         info!(
             "CONTROL: 0x{:x}, STATUS: 0x{:x}",
             d.control().read().bits(),
@@ -122,3 +443,55 @@ impl AxiDmaBD {
         );
     }
 }
+
+impl Default for CdmaBD {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Descriptor for CdmaBD {
+    fn desc_addr(&self) -> usize {
+        &self.desc as *const _ as usize
+    }
+
+    fn clear_status(&self) {
+        // The status is written by the hardware, it is read-only in the PAC.
+        unsafe { core::ptr::write_volatile(self.desc.status().as_ptr(), 0) };
+    }
+
+    fn clear(&self) {
+        self.desc.sa().reset();
+        self.desc.sa_msb().reset();
+        self.desc.da().reset();
+        self.desc.da_msb().reset();
+        self.desc.control().reset();
+        // The status is written by the hardware, it is read-only in the PAC.
+        unsafe { core::ptr::write_volatile(self.desc.status().as_ptr(), 0) };
+    }
+
+    fn set_next_desc_addr(&self, addr: usize) {
+        let addr_lsb = ((addr & 0xFFFF_FFFF) >> 6) as _;
+        let addr_msb = (addr >> 32) as _;
+        unsafe {
+            self.desc
+                .nxt_desc()
+                .write(|w| w.nxt_desc_ptr().bits(addr_lsb));
+            self.desc
+                .nxt_desc_msb()
+                .write(|w| w.nxt_desc_ptr().bits(addr_msb));
+        }
+    }
+
+    fn set_sof(&self) {}
+
+    fn set_eof(&self) {}
+
+    fn is_cmplt(&self) -> bool {
+        self.desc.status().read().cmplt().bit_is_set()
+    }
+
+    fn is_eof(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,571 @@
+//! This is the driver API for the AXI Central DMA (CDMA) engine.
+//! See [PG034](https://docs.xilinx.com/r/en-US/pg034-axi-cdma) for the
+//! hardware spec.
+//!
+//! The CDMA copies data from one memory mapped region to another one. It
+//! works in two modes:
+//!
+//! - Simple DMA: a single transfer is programmed through the SA, DA and BTT
+//!   registers. Writing BTT starts the transfer, so only one transfer can be
+//!   in flight.
+//!
+//! - Scatter Gather DMA: the transfers are described by BDs, which are managed
+//!   by the same [`BDRing`] as the AXI DMA. Every BD is a whole transfer.
+//!
+//! Keyhole read/write keeps the source/destination address fixed during a
+//! transfer, which is used to access a FIFO behind a single address.
+//!
+//! Without the Data Realignment Engine, the source and the destination must
+//! be aligned with the data width of the memory map interface.
+//!
+
+use crate::bd::CdmaBD;
+use crate::channel::ChannelSnapshot;
+use crate::clock::Deadline;
+use crate::lock::IrqMutex;
+use crate::ring::BDRing;
+use crate::transfer::TransferChannel;
+use crate::{io_fence, AxiDMAErr, AxiDMAResult, BufPtr, Transfer};
+use alloc::sync::Arc;
+use axidma_pac::{cdma, Instance};
+use core::marker::PhantomData;
+use core::sync::atomic::{compiler_fence, fence, AtomicBool, Ordering, Ordering::SeqCst};
+use core::time::Duration;
+
+/// The configuration structure for AXI CDMA engine.
+#[derive(Debug)]
+pub struct AxiCdmaConfig {
+    /// The base address of the AxiCDMA
+    pub base_address: usize,
+    /// Has Scatter Gather mode
+    pub has_sg: bool,
+    /// Whether the data realignment engine is included
+    pub has_dre: bool,
+    /// The data width of the memory map interface
+    pub data_width: usize,
+    /// The width of the bytes to transfer field
+    pub sg_length_width: usize,
+    /// the width of address
+    pub addr_width: isize,
+}
+
+/// The AxiCdma driver instance structure. An instance must be allocated for each
+/// CDMA engine in use.
+pub struct AxiCdma {
+    // Immutable
+    /// The registers of the AxiCDMA
+    regs: Instance<cdma::RegisterBlock>,
+    /// Has Scatter Gather mode
+    has_sg: bool,
+    /// Whether the data realignment engine is included
+    has_dre: bool,
+    /// The data width of the memory map interface
+    data_width: usize,
+    /// The width of address
+    addr_width: isize,
+    /// The max length of a single transfer
+    max_transfer_len: usize,
+
+    // Mutable
+    /// Whether the AxiCDMA is initialized
+    is_initialized: AtomicBool,
+    /// Whether the engine is in Scatter Gather mode
+    sg_mode: AtomicBool,
+    /// Whether a simple DMA transfer has been started and not retrieved
+    simple_pending: AtomicBool,
+    /// Buffer descriptor ring
+    pub ring: IrqMutex<BDRing<CdmaBD>>,
+}
+
+impl AxiCdma {
+    /// Reset time out
+    const RESET_TIMEOUT: Duration = Duration::from_millis(10);
+
+    /// Create the AxiCDMA instance according to the configuration
+    pub fn new(cfg: AxiCdmaConfig) -> Result<Self, AxiDMAErr> {
+        if !cfg.data_width.is_power_of_two() || !(32..=1024).contains(&cfg.data_width) {
+            error!("cdma::new: invalid data width {}", cfg.data_width);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        if !(8..=26).contains(&cfg.sg_length_width) {
+            error!("cdma::new: invalid sg length width {}", cfg.sg_length_width);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        if cfg.addr_width != 32 && cfg.addr_width != 64 {
+            error!("cdma::new: invalid address width {}", cfg.addr_width);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        Ok(Self {
+            regs: unsafe { Instance::from_base(cfg.base_address) },
+            has_sg: cfg.has_sg,
+            has_dre: cfg.has_dre,
+            data_width: cfg.data_width,
+            addr_width: cfg.addr_width,
+            max_transfer_len: (1usize << cfg.sg_length_width) - 1,
+            is_initialized: AtomicBool::new(false),
+            sg_mode: AtomicBool::new(false),
+            simple_pending: AtomicBool::new(false),
+            ring: IrqMutex::new(BDRing::new()),
+        })
+    }
+
+    /// Get the registers of the AxiCDMA
+    #[inline]
+    fn hardware(&self) -> &cdma::RegisterBlock {
+        &self.regs
+    }
+
+    /// Reset the AxiCDMA, it is back to the simple DMA mode.
+    pub fn reset(self: &Arc<Self>) -> AxiDMAResult {
+        let cr = self.hardware().cdmacr();
+        cr.modify(|_, w| w.reset().set_bit());
+        let mut deadline = Deadline::after(AxiCdma::RESET_TIMEOUT);
+        while cr.read().reset().bit_is_set() {
+            if deadline.expired() {
+                error!("AXICDMA: failed reset in intialization");
+                return Err(AxiDMAErr::Timeout(self.snapshot()));
+            }
+            core::hint::spin_loop();
+        }
+        self.ring.lock().is_halted = true;
+        self.sg_mode.store(false, Ordering::Relaxed);
+        self.simple_pending.store(false, Ordering::Relaxed);
+        self.is_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Take a snapshot of the registers and the ring of the AxiCDMA
+    pub fn snapshot(&self) -> ChannelSnapshot {
+        let hw = self.hardware();
+        let ring = self.ring.lock();
+        ChannelSnapshot {
+            dmacr: hw.cdmacr().read().bits(),
+            dmasr: hw.cdmasr().read().bits(),
+            curdesc: ((hw.curdesc_msb().read().bits() as u64) << 32)
+                | hw.curdesc().read().bits() as u64,
+            taildesc: ((hw.taildesc_msb().read().bits() as u64) << 32)
+                | hw.taildesc().read().bits() as u64,
+            free_cnt: ring.free_cnt,
+            pending_cnt: ring.pending_cnt,
+            submit_cnt: ring.submit_cnt,
+        }
+    }
+
+    /// Creates and setup the BD ring for the Scatter Gather mode.
+    pub fn create(self: &Arc<Self>, bd_count: usize) -> AxiDMAResult {
+        if !self.has_sg {
+            error!("cdma::create: no scatter gather engine");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        self.intr_disable();
+        self.ring.lock().create(bd_count, CdmaBD::new)
+    }
+
+    /// Enable or disable the keyhole read and the keyhole write.
+    /// The keyhole mode can only be changed while the engine is idle.
+    pub fn set_keyhole(self: &Arc<Self>, read: bool, write: bool) -> AxiDMAResult {
+        if !self.is_idle() {
+            error!("cdma::set_keyhole: engine is busy");
+            return Err(AxiDMAErr::DMAErr);
+        }
+        self.hardware()
+            .cdmacr()
+            .modify(|_, w| w.key_read().bit(read).key_write().bit(write));
+        Ok(())
+    }
+
+    /// Copy `src.len()` bytes from `src` to `dst`. The Scatter Gather mode is used
+    /// if the BD ring has been created, otherwise the simple DMA mode is used.
+    ///
+    /// `src` stays borrowed by the returned copy until it is completed, see
+    /// [`CdmaCopy`].
+    pub fn copy<'a>(
+        self: &Arc<Self>,
+        src: &'a BufPtr,
+        dst: BufPtr,
+    ) -> Result<CdmaCopy<'a>, AxiDMAErr> {
+        if !self.is_initialized.load(Ordering::Relaxed) {
+            error!("Copy: Driver not initialized");
+            return Err(AxiDMAErr::NotInit);
+        }
+        let len = src.len();
+        if len == 0 || len > self.max_transfer_len || len > dst.len() {
+            error!(
+                "cdma::copy: invalid len {}, dst len: {}, max: {}",
+                len,
+                dst.len(),
+                self.max_transfer_len
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let src_addr = src.as_ptr() as usize;
+        let dst_addr = dst.as_ptr() as usize;
+        self.check_addr(src_addr, len)?;
+        self.check_addr(dst_addr, len)?;
+        // The ring stays locked from the check of the mode to the start
+        let mut ring = self.ring.lock();
+        let transfer = if self.has_sg && ring.all_cnt > 0 {
+            ring.submit(|bd| {
+                bd.set_transfer(src_addr, dst_addr, len);
+                Ok(())
+            })?;
+            let transfer = Transfer::new(dst, self.clone());
+            self.to_hw(&mut ring)?;
+            transfer
+        } else {
+            self.simple_transfer(&mut ring, src_addr, dst_addr, len)?;
+            Transfer::new(dst, self.clone())
+        };
+        Ok(CdmaCopy {
+            transfer: Some(transfer),
+            cdma: self.clone(),
+            _src: PhantomData,
+        })
+    }
+
+    /// Check that a buffer of `len` bytes at `addr` can be accessed by the
+    /// engine: aligned with the data width without DRE, and below 4G with
+    /// 32-bit addresses
+    fn check_addr(&self, addr: usize, len: usize) -> AxiDMAResult {
+        let word_len = self.data_width / 8;
+        if !self.has_dre && addr & (word_len - 1) != 0 {
+            error!(
+                "cdma::copy: 0x{:x} is not aligned with {} bytes",
+                addr, word_len
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        if self.addr_width == 32 && (addr as u64).saturating_add(len as u64) > 1 << 32 {
+            error!(
+                "cdma::copy: 0x{:x} is out of the 32-bit address space",
+                addr
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        Ok(())
+    }
+
+    /// Whether the engine has completed all the queued transfers
+    pub fn is_idle(&self) -> bool {
+        self.hardware().cdmasr().read().idle().bit_is_set()
+    }
+
+    /// Set interrupt coalescing parameters.
+    /// Only supported interrupt threshold count not timer delay
+    /// The valid threshold is 1 ~ 255
+    pub fn set_coalesce(self: &Arc<Self>, threshold: usize) -> AxiDMAResult {
+        if threshold == 0 || threshold > 0xff {
+            error!("invalid coalescing threshold {}", threshold);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        self.hardware()
+            .cdmacr()
+            .modify(|_, w| unsafe { w.irq_threshold().bits(threshold as _) });
+        Ok(())
+    }
+
+    /// Get the interrupt threshold count
+    pub fn get_coalesce(self: &Arc<Self>) -> usize {
+        self.hardware().cdmacr().read().irq_threshold().bits() as _
+    }
+
+    /// Retrieve the BD from hardware. In the simple DMA mode, the completed
+    /// transfer is counted as one BD.
+    pub fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        if !self.sg_mode.load(Ordering::Relaxed) {
+            let done = self.is_idle() && self.simple_pending.swap(false, Ordering::Relaxed);
+            return Ok(done as usize);
+        }
+        let mut ring = self.ring.lock();
+        compiler_fence(SeqCst);
+        fence(SeqCst);
+        io_fence();
+        Ok(ring.from_hw())
+    }
+
+    /// Wait the engine completing a transaction synchronously.
+    pub fn wait(&self) {
+        let mut status = self.hardware().cdmasr().read();
+        while status.idle().bit_is_clear()
+            && status.ioc_irq().bit_is_clear()
+            && status.dly_irq().bit_is_clear()
+            && status.err_irq().bit_is_clear()
+        {
+            status = self.hardware().cdmasr().read();
+        }
+    }
+
+    /// Disable the interrupt of this engine.
+    pub fn intr_disable(&self) {
+        trace!("cdma intr_disable");
+        self.hardware().cdmacr().modify(|_, w| {
+            w.dly_irq_en()
+                .clear_bit()
+                .err_irq_en()
+                .clear_bit()
+                .ioc_irq_en()
+                .clear_bit()
+        });
+    }
+
+    /// Enable the interrupt of this engine.
+    pub fn intr_enable(&self) {
+        trace!("cdma intr_enable");
+        self.hardware().cdmacr().modify(|_, w| {
+            w.dly_irq_en()
+                .set_bit()
+                .err_irq_en()
+                .set_bit()
+                .ioc_irq_en()
+                .set_bit()
+        });
+    }
+
+    /// Check whether a transaction is completed
+    pub fn check_cmplt(&self) -> bool {
+        let status = self.hardware().cdmasr().read();
+        status.err_irq().bit_is_set()
+            || status.dly_irq().bit_is_set()
+            || status.ioc_irq().bit_is_set()
+    }
+
+    /// The interrupt handler
+    pub fn intr_handler(&self) -> AxiDMAResult {
+        let sr = self.hardware().cdmasr();
+        let status = sr.read();
+        if status.err_irq().bit_is_set() {
+            trace!("cdma_intr: err intr detected");
+            self.dump_regs();
+            sr.modify(|_, w| w.err_irq().set_bit());
+            return Err(AxiDMAErr::IntrErr);
+        }
+        if status.ioc_irq().bit_is_set() {
+            trace!("cdma_intr: cplt intr detected");
+            sr.modify(|_, w| w.ioc_irq().set_bit());
+        }
+        if status.dly_irq().bit_is_set() {
+            trace!("cdma_intr: dly intr detected");
+            sr.modify(|_, w| w.dly_irq().set_bit());
+        }
+        Ok(())
+    }
+
+    /// Dump the register of the engine
+    pub fn dump_regs(&self) {
+        let hw = self.hardware();
+        info!(
+            "CR: 0b{:b}, SR: 0b{:b}",
+            hw.cdmacr().read().bits(),
+            hw.cdmasr().read().bits()
+        );
+        info!(
+            "CDESC_MSB: 0x{:x}, CDESC: 0x{:x}",
+            hw.curdesc_msb().read().bits(),
+            hw.curdesc().read().bits()
+        );
+        info!(
+            "TDESC_MSB: 0x{:x}, TDESC: 0x{:x}",
+            hw.taildesc_msb().read().bits(),
+            hw.taildesc().read().bits()
+        );
+    }
+
+    /// Start the queued BDs. The engine is switched to the Scatter Gather mode
+    /// when it is halted.
+    fn to_hw(&self, ring: &mut BDRing<CdmaBD>) -> AxiDMAResult {
+        let hw = self.hardware();
+        if ring.is_halted {
+            if !self.is_idle() {
+                error!("cdma::to_hw: engine is busy, cannot switch to sg mode");
+                return Err(AxiDMAErr::DMAErr);
+            }
+            // CURDESC can only be written when the SG mode is off.
+            hw.cdmacr().modify(|_, w| w.sg_mode().clear_bit());
+            let addr = ring.head_desc_addr();
+            unsafe {
+                hw.curdesc()
+                    .write(|w| w.curdesc_ptr().bits(((addr & 0xFFFF_FFFF) >> 6) as _));
+                hw.curdesc_msb()
+                    .write(|w| w.curdesc_ptr().bits((addr >> 32) as _));
+            }
+            hw.cdmacr().modify(|_, w| w.sg_mode().set_bit());
+            self.sg_mode.store(true, Ordering::Relaxed);
+            trace!("cdma::to_hw: cur desc addr: 0x{:x}", addr);
+        }
+        compiler_fence(SeqCst);
+        fence(SeqCst);
+        io_fence();
+        if let Some(addr) = ring.to_hw() {
+            // update tail desc, it starts the transfer
+            unsafe {
+                hw.taildesc_msb()
+                    .write(|w| w.taildesc_ptr().bits((addr >> 32) as _));
+                hw.taildesc()
+                    .write(|w| w.taildesc_ptr().bits(((addr & 0xFFFF_FFFF) >> 6) as _));
+            }
+        }
+        Ok(())
+    }
+
+    /// Start a simple DMA transfer. The engine is switched to the simple DMA mode
+    /// if it is in the Scatter Gather mode.
+    fn simple_transfer(
+        &self,
+        ring: &mut BDRing<CdmaBD>,
+        src: usize,
+        dst: usize,
+        len: usize,
+    ) -> AxiDMAResult {
+        let hw = self.hardware();
+        if !self.is_idle() {
+            error!("cdma::simple_transfer: engine is busy");
+            return Err(AxiDMAErr::DMAErr);
+        }
+        if self.sg_mode.swap(false, Ordering::Relaxed) {
+            hw.cdmacr().modify(|_, w| w.sg_mode().clear_bit());
+            ring.is_halted = true;
+        }
+        trace!(
+            "cdma::simple_transfer: src: {:x}, dst: {:x}, len: {}",
+            src,
+            dst,
+            len
+        );
+        compiler_fence(SeqCst);
+        fence(SeqCst);
+        io_fence();
+        unsafe {
+            hw.sa()
+                .write(|w| w.source_address().bits((src & 0xFFFF_FFFF) as _));
+            hw.sa_msb()
+                .write(|w| w.source_address().bits((src >> 32) as _));
+            hw.da()
+                .write(|w| w.destination_address().bits((dst & 0xFFFF_FFFF) as _));
+            hw.da_msb()
+                .write(|w| w.destination_address().bits((dst >> 32) as _));
+            // Writing BTT starts the transfer
+            hw.btt().write(|w| w.btt().bits(len as _));
+        }
+        self.simple_pending.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// A copy of the AxiCDMA, which borrows its source buffer until it is done.
+///
+/// Dropping the copy before it is done blocks until the engine has finished
+/// it, so neither the source nor the destination is released while the
+/// engine still accesses them. Leaking it with [`core::mem::forget`] also
+/// leaks the destination, but releases the borrow of the source early: the
+/// engine may then read the source after it has been reused, and the copied
+/// data is undefined.
+pub struct CdmaCopy<'a> {
+    /// Always `Some` until the copy is waited or polled to completion
+    transfer: Option<Transfer<AxiCdma>>,
+    cdma: Arc<AxiCdma>,
+    _src: PhantomData<&'a BufPtr>,
+}
+
+impl CdmaCopy<'_> {
+    /// Blocks until the copy is done and returns the destination buffer
+    pub fn wait(mut self) -> Result<BufPtr, AxiDMAErr> {
+        match self.transfer.take() {
+            Some(transfer) => transfer.wait(),
+            None => Err(AxiDMAErr::InValidParam),
+        }
+    }
+}
+
+impl Drop for CdmaCopy<'_> {
+    fn drop(&mut self) {
+        if self.transfer.is_some() {
+            warn!("cdma: copy dropped before it is done, wait for the engine");
+            self.cdma.wait();
+            if let Err(e) = self.cdma.finish() {
+                error!("cdma: dropped copy failed: {:?}", e);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "async")]
+impl Future for CdmaCopy<'_> {
+    type Output = BufPtr;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<BufPtr> {
+        let Some(transfer) = self.transfer.as_mut() else {
+            panic!("cdma: copy polled after completion");
+        };
+        let poll = Pin::new(transfer).poll(cx);
+        if poll.is_ready() {
+            self.transfer = None;
+        }
+        poll
+    }
+}
+
+impl TransferChannel for AxiCdma {
+    fn wait(&self) {
+        AxiCdma::wait(self)
+    }
+
+    fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        AxiCdma::from_hw(self)
+    }
+
+    fn intr_handler(&self) -> AxiDMAResult {
+        AxiCdma::intr_handler(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(has_dre: bool, addr_width: isize) -> AxiCdmaConfig {
+        AxiCdmaConfig {
+            base_address: 0,
+            has_sg: true,
+            has_dre,
+            data_width: 64,
+            sg_length_width: 26,
+            addr_width,
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let mut cfg = config(false, 32);
+        cfg.data_width = 48;
+        assert!(AxiCdma::new(cfg).is_err());
+        assert!(AxiCdma::new(config(false, 40)).is_err());
+        let mut cfg = config(false, 32);
+        cfg.sg_length_width = 27;
+        assert!(AxiCdma::new(cfg).is_err());
+    }
+
+    #[test]
+    fn unaligned_buffers_need_the_dre() {
+        let cdma = AxiCdma::new(config(false, 64)).unwrap();
+        assert!(cdma.check_addr(0x1000, 16).is_ok());
+        assert!(cdma.check_addr(0x1004, 16).is_err());
+        let cdma = AxiCdma::new(config(true, 64)).unwrap();
+        assert!(cdma.check_addr(0x1003, 16).is_ok());
+    }
+
+    #[test]
+    fn buffers_stay_below_4g_with_32_bit_addresses() {
+        let cdma = AxiCdma::new(config(false, 32)).unwrap();
+        assert!(cdma.check_addr(0xffff_fff0, 0x10).is_ok());
+        assert!(cdma.check_addr(0xffff_fff8, 0x10).is_err());
+        let cdma = AxiCdma::new(config(false, 64)).unwrap();
+        assert!(cdma.check_addr(0x1_0000_0000, 0x10).is_ok());
+    }
+}
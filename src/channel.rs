@@ -6,21 +6,130 @@
 
 use crate::{io_fence, AxiDMAErr, AxiDMAResult, AxiDmaConfig, BufPtr};
 
-use crate::bd::AxiDmaBD;
-use alloc::{boxed::Box, collections::VecDeque};
-use core::pin::Pin;
-use core::sync::atomic::{compiler_fence, fence, Ordering::SeqCst};
-use spin::Mutex;
+use crate::bd::{AxiDmaBD, Descriptor};
+use crate::clock::Deadline;
+use crate::completion::{Completion, CompletionCallback, CompletionState, CompletionStatus};
+use crate::dmaengine::{DmaChannel, DmaCookie, DmaStatus, DmaTransferDirection};
+use crate::lock::{IrqMutex, IrqMutexGuard};
+use crate::napi::{NapiConfig, NapiState};
 #[cfg(feature = "async")]
-use core::task::Waker;
+use crate::ready::SubmitReady;
+use crate::ring::BDRing;
+use crate::staging::{StagedPacket, StagingQueue};
+use crate::stats::{ChannelStats, StatsCounters};
+use crate::transfer::TransferChannel;
+use crate::watchdog::{Sample, StallRecovery, StallReport, Watchdog, WatchdogConfig};
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use axidma_pac::{channel, Instance};
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+use core::sync::atomic::{
+    compiler_fence, fence, AtomicBool, AtomicUsize, Ordering::Relaxed, Ordering::Release,
+    Ordering::SeqCst,
+};
+use core::time::Duration;
+#[cfg(feature = "async")]
+use core::task::{Context, Poll, Waker};
 
 /// The channel direction
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direaction {
     TX,
     RX,
 }
 
+/// The bookkeeping of the descriptors submitted through [`DmaChannel`]
+struct EngineState {
+    /// The cookie of the cyclic descriptor occupying the ring
+    cyclic: Option<DmaCookie>,
+}
+
+impl EngineState {
+    const fn new() -> Self {
+        Self { cyclic: None }
+    }
+}
+
+/// The guard of the ring lock of a channel. The packets staged by
+/// [`AxiDMAChannel::submit_mp`] while the lock is held are flushed when it is
+/// released.
+struct RingGuard<'a> {
+    guard: ManuallyDrop<IrqMutexGuard<'a, BDRing>>,
+    channel: &'a AxiDMAChannel,
+}
+
+impl Deref for RingGuard<'_> {
+    type Target = BDRing;
+
+    fn deref(&self) -> &BDRing {
+        &self.guard
+    }
+}
+
+impl DerefMut for RingGuard<'_> {
+    fn deref_mut(&mut self) -> &mut BDRing {
+        &mut self.guard
+    }
+}
+
+impl Drop for RingGuard<'_> {
+    fn drop(&mut self) {
+        // Safety: the guard is never used again
+        let guard = unsafe { ManuallyDrop::take(&mut self.guard) };
+        if self.channel.staged.release(guard) {
+            if let Err(err) = self.channel.flush_staged() {
+                error!("axidma: failed to flush the staged packets: {:?}", err);
+            }
+        }
+    }
+}
+
+/// A descriptor prepared by the dmaengine-style API of the AxiDMA channel.
+pub struct AxiDmaDescriptor {
+    /// The buffers of the descriptor, every buffer is a packet in cyclic mode
+    buffers: Vec<BufPtr>,
+    /// Whether the descriptor is cyclic
+    cyclic: bool,
+}
+
+/// The state of a channel when an operation timed out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelSnapshot {
+    /// The DMACR register
+    pub dmacr: u32,
+    /// The DMASR register
+    pub dmasr: u32,
+    /// The CURDESC register, with its MSB
+    pub curdesc: u64,
+    /// The TAILDESC register, with its MSB
+    pub taildesc: u64,
+    /// The number of free BDs
+    pub free_cnt: usize,
+    /// The number of BDs submitted but not moved to the hardware
+    pub pending_cnt: usize,
+    /// The number of BDs in the hardware group
+    pub submit_cnt: usize,
+}
+
+impl ChannelSnapshot {
+    /// A snapshot of the registers only, for the engines which have no BD
+    /// ring. `curdesc` and `taildesc` are 0 if they have no SG engine either.
+    pub(crate) fn registers(dmacr: u32, dmasr: u32, curdesc: u64, taildesc: u64) -> Self {
+        Self {
+            dmacr,
+            dmasr,
+            curdesc,
+            taildesc,
+            free_cnt: 0,
+            pending_cnt: 0,
+            submit_cnt: 0,
+        }
+    }
+}
+
 /// The structure of DMA channel for descriptor storage control.
 /// It only supports the Scatter Gather mode.
 /// Not supported feature:
@@ -28,8 +137,8 @@ pub enum Direaction {
 ///     - [ ] address translation(All address must be physical address)
 pub struct AxiDMAChannel {
     // Immutable
-    /// The base address of register space of Channel
-    channel_baseaddr: usize,
+    /// The registers of the channel
+    regs: Instance<channel::RegisterBlock>,
     /// Whether has stscntrl stream
     has_sts_cntrl_strm: bool,
     /// Whether the Data Realignment Enable
@@ -38,143 +147,301 @@ pub struct AxiDMAChannel {
     data_width: usize,
     ///
     max_transfer_len: usize,
+    /// Whether the micro dma mode is enable
+    is_micro_dma: bool,
+    /// The number of bytes of a burst
+    burst_len: usize,
+    /// The direction of the channel
+    direction: Direaction,
 
     // Mutable
     /// Buffer descriptor ring
-    pub ring: Mutex<BDRing>,
+    pub ring: IrqMutex<BDRing>,
+    /// The packets pushed by [`AxiDMAChannel::submit_mp`]
+    staged: StagingQueue,
+    /// The number of BDs of the ring, read without the ring lock
+    bd_count: AtomicUsize,
+    /// The staged packets which did not fit into the ring, only accessed
+    /// with the ring lock held
+    backlog: IrqMutex<VecDeque<StagedPacket>>,
+    /// The packets in the ring and the completion queue, only updated with
+    /// the ring lock held
+    completions: IrqMutex<CompletionState>,
+    /// The descriptors submitted through the dmaengine-style API
+    engine: IrqMutex<EngineState>,
+    /// The state of the interrupt-then-poll mode, `None` when it is off
+    napi: IrqMutex<Option<NapiState>>,
+    /// The stall watchdog, `None` when it is off
+    watchdog: IrqMutex<Option<Watchdog>>,
+    /// The statistics counters
+    stats: StatsCounters,
 
     #[cfg(feature = "async")]
     /// future wakers
-    pub wakers: Mutex<VecDeque<Waker>>,
-}
-
-
-
-/// The structure of BDRing, it must be access exclusively.
-pub struct BDRing {
-    /// Whether channel is halted
-    is_halted: bool,
-    /// BD ring
-    bds: VecDeque<Pin<Box<AxiDmaBD>>>,
-    /// The index of first BD in the work group
-    bd_head: usize,
-    /// The index of last BD in the work group
-    bd_tail: usize,
-    /// BD to load when channel is started
-    bd_restart: usize,
-    /// Number of BDs in free group
-    free_cnt: usize,
-    /// Total Number of BDs for channel
-    all_cnt: usize,
-    /// Used for multiple transaction
-    pending_cnt: usize,
-    /// Used for multiple transaction
-    submit_cnt: usize,
+    pub wakers: IrqMutex<VecDeque<Waker>>,
+    #[cfg(feature = "async")]
+    /// The wakers waiting for free BDs, woken by [`AxiDMAChannel::from_hw`]
+    ready_wakers: IrqMutex<Vec<Waker>>,
 }
 
-impl BDRing {
-    /// the pointer of head buffer descriptor in the work group
-    fn head_desc_addr(&self) -> usize {
-        &self.bds[self.bd_head].desc as *const _ as usize
-    }
-
-    /// the pointer of tail buffer descriptor in the work group
-    fn tail_desc_addr(&self) -> usize {
-        &self.bds[self.bd_tail].desc as *const _ as usize
-    }
-}
 impl AxiDMAChannel {
+    /// Halt time out
+    const HALT_TIMEOUT: Duration = Duration::from_millis(10);
+
     /// Create a new channel without any buffer descriptor.
     pub fn new(direction: Direaction, cfg: &AxiDmaConfig) -> Self {
         let max_transfer_len = (1usize << cfg.sg_length_width) - 1;
-        let (has_dre, data_width, channel_baseaddr) = match direction {
+        let (has_dre, data_width, burst_size, channel_offset) = match direction {
             Direaction::TX => (
                 cfg.has_mm2s_dre,
                 cfg.mm2s_data_width,
-                cfg.base_address + cfg.tx_channel_offset,
+                cfg.mm2s_burst_size,
+                cfg.tx_channel_offset,
             ),
             Direaction::RX => (
                 cfg.has_s2mm_dre,
                 cfg.s2mm_data_width,
-                cfg.base_address + cfg.rx_channel_offset,
+                cfg.s2mm_burst_size,
+                cfg.rx_channel_offset,
             ),
         };
+        // The data realignment engine is not available in micro dma mode
+        if cfg.is_micro_dma && has_dre {
+            warn!("axidma: dre is not available in micro dma mode, ignored");
+        }
         Self {
-            channel_baseaddr,
+            regs: unsafe { Instance::from_base(cfg.base_address + channel_offset) },
             has_sts_cntrl_strm: cfg.has_sts_cntrl_strm,
-            has_dre,
+            has_dre: has_dre && !cfg.is_micro_dma,
             data_width,
             max_transfer_len,
-            ring: Mutex::new(BDRing {
-                is_halted: true,
-                bds: VecDeque::new(),
-                bd_head: 0,
-                bd_tail: 0,
-                bd_restart: 0,
-                free_cnt: 0,
-                all_cnt: 0,
-                pending_cnt: 0,
-                submit_cnt: 0,
-            }),
+            is_micro_dma: cfg.is_micro_dma,
+            burst_len: data_width / 8 * burst_size,
+            direction,
+            ring: IrqMutex::new(BDRing::new()),
+            staged: StagingQueue::new(),
+            bd_count: AtomicUsize::new(0),
+            backlog: IrqMutex::new(VecDeque::new()),
+            completions: IrqMutex::new(CompletionState::new()),
+            engine: IrqMutex::new(EngineState::new()),
+            napi: IrqMutex::new(None),
+            watchdog: IrqMutex::new(None),
+            stats: StatsCounters::new(),
+            #[cfg(feature = "async")]
+            wakers: IrqMutex::new(VecDeque::new()),
             #[cfg(feature = "async")]
-            wakers: Mutex::new(VecDeque::new()),
+            ready_wakers: IrqMutex::new(Vec::new()),
         }
     }
 
     /// Creates and setup the BD ring.
     pub fn create(&self, bd_count: usize) -> AxiDMAResult {
-        if bd_count <= 0 {
-            error!("non-positive BD number {}", bd_count);
-            return Err(AxiDMAErr::InValidParam);
-        }
-        let mut ring = self.ring.lock();
-        ring.bds.clear();
-        ring.bds.reserve(bd_count);
-        for _ in 0..bd_count {
-            let bd = Box::pin(AxiDmaBD::new(
+        let mut ring = self.lock_ring();
+        ring.create(bd_count, || {
+            AxiDmaBD::new(
                 self.has_sts_cntrl_strm,
                 self.has_dre,
-                self.data_width as _,
-            ));
-            ring.bds.push_back(bd);
-        }
-        // link bd chain
-        for i in 0..bd_count {
-            let next_addr = &ring.bds[(i + 1) % bd_count].desc as *const _ as usize;
-            ring.bds[i].set_next_desc_addr(next_addr);
-        }
-        ring.pending_cnt = 0;
-        ring.submit_cnt = 0;
-        ring.is_halted = true;
-        ring.all_cnt = bd_count;
-        ring.free_cnt = bd_count;
-        ring.bd_head = 0;
-        ring.bd_tail = 0;
-        ring.bd_restart = 0;
+                (self.data_width / 8) as _,
+            )
+        })?;
+        // The packets of the old ring are dropped
+        self.completions.lock().abort_all();
+        drop(ring);
+        self.wake_ready();
+        self.run_callbacks();
+        self.bd_count.store(bd_count, Relaxed);
         Ok(())
     }
 
     /// Reset this channel.
     pub fn reset(&self) -> AxiDMAResult {
         self.hardware().dmacr().modify(|_, w| w.reset().reset());
-        self.ring.lock().is_halted = true;
+        self.stats.reset();
+        let mut ring = self.lock_ring();
+        ring.is_halted = true;
+        ring.resume_at = None;
         Ok(())
     }
 
     /// Start this channel.
     pub fn start(&self) -> AxiDMAResult {
+        if self.lock_ring().all_cnt == 0 {
+            error!("axidma::start: BD ring is not created");
+            return Err(AxiDMAErr::BDRingNoList);
+        }
         self.hardware().dmacr().modify(|_, w| w.run_stop().run());
-        self.ring.lock().is_halted = false;
+        self.lock_ring().is_halted = false;
         Ok(())
     }
 
-    /// Stop this channel.
+    /// Stop this channel and wait for it to halt.
     pub fn stop(&self) -> AxiDMAResult {
+        self.stop_timeout(Self::HALT_TIMEOUT)
+    }
+
+    /// Stop this channel and wait at most `timeout` for it to halt, the
+    /// outstanding transfers of the hardware are completed before. The first
+    /// BD not completed is recorded, so the next `to_hw` or
+    /// [`AxiDMAChannel::resume`] continues exactly where the channel stopped.
+    pub fn stop_timeout(&self, timeout: Duration) -> AxiDMAResult {
         self.hardware().dmacr().modify(|_, w| w.run_stop().stop());
-        self.ring.lock().is_halted = true;
+        self.lock_ring().is_halted = true;
+        let mut deadline = Deadline::after(timeout);
+        while self.hardware().dmasr().read().halted().is_running() {
+            if deadline.expired() {
+                error!("axidma::stop: failed to halt the channel");
+                return Err(AxiDMAErr::Timeout(self.snapshot()));
+            }
+            core::hint::spin_loop();
+        }
+        compiler_fence(SeqCst);
+        fence(SeqCst);
+        io_fence();
+        let mut ring = self.lock_ring();
+        ring.is_stopped = true;
+        if ring.all_cnt > 0 {
+            ring.resume_at = ring.first_uncompleted();
+            trace!(
+                "axidma::stop: resume at {:?}, curdesc: 0x{:x}",
+                ring.resume_at,
+                self.hardware().curdesc().read().bits()
+            );
+        }
         Ok(())
     }
 
+    /// Stop this channel gracefully: wait at most `timeout` for it to halt,
+    /// then retrieve the completed BDs. The BDs left in the hardware group
+    /// are resumed by [`AxiDMAChannel::resume`]. It returns the number of
+    /// retrieved BDs.
+    pub fn quiesce(&self, timeout: Duration) -> Result<usize, AxiDMAErr> {
+        self.stop_timeout(timeout)?;
+        self.from_hw()
+    }
+
+    /// Stop this channel as [`AxiDMAChannel::quiesce`], then abort all the
+    /// packets left in the ring and the staged ones. The buffers handed over
+    /// to the channel are given back with [`CompletionStatus::Aborted`].
+    pub fn quiesce_abort(&self, timeout: Duration) -> Result<usize, AxiDMAErr> {
+        let cnt = self.quiesce(timeout)?;
+        let mut ring = self.lock_ring();
+        ring.discard();
+        self.completions.lock().abort_all();
+        self.backlog.lock().clear();
+        self.staged.take_all();
+        drop(ring);
+        self.wake_ready();
+        self.run_callbacks();
+        Ok(cnt)
+    }
+
+    /// Take a snapshot of the statistics counters, they are all zero without
+    /// the `stats` feature.
+    pub fn stats(&self) -> ChannelStats {
+        self.stats.snapshot()
+    }
+
+    /// Clear the statistics counters
+    pub fn clear_stats(&self) {
+        self.stats.clear();
+    }
+
+    /// Turn on the stall watchdog, see [`crate::AxiDma::watchdog_check`]
+    pub fn watchdog_enable(&self, config: WatchdogConfig) {
+        *self.watchdog.lock() = Some(Watchdog::new(config));
+    }
+
+    /// Turn off the stall watchdog
+    pub fn watchdog_disable(&self) {
+        self.watchdog.lock().take();
+    }
+
+    /// Sample the progress of the channel and return the report of a stall.
+    /// The recovery is applied by the AxiDMA, because the reset of a channel
+    /// resets the whole engine.
+    pub(crate) fn watchdog_sample(&self) -> Option<StallReport> {
+        let hw = self.hardware();
+        let ring = self.lock_ring();
+        let status = hw.dmasr().read();
+        let busy =
+            ring.submit_cnt > 0 && status.idle().is_not_idle() && status.halted().is_running();
+        let sample = Sample {
+            curdesc: ((hw.curdesc_msb().read().bits() as u64) << 32)
+                | hw.curdesc().read().bits() as u64,
+            bd_head: ring.bd_head,
+            submit_cnt: ring.submit_cnt,
+        };
+        drop(ring);
+        let mut watchdog = self.watchdog.lock();
+        let state = watchdog.as_mut()?;
+        if !state.observe(sample, busy) {
+            return None;
+        }
+        let recovery = state.config.recovery;
+        drop(watchdog);
+        let snapshot = self.snapshot();
+        warn!("axidma::watchdog: channel stalled, {:?}", snapshot);
+        Some(StallReport { snapshot, recovery })
+    }
+
+    /// Prepare the channel for the reset of a recovery: retrieve the completed
+    /// BDs and return DMACR to restore.
+    pub(crate) fn recover_begin(&self) -> Result<u32, AxiDMAErr> {
+        self.from_hw()?;
+        Ok(self.hardware().dmacr().read().bits())
+    }
+
+    /// Restore the channel after the reset of a recovery. DMACR is restored,
+    /// then the packets of the hardware group are started again, or aborted
+    /// with [`StallRecovery::FailPending`].
+    pub(crate) fn recover_end(&self, dmacr: u32, recovery: StallRecovery) -> AxiDMAResult {
+        self.hardware()
+            .dmacr()
+            .write(|w| unsafe { w.bits(dmacr) }.run_stop().stop());
+        let mut ring = self.lock_ring();
+        if recovery != StallRecovery::FailPending {
+            // The packets of the hardware group start again from their first BD
+            for i in 0..ring.submit_cnt {
+                ring.bds[(ring.bd_head + i) % ring.all_cnt].clear_status();
+            }
+            if ring.all_cnt > 0 {
+                self.ring_to_hw(&mut ring)?;
+            }
+            return Ok(());
+        }
+        ring.discard();
+        self.completions.lock().abort_all();
+        self.backlog.lock().clear();
+        self.staged.take_all();
+        drop(ring);
+        self.wake_ready();
+        self.run_callbacks();
+        Ok(())
+    }
+
+    /// Resume this channel after [`AxiDMAChannel::stop`] or
+    /// [`AxiDMAChannel::quiesce`], from the first BD it has not completed.
+    pub fn resume(&self) -> AxiDMAResult {
+        self.ring_to_hw(&mut self.lock_ring())
+    }
+
+    /// Take a snapshot of the registers and the ring of this channel
+    pub fn snapshot(&self) -> ChannelSnapshot {
+        let hw = self.hardware();
+        let ring = self.lock_ring();
+        ChannelSnapshot {
+            dmacr: hw.dmacr().read().bits(),
+            dmasr: hw.dmasr().read().bits(),
+            curdesc: ((hw.curdesc_msb().read().bits() as u64) << 32)
+                | hw.curdesc().read().bits() as u64,
+            taildesc: ((hw.taildesc_msb().read().bits() as u64) << 32)
+                | hw.taildesc().read().bits() as u64,
+            free_cnt: ring.free_cnt,
+            pending_cnt: ring.pending_cnt,
+            submit_cnt: ring.submit_cnt,
+        }
+    }
+
     // Check whether reset is done when both went normal
     pub fn reset_is_done(&self) -> bool {
         if self.hardware().dmacr().read().reset().is_reset() {
@@ -202,114 +469,585 @@ impl AxiDMAChannel {
         self.hardware().dmacr().read().irq_threshold().bits() as _
     }
 
+    /// Set both the interrupt threshold and the interrupt delay
+    fn write_coalesce(&self, threshold: u8, delay: u8) {
+        self.hardware()
+            .dmacr()
+            .modify(|_, w| unsafe { w.irq_threshold().bits(threshold).irq_delay().bits(delay) });
+    }
+
+    /// Turn on the interrupt-then-poll mode, the interrupt coalescing is tuned
+    /// within the bounds of `config`.
+    pub fn napi_enable(&self, config: NapiConfig) -> AxiDMAResult {
+        config.validate()?;
+        let state = NapiState::new(config);
+        self.write_coalesce(state.threshold, state.delay());
+        *self.napi.lock() = Some(state);
+        self.intr_enable();
+        Ok(())
+    }
+
+    /// Turn off the interrupt-then-poll mode, the interrupts are enabled and
+    /// the current interrupt coalescing is kept.
+    pub fn napi_disable(&self) {
+        self.napi.lock().take();
+        self.intr_enable();
+    }
+
+    /// Whether the interrupts are disabled and the channel waits for
+    /// [`AxiDMAChannel::poll`]
+    pub fn napi_scheduled(&self) -> bool {
+        self.napi
+            .lock()
+            .as_ref()
+            .is_some_and(|state| state.scheduled)
+    }
+
+    /// Retrieve at most `budget` completed packets in the interrupt-then-poll
+    /// mode and return their number. When it is less than `budget`, the ring
+    /// is drained, the polling round ends and the interrupts are enabled again.
+    pub fn poll(&self, budget: usize) -> Result<usize, AxiDMAErr> {
+        if self.napi.lock().is_none() {
+            error!("axidma::poll: the polling mode is off");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        // A packet completed from now on sets the status again
+        self.hardware()
+            .dmasr()
+            .modify(|_, w| w.ioc_irq().set_bit().dly_irq().set_bit());
+        let mut pkts = 0;
+        self.from_hw_limit(budget, |_, _| pkts += 1)?;
+        self.run_callbacks();
+        let mut napi = self.napi.lock();
+        if let Some(state) = napi.as_mut() {
+            if let Some(tuned) = state.end_poll(pkts, budget) {
+                if tuned {
+                    self.write_coalesce(state.threshold, state.delay());
+                }
+                self.intr_enable();
+            }
+        }
+        Ok(pkts)
+    }
+
     /// Submit a buffer to ring
     pub fn submit(&self, buffer: BufPtr) -> Result<BufPtr, AxiDMAErr> {
-        if buffer.len() > self.max_transfer_len {
-            error!("single buffer len has exceed max transfer len");
+        self.submit_sg(core::slice::from_ref(&buffer))?;
+        Ok(buffer)
+    }
+
+    /// Submit a buffer to ring with the user application words, which are sent
+    /// on the control stream, e.g. the checksum offload command of AXI Ethernet.
+    /// It requires the status/control stream.
+    pub fn submit_app(&self, buffer: BufPtr, app: &[u32; 5]) -> Result<BufPtr, AxiDMAErr> {
+        if !self.has_sts_cntrl_strm {
+            error!("axidma::submit_app: no status/control stream");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        self.submit_packet(core::slice::from_ref(&buffer), Some(app))?;
+        Ok(buffer)
+    }
+
+    /// Submit several buffers to ring as a single packet, e.g. the header and
+    /// the payload of a frame. Without the data realignment engine, the length
+    /// of every buffer except the last one must be a multiple of the data word.
+    pub fn submit_sg(&self, buffers: &[BufPtr]) -> AxiDMAResult {
+        self.submit_packet(buffers, None)?;
+        Ok(())
+    }
+
+    /// Submit several buffers to ring as a single packet, the application words
+    /// are set in the first BD. It returns the cookie of the packet.
+    fn submit_packet(
+        &self,
+        buffers: &[BufPtr],
+        app: Option<&[u32; 5]>,
+    ) -> Result<DmaCookie, AxiDMAErr> {
+        let chunks = self.prepare_packet(buffers)?;
+        self.ring_submit(&mut self.lock_ring(), &chunks, app, None, None)
+    }
+
+    /// Submit a buffer to ring and hand it over to the channel. It returns the
+    /// cookie of the packet, and the buffer is given back in its [`Completion`].
+    pub fn submit_queued(&self, buffer: BufPtr) -> Result<DmaCookie, AxiDMAErr> {
+        let chunks = self.prepare_packet(core::slice::from_ref(&buffer))?;
+        self.ring_submit(&mut self.lock_ring(), &chunks, None, Some(buffer), None)
+    }
+
+    /// Submit a buffer to ring and hand it over to the channel as
+    /// [`AxiDMAChannel::submit_queued`], but `callback` is invoked with the
+    /// record of the packet instead of queuing it. The callback usually runs
+    /// in interrupt context from [`AxiDMAChannel::intr_handler`], so the
+    /// interrupt on complete must be enabled. See the [`Completion`] contract
+    /// for the other contexts.
+    pub fn submit_with_callback(
+        &self,
+        buffer: BufPtr,
+        callback: CompletionCallback,
+    ) -> Result<DmaCookie, AxiDMAErr> {
+        if self.napi.lock().is_none() && self.hardware().dmacr().read().ioc_irq_en().is_disable() {
+            error!("axidma::submit_with_callback: the interrupt is disabled");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let chunks = self.prepare_packet(core::slice::from_ref(&buffer))?;
+        self.ring_submit(
+            &mut self.lock_ring(),
+            &chunks,
+            None,
+            Some(buffer),
+            Some(callback),
+        )
+    }
+
+    /// Invoke the callbacks of the completed and the aborted packets. It must
+    /// be called without any lock of the channel held.
+    fn run_callbacks(&self) {
+        let callbacks = self.completions.lock().take_callbacks();
+        for (callback, completion) in callbacks {
+            callback(completion);
+        }
+    }
+
+    /// Submit a buffer with the user application words as
+    /// [`AxiDMAChannel::submit_queued`]. It requires the status/control stream.
+    pub fn submit_queued_app(
+        &self,
+        buffer: BufPtr,
+        app: &[u32; 5],
+    ) -> Result<DmaCookie, AxiDMAErr> {
+        if !self.has_sts_cntrl_strm {
+            error!("axidma::submit_queued_app: no status/control stream");
             return Err(AxiDMAErr::InValidParam);
         }
-        let mut ring = self.ring.lock();
-        if 1 > ring.free_cnt {
-            error!("no free bd");
+        let chunks = self.prepare_packet(core::slice::from_ref(&buffer))?;
+        self.ring_submit(
+            &mut self.lock_ring(),
+            &chunks,
+            Some(app),
+            Some(buffer),
+            None,
+        )
+    }
+
+    /// Take the oldest record of the completion queue
+    pub fn pop_completion(&self) -> Option<Completion> {
+        self.completions.lock().pop()
+    }
+
+    /// Take all the records of the completion queue, `f` is called on every
+    /// record in order without any lock held. It returns the number of records.
+    pub fn drain_completions<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(Completion),
+    {
+        let done = self.completions.lock().take();
+        let cnt = done.len();
+        done.into_iter().for_each(&mut f);
+        cnt
+    }
+
+    /// Split the buffers of a packet into the (address, length) of its BDs and
+    /// check them, so that filling the BDs can only fail on a full ring.
+    fn prepare_packet(&self, buffers: &[BufPtr]) -> Result<Vec<(usize, usize)>, AxiDMAErr> {
+        let mut chunks = Vec::with_capacity(buffers.len());
+        for buffer in buffers {
+            self.split_buf(buffer, &mut chunks)?;
+        }
+        let word_len = self.data_width / 8;
+        if !self.has_dre {
+            if let Some(&(addr, _)) = chunks.iter().find(|(addr, _)| addr & (word_len - 1) != 0) {
+                error!(
+                    "axidma::submit: buf 0x{:x} is not aligned with {} byte without dre",
+                    addr, word_len
+                );
+                return Err(AxiDMAErr::InValidParam);
+            }
+            let last = chunks.len().saturating_sub(1);
+            if let Some(&(_, len)) = chunks[..last]
+                .iter()
+                .find(|(_, len)| len & (word_len - 1) != 0)
+            {
+                error!(
+                    "axidma::submit: non-final buf len {} is not a multiple of {} byte without dre",
+                    len, word_len
+                );
+                return Err(AxiDMAErr::InValidParam);
+            }
+        }
+        Ok(chunks)
+    }
+
+    /// Fill the BDs of a packet in the ring and record the packet, a
+    /// completion is queued for it if `buffer` is handed over.
+    fn ring_submit(
+        &self,
+        ring: &mut BDRing,
+        chunks: &[(usize, usize)],
+        app: Option<&[u32; 5]>,
+        buffer: Option<BufPtr>,
+        callback: Option<CompletionCallback>,
+    ) -> Result<DmaCookie, AxiDMAErr> {
+        let result = ring.submit_packet(chunks.len(), |i, bd| {
+            let (addr, len) = chunks[i];
+            let chunk = unsafe { NonNull::new_unchecked(addr as *mut u8) };
+            bd.set_buf(&BufPtr::new(chunk, len))?;
+            match app {
+                Some(app) if i == 0 => bd.set_app(app),
+                _ => Ok(()),
+            }
+        });
+        if let Err(AxiDMAErr::BDRingNoList) = result {
+            self.stats.submit_full();
+        }
+        result?;
+        self.stats.occupancy(ring.all_cnt - ring.free_cnt);
+        Ok(self.completions.lock().push(buffer, callback))
+    }
+
+    /// Split the buffers into BDs as [`AxiDMAChannel::prepare_packet`], the
+    /// packet must fit into the empty ring.
+    fn prepare_bounded(&self, buffers: &[BufPtr]) -> Result<Vec<(usize, usize)>, AxiDMAErr> {
+        let chunks = self.prepare_packet(buffers)?;
+        if chunks.len() > self.bd_count.load(Relaxed) {
+            error!(
+                "axidma::submit: packet of {} BDs never fits into the ring",
+                chunks.len()
+            );
             return Err(AxiDMAErr::BDRingNoList);
         }
-        let start = ring.bd_restart;
-        let bd = &ring.bds[ring.bd_restart];
-        bd.clear();
-        bd.set_buf(&buffer)?;
-        ring.bd_restart += 1;
-        if ring.bd_restart == ring.all_cnt {
-            ring.bd_restart = 0;
+        Ok(chunks)
+    }
+
+    /// Whether the packet of `buffer` fits into the free BDs of the ring now.
+    /// It fails if the packet never fits into the ring.
+    pub fn ready(&self, buffer: &BufPtr) -> Result<bool, AxiDMAErr> {
+        let chunks = self.prepare_bounded(core::slice::from_ref(buffer))?;
+        Ok(self.lock_ring().free_cnt >= chunks.len())
+    }
+
+    /// Submit a buffer to ring as [`AxiDMAChannel::submit`], waiting for free
+    /// BDs if the ring is full. The completed BDs are retrieved while waiting.
+    /// It waits at most `timeout`, `None` waits forever.
+    pub fn submit_blocking(
+        &self,
+        buffer: BufPtr,
+        timeout: Option<Duration>,
+    ) -> Result<BufPtr, AxiDMAErr> {
+        let chunks = self.prepare_bounded(core::slice::from_ref(&buffer))?;
+        let mut deadline = timeout.map(Deadline::after);
+        let mut counted = false;
+        loop {
+            {
+                let mut ring = self.lock_ring();
+                if ring.free_cnt >= chunks.len() {
+                    self.ring_submit(&mut ring, &chunks, None, None, None)?;
+                    return Ok(buffer);
+                }
+            }
+            // The submission is counted once, however long it waits
+            if !counted {
+                self.stats.submit_full();
+                counted = true;
+            }
+            if deadline.as_mut().is_some_and(Deadline::expired) {
+                error!("axidma::submit_blocking: no free bd in time");
+                return Err(AxiDMAErr::Timeout(self.snapshot()));
+            }
+            self.from_hw()?;
+            core::hint::spin_loop();
         }
-        ring.bd_tail = if ring.bd_restart == 0 {
-            ring.bds.len() - 1
-        } else {
-            ring.bd_restart - 1
+    }
+
+    /// Check whether the packet of `buffer` fits into the free BDs of the
+    /// ring. If not, the waker of `cx` is woken once BDs are freed.
+    #[cfg(feature = "async")]
+    pub fn poll_ready(&self, buffer: &BufPtr, cx: &mut Context<'_>) -> Poll<AxiDMAResult> {
+        let chunks = match self.prepare_bounded(core::slice::from_ref(buffer)) {
+            Ok(chunks) => chunks,
+            Err(err) => return Poll::Ready(Err(err)),
         };
-        ring.bds[start]
-            .desc
-            .control()
-            .modify(|_, w| w.sof().set_bit());
-        ring.bds[ring.bd_tail]
-            .desc
-            .control()
-            .modify(|_, w| w.eof().set_bit());
-
-        ring.free_cnt -= 1;
-        ring.pending_cnt += 1;
+        let ring = self.lock_ring();
+        if ring.free_cnt >= chunks.len() {
+            return Poll::Ready(Ok(()));
+        }
+        self.register_ready(cx);
+        Poll::Pending
+    }
+
+    /// Submit a buffer to ring as [`AxiDMAChannel::submit`] once enough BDs
+    /// are free.
+    #[cfg(feature = "async")]
+    pub fn submit_ready(&self, buffer: BufPtr) -> Result<SubmitReady<'_>, AxiDMAErr> {
+        let chunks = self.prepare_bounded(core::slice::from_ref(&buffer))?;
+        Ok(SubmitReady::new(self, chunks, buffer))
+    }
+
+    /// Submit the packet if it fits into the free BDs, or register the waker
+    /// of `cx` with the ring lock held. The full ring is counted once per
+    /// submission, `counted` tells whether it has been.
+    #[cfg(feature = "async")]
+    pub(crate) fn poll_submit(
+        &self,
+        chunks: &[(usize, usize)],
+        counted: &mut bool,
+        cx: &mut Context<'_>,
+    ) -> Poll<AxiDMAResult> {
+        let mut ring = self.lock_ring();
+        if ring.free_cnt >= chunks.len() {
+            return Poll::Ready(
+                self.ring_submit(&mut ring, chunks, None, None, None)
+                    .map(|_| ()),
+            );
+        }
+        if !*counted {
+            self.stats.submit_full();
+            *counted = true;
+        }
+        self.register_ready(cx);
+        Poll::Pending
+    }
+
+    /// Register the waker of `cx`, it is woken once BDs are retrieved. It must
+    /// be registered before checking the state it waits for.
+    #[cfg(feature = "async")]
+    pub(crate) fn register_ready(&self, cx: &mut Context<'_>) {
+        let mut wakers = self.ready_wakers.lock();
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+    }
+
+    /// Submit a buffer from any core without waiting for the other producers.
+    /// The buffer is checked and staged, then the holder of the ring lock moves
+    /// all the staged buffers into the ring in order and starts them with a
+    /// single TAILDESC update. A buffer which does not fit into the ring stays
+    /// staged until BDs are retrieved by [`AxiDMAChannel::from_hw`].
+    pub fn submit_mp(&self, buffer: BufPtr) -> Result<BufPtr, AxiDMAErr> {
+        self.submit_mp_packet(core::slice::from_ref(&buffer), None, None)?;
+        Ok(buffer)
+    }
+
+    /// Submit several buffers as a single packet as [`AxiDMAChannel::submit_mp`]
+    pub fn submit_mp_sg(&self, buffers: &[BufPtr]) -> AxiDMAResult {
+        self.submit_mp_packet(buffers, None, None)
+    }
+
+    /// Submit a buffer as [`AxiDMAChannel::submit_mp`], the returned flag is
+    /// set once the buffer is moved into the ring
+    pub(crate) fn submit_mp_tracked(&self, buffer: &BufPtr) -> Result<Arc<AtomicBool>, AxiDMAErr> {
+        let in_ring = Arc::new(AtomicBool::new(false));
+        self.submit_mp_packet(core::slice::from_ref(buffer), None, Some(in_ring.clone()))?;
+        Ok(in_ring)
+    }
+
+    fn submit_mp_packet(
+        &self,
+        buffers: &[BufPtr],
+        app: Option<&[u32; 5]>,
+        in_ring: Option<Arc<AtomicBool>>,
+    ) -> AxiDMAResult {
+        let chunks = self.prepare_bounded(buffers)?;
+        self.staged.push(StagedPacket {
+            chunks,
+            app: app.copied(),
+            in_ring,
+        });
+        self.flush_staged()
+    }
+
+    /// Move the staged packets into the ring and start them. If the ring lock
+    /// is held, its holder flushes them after releasing the lock.
+    pub fn flush_staged(&self) -> AxiDMAResult {
+        self.staged.flush(
+            || self.ring.try_lock(),
+            |ring, packets| self.drain_staged(ring, packets),
+        )
+    }
+
+    /// Move the staged packets, after the older ones left in the backlog, into
+    /// the locked ring while they fit
+    fn drain_staged(&self, ring: &mut BDRing, packets: Vec<StagedPacket>) -> AxiDMAResult {
+        let mut backlog = self.backlog.lock();
+        let staged = packets.len();
+        backlog.extend(packets);
+        let mut moved = 0;
+        while let Some(packet) = backlog.front() {
+            if packet.chunks.len() > ring.free_cnt {
+                break;
+            }
+            self.ring_submit(ring, &packet.chunks, packet.app.as_ref(), None, None)?;
+            if let Some(in_ring) = packet.in_ring.as_ref() {
+                in_ring.store(true, Release);
+            }
+            backlog.pop_front();
+            moved += 1;
+        }
+        // The packets just staged which are left in the backlog found the ring
+        // full, the older ones have been counted already
+        for _ in 0..staged.min(backlog.len()) {
+            self.stats.submit_full();
+        }
+        if moved > 0 {
+            trace!(
+                "axidma::flush_staged: {} packets, {} left",
+                moved,
+                backlog.len()
+            );
+            // A stopped channel starts them when it is resumed
+            if !ring.is_stopped {
+                self.ring_to_hw(ring)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Take the ring lock, see [`RingGuard`]
+    fn lock_ring(&self) -> RingGuard<'_> {
+        RingGuard {
+            guard: ManuallyDrop::new(self.ring.lock()),
+            channel: self,
+        }
+    }
+
+    /// Split a buffer into the (address, length) of the BDs describing it.
+    /// In micro dma mode every BD must stay within one burst, so the buffer is
+    /// split at the burst boundaries. The buffer must be aligned to the data
+    /// width because there is no data realignment engine.
+    fn split_buf(&self, buffer: &BufPtr, chunks: &mut Vec<(usize, usize)>) -> AxiDMAResult {
+        let addr = buffer.as_ptr() as usize;
+        let len = buffer.len();
+        if !self.is_micro_dma {
+            if len > self.max_transfer_len {
+                error!("single buffer len has exceed max transfer len");
+                return Err(AxiDMAErr::InValidParam);
+            }
+            chunks.push((addr, len));
+            return Ok(());
+        }
+        let align = self.data_width / 8;
+        if len == 0 || self.burst_len == 0 {
+            error!(
+                "axidma::split_buf: invalid len {}, burst: {}",
+                len, self.burst_len
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        if addr & (align - 1) != 0 {
+            error!(
+                "axidma::split_buf: buf 0x{:x} is not aligned with the data width {} bytes",
+                addr, align
+            );
+            return Err(AxiDMAErr::MicroDmaViolation);
+        }
+        let burst_len = self.burst_len;
+        let mut offset = 0;
+        while offset < len {
+            let chunk_len = core::cmp::min(burst_len - (addr + offset) % burst_len, len - offset);
+            chunks.push((addr + offset, chunk_len));
+            offset += chunk_len;
+        }
         trace!(
-            "bd_ring::submit: done, restart: {}, tail: {}, free: {}, pending: {}",
-            ring.bd_restart,
-            ring.bd_tail,
-            ring.free_cnt,
-            ring.pending_cnt
+            "axidma::split_buf: addr: 0x{:x}, len: {}, burst: {}, bds: {}",
+            addr,
+            len,
+            burst_len,
+            chunks.len()
         );
-        Ok(buffer)
+        Ok(())
     }
 
     /// Retrieve the BD from hardware
     pub fn from_hw(&self) -> Result<usize, AxiDMAErr> {
-        let mut ring = self.ring.lock();
-        let mut bd_cnt = 0;
-        let mut partial_cnt = 0;
-        let mut cur_bd = ring.bd_head;
-        trace!(
-        "bd_ring::from_hw: head: {}, tail: {}",
-        ring.bd_head,
-        ring.bd_tail
-        );
+        self.from_hw_with(|_, _| {})
+    }
+
+    /// Retrieve the BD from hardware, `f` is called with the actual length and
+    /// the user application words of every completed packet. The application
+    /// words are `None` without the status/control stream.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_hw_with<F>(&self, f: F) -> Result<usize, AxiDMAErr>
+    where
+        F: FnMut(usize, Option<[u32; 5]>),
+    {
+        self.from_hw_limit(usize::MAX, f)
+    }
+
+    /// Retrieve at most `max_pkts` completed packets as
+    /// [`AxiDMAChannel::from_hw_with`]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_hw_limit<F>(&self, max_pkts: usize, mut f: F) -> Result<usize, AxiDMAErr>
+    where
+        F: FnMut(usize, Option<[u32; 5]>),
+    {
+        let mut ring = self.lock_ring();
         compiler_fence(SeqCst);
         fence(SeqCst);
         io_fence();
-
-        loop {
-            let bd = &ring.bds[cur_bd];
-            let status = bd.desc.status().read();
-            // Check the status of buffer descriptor, if is not completed, this action must be stopped.
-            if status.cmplt().is_false() {
-                trace!("bd_ring::from_hw: Uncompleted BD found at {}", cur_bd);
-                // bd.dump();
-                break;
+        let mut completions = self.completions.lock();
+        let mut len = 0;
+        let mut status = CompletionStatus::Ok;
+        let cnt = ring.from_hw_limit(max_pkts, |bd| {
+            len += bd.transferred_len();
+            if status == CompletionStatus::Ok {
+                status = bd.completion_status();
             }
-            bd_cnt += 1;
-            let ctrl = bd.desc.control().read();
-            // check that this buffer descriptor is the end of the transaction
-            // It is found a eof of a transaction, but maybe there are more buffer descriptor under the hardware.
-            if ctrl.eof().is_true() || status.rxeof().is_true() {
-                trace!("bd_ring::from_hw: EOF found at {}", cur_bd);
-                partial_cnt = 0;
-            } else {
-                partial_cnt += 1;
+            if bd.is_eof() {
+                let app = bd.app();
+                completions.complete(len, status, app);
+                self.stats.packet(len);
+                f(len, app);
+                len = 0;
+                status = CompletionStatus::Ok;
             }
-            if cur_bd == ring.bd_tail {
-                break;
-            }
-            cur_bd += 1;
-            if cur_bd == ring.all_cnt {
-                cur_bd = 0;
+        });
+        drop(completions);
+        drop(ring);
+        self.flush_backlog(cnt)?;
+        if cnt > 0 {
+            self.wake_ready();
+        }
+        Ok(cnt)
+    }
+
+    /// Wake the producers waiting for free BDs
+    fn wake_ready(&self) {
+        #[cfg(feature = "async")]
+        {
+            let wakers = core::mem::take(&mut *self.ready_wakers.lock());
+            for waker in wakers {
+                waker.wake();
             }
         }
-        trace!(
-            "bd_ring::from_hw: bd_cnt: {}, partial: {}",
-            bd_cnt,
-            partial_cnt
-        );
-        bd_cnt -= partial_cnt;
-        if bd_cnt > 0 {
-            ring.bd_head = (ring.bd_head + bd_cnt) % ring.all_cnt;
-            ring.submit_cnt -= bd_cnt;
-            ring.free_cnt += bd_cnt;
-            trace!("bd_ring::from_hw: free_cnt: {}", ring.free_cnt);
+    }
+
+    /// Flush the staged packets waiting for the BDs just retrieved
+    fn flush_backlog(&self, retrieved: usize) -> AxiDMAResult {
+        if retrieved > 0 && !self.backlog.lock().is_empty() {
+            self.flush_staged()?;
         }
-        Ok(bd_cnt)
+        Ok(())
     }
 
     /// Start a transfer
     pub fn to_hw(&self) -> AxiDMAResult {
+        self.ring_to_hw(&mut self.lock_ring())
+    }
+
+    /// Start the pre-process group of the locked ring
+    fn ring_to_hw(&self, ring: &mut BDRing) -> AxiDMAResult {
         let hardware = self.hardware();
-        let mut ring = self.ring.lock();
-        if ring.is_halted {
-            let addr = ring.head_desc_addr();
+        if ring.all_cnt == 0 {
+            error!("axidma::to_hw: BD ring is not created");
+            return Err(AxiDMAErr::BDRingNoList);
+        }
+        let restart = ring.is_halted;
+        ring.is_stopped = false;
+        if restart {
+            // Continue from the BD the channel stopped at
+            let addr = match ring.resume_at.take() {
+                Some(index) => ring.bds[index].desc_addr(),
+                None => ring.head_desc_addr(),
+            };
             self.update_cur_bd(addr);
             trace!("axidma::to_hw: cur desc addr: 0x{:x}", addr);
         }
@@ -317,44 +1055,60 @@ impl AxiDMAChannel {
         fence(SeqCst);
         io_fence();
         hardware.dmacr().modify(|_, w| w.run_stop().run());
-        ring.is_halted = false;
-        if ring.pending_cnt > 0 {
-            ring.submit_cnt += ring.pending_cnt;
-            ring.pending_cnt = 0;
+        match ring.to_hw() {
             // update tail desc
-            self.update_tail_bd(ring.tail_desc_addr());
+            Some(addr) => self.update_tail_bd(addr),
+            // The hardware group left by the stop is started again
+            None if restart && ring.submit_cnt > 0 => self.update_tail_bd(ring.tail_desc_addr()),
+            None => {}
         }
         Ok(())
     }
 
     /// Check out whether the channel has free block descriptor
     pub fn has_free_bd(&self) -> bool {
-        self.ring.lock().free_cnt > 0
+        self.lock_ring().has_free_bd()
     }
 
     /// Wait the channel completing a transaction synchronously.
     pub fn wait(&self) {
-        let mut status = self.hardware().dmasr().read();
-        while status.idle().is_not_idle() && status.ioc_irq().is_no_intr()
-            && status.dly_irq().is_no_intr()
-            && status.err_irq().is_no_intr()
-        {
-            status = self.hardware().dmasr().read();
+        while !self.is_done() {}
+    }
+
+    /// Wait the channel completing a transaction at most `timeout`.
+    pub fn wait_timeout(&self, timeout: Duration) -> AxiDMAResult {
+        let mut deadline = Deadline::after(timeout);
+        while !self.is_done() {
+            if deadline.expired() {
+                error!("axidma::wait: timed out");
+                return Err(AxiDMAErr::Timeout(self.snapshot()));
+            }
+            core::hint::spin_loop();
         }
+        Ok(())
+    }
+
+    /// Whether the channel is idle or has raised an interrupt
+    pub(crate) fn is_done(&self) -> bool {
+        let status = self.hardware().dmasr().read();
+        status.idle().is_idle()
+            || status.ioc_irq().is_detected()
+            || status.dly_irq().is_detected()
+            || status.err_irq().is_detected()
     }
 
     /// Enable the cyclic mode of this channel
     pub fn cyclic_enable(&self) {
         self.hardware()
             .dmacr()
-            .write(|w| w.cyclic_enable().set_bit());
+            .modify(|_, w| w.cyclic_enable().set_bit());
     }
 
     /// Disable the cyclic mode of this channel
     pub fn cyclic_disable(&self) {
         self.hardware()
             .dmacr()
-            .write(|w| w.cyclic_enable().clear_bit());
+            .modify(|_, w| w.cyclic_enable().clear_bit());
     }
 
     /// Disable the interrupt of this channel.
@@ -391,19 +1145,49 @@ impl AxiDMAChannel {
             || status.ioc_irq().is_detected()
     }
 
-    /// The interrupt handler
+    /// The interrupt handler. The completed BDs are retrieved and the records
+    /// of their packets are pushed into the completion queue. In the
+    /// interrupt-then-poll mode, it only disables the interrupts and schedules
+    /// [`AxiDMAChannel::poll`].
     pub fn intr_handler(&self) -> AxiDMAResult {
         // If the channel disables the interrupt, it will do nothing.
         let sr = self.hardware().dmasr();
         let status = sr.read();
+        if status.ioc_irq().is_detected() {
+            self.stats.ioc_irq();
+        }
+        if status.dly_irq().is_detected() {
+            self.stats.dly_irq();
+        }
         if status.err_irq().is_detected() {
+            self.stats.error([
+                status.dma_int_err().is_detected(),
+                status.dma_slv_err().is_detected(),
+                status.dma_dec_err().is_detected(),
+                status.sg_int_err().is_detected(),
+                status.sg_slv_err().is_detected(),
+                status.sg_dec_err().is_detected(),
+            ]);
             // dump regs
             // reset
             trace!("axidma_intr: err intr detected");
             self.dump_regs();
             sr.modify(|_, w| w.err_irq().set_bit());
+            self.from_hw()?;
+            self.run_callbacks();
             return Err(AxiDMAErr::IntrErr);
         }
+        if let Some(state) = self.napi.lock().as_mut() {
+            if status.ioc_irq().is_detected() || status.dly_irq().is_detected() {
+                sr.modify(|_, w| w.ioc_irq().set_bit().dly_irq().set_bit());
+                if !state.scheduled {
+                    trace!("axidma_intr: schedule polling");
+                    state.scheduled = true;
+                    self.intr_disable();
+                }
+            }
+            return Ok(());
+        }
         if status.ioc_irq().is_detected() {
             trace!("axidma_intr: cplt intr detected");
             sr.modify(|_, w| w.ioc_irq().set_bit());
@@ -412,6 +1196,8 @@ impl AxiDMAChannel {
             trace!("axidma_intr: dly intr detected");
             sr.modify(|_, w| w.dly_irq().set_bit());
         }
+        self.from_hw()?;
+        self.run_callbacks();
         Ok(())
     }
 
@@ -437,8 +1223,8 @@ impl AxiDMAChannel {
 
     /// Get the registers of the channel
     #[inline]
-    fn hardware(&self) -> &axidma_pac::channel::RegisterBlock {
-        unsafe { &*(self.channel_baseaddr as *const _) }
+    fn hardware(&self) -> &channel::RegisterBlock {
+        &self.regs
     }
 
     /// Update the current buffer descriptor of the channel
@@ -469,3 +1255,276 @@ impl AxiDMAChannel {
         }
     }
 }
+
+impl AxiDMAChannel {
+    /// Check the direction of a dmaengine-style descriptor
+    fn check_direction(&self, direction: DmaTransferDirection) -> AxiDMAResult {
+        let expected = match self.direction {
+            Direaction::TX => DmaTransferDirection::MemToDev,
+            Direaction::RX => DmaTransferDirection::DevToMem,
+        };
+        if direction != expected {
+            error!(
+                "axidma::prep: invalid direction {:?} for {:?} channel",
+                direction, self.direction
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        Ok(())
+    }
+}
+
+impl DmaChannel for AxiDMAChannel {
+    type Descriptor = AxiDmaDescriptor;
+
+    fn prep_slave_sg(
+        &self,
+        buffers: &[BufPtr],
+        direction: DmaTransferDirection,
+    ) -> Result<AxiDmaDescriptor, AxiDMAErr> {
+        self.check_direction(direction)?;
+        if buffers.is_empty() {
+            error!("axidma::prep_slave_sg: no buffer");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        Ok(AxiDmaDescriptor {
+            buffers: buffers.to_vec(),
+            cyclic: false,
+        })
+    }
+
+    fn prep_cyclic(
+        &self,
+        buffer: &BufPtr,
+        period_len: usize,
+        direction: DmaTransferDirection,
+    ) -> Result<AxiDmaDescriptor, AxiDMAErr> {
+        self.check_direction(direction)?;
+        if period_len == 0 || !buffer.len().is_multiple_of(period_len) {
+            error!(
+                "axidma::prep_cyclic: buf len {} is not a multiple of period {}",
+                buffer.len(),
+                period_len
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        // The hardware walks through the whole ring in cyclic mode, so every
+        // BD of the ring must describe a period.
+        let periods = buffer.len() / period_len;
+        let bd_count = self.lock_ring().all_cnt;
+        if periods != bd_count {
+            error!(
+                "axidma::prep_cyclic: {} periods, but the ring has {} BDs",
+                periods, bd_count
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let buffers = (0..periods)
+            .map(|i| {
+                let addr = buffer.as_ptr() as usize + i * period_len;
+                BufPtr::new(
+                    unsafe { NonNull::new_unchecked(addr as *mut u8) },
+                    period_len,
+                )
+            })
+            .collect();
+        Ok(AxiDmaDescriptor {
+            buffers,
+            cyclic: true,
+        })
+    }
+
+    fn submit(&self, desc: AxiDmaDescriptor) -> Result<DmaCookie, AxiDMAErr> {
+        let mut engine = self.engine.lock();
+        if engine.cyclic.is_some() {
+            error!("axidma::submit: the ring is occupied by a cyclic descriptor");
+            return Err(AxiDMAErr::DMAErr);
+        }
+        // The cookie of a descriptor is the one of its last packet, the packets
+        // are completed in order.
+        let cookie = if desc.cyclic {
+            let mut ring = self.lock_ring();
+            if ring.free_cnt != ring.all_cnt {
+                error!("axidma::submit: cyclic descriptor needs an idle ring");
+                return Err(AxiDMAErr::BDRingNoList);
+            }
+            // Every period takes a single BD, they are all checked before
+            // the ring is touched
+            let mut periods = Vec::with_capacity(desc.buffers.len());
+            for buffer in desc.buffers.iter() {
+                let chunks = self.prepare_packet(core::slice::from_ref(buffer))?;
+                if chunks.len() != 1 {
+                    error!("axidma::submit: cyclic period of {} BDs", chunks.len());
+                    return Err(AxiDMAErr::InValidParam);
+                }
+                periods.push(chunks);
+            }
+            let mut cookie = 0;
+            for (i, chunks) in periods.iter().enumerate() {
+                match self.ring_submit(&mut ring, chunks, None, None, None) {
+                    Ok(last) => cookie = last,
+                    Err(err) => {
+                        // Give the periods queued so far back
+                        ring.unalloc(i)?;
+                        self.completions.lock().unpush(i);
+                        return Err(err);
+                    }
+                }
+            }
+            engine.cyclic = Some(cookie);
+            cookie
+        } else {
+            self.submit_packet(&desc.buffers, None)?
+        };
+        trace!(
+            "axidma::submit: cookie {}, buffers: {}",
+            cookie,
+            desc.buffers.len()
+        );
+        Ok(cookie)
+    }
+
+    fn issue_pending(&self) -> AxiDMAResult {
+        if self.engine.lock().cyclic.is_some() {
+            self.cyclic_enable();
+        }
+        self.to_hw()
+    }
+
+    fn tx_status(&self, cookie: DmaCookie) -> DmaStatus {
+        // The engine lock is released before retrieving the BDs, which takes
+        // the locks of the interrupt handler
+        let cyclic = self.engine.lock().cyclic;
+        // A channel without interrupt only progresses by polling the status.
+        // The BDs of a cyclic descriptor are never retrieved.
+        if cyclic.is_none() && self.from_hw().is_err() {
+            return DmaStatus::Error;
+        }
+        let status = match cyclic {
+            Some(cyclic) if cyclic == cookie => DmaStatus::InProgress,
+            _ => self.completions.lock().status(cookie),
+        };
+        if status != DmaStatus::InProgress {
+            return status;
+        }
+        let status = self.hardware().dmasr().read();
+        if status.err_irq().is_detected() {
+            DmaStatus::Error
+        } else if status.halted().is_halted() {
+            DmaStatus::Paused
+        } else {
+            DmaStatus::InProgress
+        }
+    }
+
+    fn terminate_all(&self) -> AxiDMAResult {
+        let mut engine = self.engine.lock();
+        self.cyclic_disable();
+        self.stop()?;
+        let mut ring = self.lock_ring();
+        ring.discard();
+        self.completions.lock().abort_all();
+        drop(ring);
+        engine.cyclic = None;
+        // The callbacks may submit new descriptors
+        drop(engine);
+        self.wake_ready();
+        self.run_callbacks();
+        Ok(())
+    }
+}
+
+impl TransferChannel for AxiDMAChannel {
+    fn wait(&self) {
+        AxiDMAChannel::wait(self)
+    }
+
+    fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        AxiDMAChannel::from_hw(self)
+    }
+
+    fn intr_handler(&self) -> AxiDMAResult {
+        AxiDMAChannel::intr_handler(self)
+    }
+
+    fn finish(&self) -> AxiDMAResult {
+        // The interrupt handler already retrieves the completed BDs
+        AxiDMAChannel::intr_handler(self)
+    }
+}
+
+// The staging queue of a channel uses the loom atomics, which only work
+// within a loom model
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+    use crate::AxiDmaConfigBuilder;
+
+    /// A tx channel whose registers are never accessed
+    fn channel(builder: AxiDmaConfigBuilder) -> AxiDMAChannel {
+        AxiDMAChannel::new(Direaction::TX, &builder.build().unwrap())
+    }
+
+    /// A buffer at `addr` which is never accessed
+    fn buffer(addr: usize, len: usize) -> BufPtr {
+        BufPtr::new(NonNull::new(addr as *mut u8).unwrap(), len)
+    }
+
+    fn split(
+        channel: &AxiDMAChannel,
+        addr: usize,
+        len: usize,
+    ) -> Result<Vec<(usize, usize)>, AxiDMAErr> {
+        let mut chunks = Vec::new();
+        channel.split_buf(&buffer(addr, len), &mut chunks)?;
+        Ok(chunks)
+    }
+
+    #[test]
+    fn micro_dma_splits_at_burst_boundaries() {
+        // 16 beats of 4 bytes
+        let micro = channel(
+            AxiDmaConfigBuilder::new(0)
+                .micro_dma(true)
+                .mm2s(32, 16, false),
+        );
+        assert_eq!(
+            split(&micro, 0x1030, 100).unwrap(),
+            [(0x1030, 16), (0x1040, 64), (0x1080, 20)]
+        );
+        assert_eq!(split(&micro, 0x1040, 64).unwrap(), [(0x1040, 64)]);
+        assert!(matches!(
+            split(&micro, 0x1002, 8),
+            Err(AxiDMAErr::MicroDmaViolation)
+        ));
+        assert!(split(&micro, 0x1000, 0).is_err());
+    }
+
+    #[test]
+    fn unaligned_buffers_need_the_dre() {
+        let packet = |channel: &AxiDMAChannel, bufs: &[(usize, usize)]| {
+            let bufs: Vec<_> = bufs.iter().map(|&(addr, len)| buffer(addr, len)).collect();
+            channel.prepare_packet(&bufs)
+        };
+        // 4 byte words without the DRE
+        let plain = channel(AxiDmaConfigBuilder::new(0).mm2s(32, 16, false));
+        assert!(packet(&plain, &[(0x1000, 8), (0x1008, 3)]).is_ok());
+        assert!(packet(&plain, &[(0x1001, 8)]).is_err());
+        assert!(packet(&plain, &[(0x1000, 8), (0x1009, 8)]).is_err());
+        // Only the last buffer may end in the middle of a word
+        assert!(packet(&plain, &[(0x1000, 3), (0x1004, 8)]).is_err());
+
+        let dre = channel(AxiDmaConfigBuilder::new(0).mm2s(32, 16, true));
+        assert_eq!(
+            packet(&dre, &[(0x1001, 3), (0x1005, 8)]).unwrap(),
+            [(0x1001, 3), (0x1005, 8)]
+        );
+    }
+
+    #[test]
+    fn buffer_is_not_split_without_micro_dma() {
+        let channel = channel(AxiDmaConfigBuilder::new(0).sg_length_width(14));
+        assert_eq!(split(&channel, 0x1030, 0x3fff).unwrap(), [(0x1030, 0x3fff)]);
+        assert!(split(&channel, 0x1030, 0x4000).is_err());
+    }
+}
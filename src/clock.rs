@@ -0,0 +1,105 @@
+//! The monotonic clock of the timeouts.
+//!
+//! The platform installs its clock once with [`set_clock`], e.g. a wrapper of
+//! the `time` CSR on RISC-V or of the generic timer on ARM. The timeouts of
+//! `wait_timeout`, `stop`, `reset` and the futures are then measured in real
+//! time. Without a clock, a timeout is turned into a number of polls of the
+//! registers, [`FALLBACK_POLLS_PER_US`] per microsecond. The time of a poll
+//! depends on the SoC and the bus, so this fallback is only approximate: the
+//! timeout may expire earlier or later than requested. Install a clock
+//! wherever the timeouts matter.
+//!
+
+use core::time::Duration;
+use spin::Once;
+
+/// The number of polls per microsecond of a timeout without any clock
+/// installed, which assumes that a poll takes about one microsecond
+pub const FALLBACK_POLLS_PER_US: usize = 1;
+
+/// A monotonic clock, it must never go backwards
+pub trait MonotonicClock: Send + Sync {
+    /// The time elapsed since an arbitrary fixed point
+    fn now(&self) -> Duration;
+}
+
+static CLOCK: Once<&'static dyn MonotonicClock> = Once::new();
+
+/// Install the clock of the timeouts, only the first call takes effect
+pub fn set_clock(clock: &'static dyn MonotonicClock) {
+    CLOCK.call_once(|| clock);
+}
+
+/// The installed clock
+pub fn clock() -> Option<&'static dyn MonotonicClock> {
+    CLOCK.get().copied()
+}
+
+/// The end of a timeout
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Deadline {
+    /// The time of the installed clock at which it expires
+    At(Duration),
+    /// The number of polls left without any clock
+    Polls(usize),
+}
+
+impl Deadline {
+    /// The deadline `timeout` from now
+    pub fn after(timeout: Duration) -> Self {
+        match clock() {
+            Some(clock) => Self::At(clock.now().saturating_add(timeout)),
+            None => {
+                let us = usize::try_from(timeout.as_micros()).unwrap_or(usize::MAX);
+                Self::Polls(us.saturating_mul(FALLBACK_POLLS_PER_US).max(1))
+            }
+        }
+    }
+
+    /// Check the deadline, every call counts as one poll without any clock
+    pub fn expired(&mut self) -> bool {
+        match self {
+            Self::At(end) => clock().is_some_and(|clock| clock.now() >= *end),
+            Self::Polls(0) => true,
+            Self::Polls(left) => {
+                *left -= 1;
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // No clock is installed by the tests, so every deadline counts polls
+
+    #[test]
+    fn fallback_scales_with_the_timeout() {
+        assert!(matches!(
+            Deadline::after(Duration::from_millis(10)),
+            Deadline::Polls(10_000)
+        ));
+        assert!(matches!(
+            Deadline::after(Duration::MAX),
+            Deadline::Polls(usize::MAX)
+        ));
+    }
+
+    #[test]
+    fn fallback_expires_after_its_polls() {
+        let mut deadline = Deadline::after(Duration::from_micros(3));
+        for _ in 0..3 {
+            assert!(!deadline.expired());
+        }
+        assert!(deadline.expired());
+    }
+
+    #[test]
+    fn zero_timeout_polls_once() {
+        let mut deadline = Deadline::after(Duration::ZERO);
+        assert!(!deadline.expired());
+        assert!(deadline.expired());
+    }
+}
@@ -0,0 +1,281 @@
+//! The completion queue of an AxiDMA channel.
+//!
+//! Every packet entering the BD ring gets a cookie. When its BDs are retrieved,
+//! either by `AxiDMAChannel::intr_handler` in interrupt context or by
+//! `AxiDMAChannel::from_hw` in thread context, a [`Completion`] is recorded
+//! for the packets submitted by `AxiDMAChannel::submit_queued`. The thread
+//! context drains the records with `AxiDMAChannel::pop_completion` or
+//! `AxiDMAChannel::drain_completions`, which is the only place where the
+//! buffers of these packets are given back.
+//!
+//! A packet submitted by `AxiDMAChannel::submit_with_callback` carries a
+//! [`CompletionCallback`] instead. The BDs may be retrieved in any context,
+//! the callback is invoked by the next of the following calls, in the context
+//! of its caller:
+//!
+//! - `AxiDMAChannel::intr_handler`, in interrupt context. It is also called in
+//!   thread context by `Transfer::wait`, the future of a `Transfer` and
+//!   `TransferTimeout`.
+//! - `AxiDMAChannel::poll` in the interrupt-then-poll mode, in the context of
+//!   the poller.
+//! - `create`, `terminate_all`, `quiesce_abort` and `AxiDma::recover`, which
+//!   invoke the callbacks of the dropped packets with
+//!   [`CompletionStatus::Aborted`] in the context of their caller.
+//!
+//! So a callback must be safe to run both in interrupt and in thread context:
+//! it must not block, sleep or take a lock which the interrupted code may
+//! hold. The callbacks are invoked after all the locks of the channel are
+//! released, so a callback may submit new packets to the channel, but it must
+//! not wait for them, e.g. with `Transfer::wait` or `submit_blocking`.
+//!
+
+use crate::{BufPtr, DmaCookie, DmaStatus};
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+
+/// The callback invoked with the record of a completed packet
+pub type CompletionCallback = Box<dyn FnOnce(Completion) + Send>;
+
+/// The status of a completed packet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionStatus {
+    /// The packet is transferred
+    Ok,
+    /// The DataMover reported an internal error, e.g. a BD of 0 byte
+    InternalErr,
+    /// The memory map slave responded with an error
+    SlaveErr,
+    /// The buffer address can not be decoded
+    DecodeErr,
+    /// The packet was dropped from the ring before being completed
+    Aborted,
+}
+
+/// The record of a completed packet
+#[derive(Debug)]
+pub struct Completion {
+    /// The cookie returned by the submission
+    pub cookie: DmaCookie,
+    /// The number of bytes transferred by the hardware
+    pub len: usize,
+    /// The status of the packet
+    pub status: CompletionStatus,
+    /// The user application words of the status stream
+    pub app: Option<[u32; 5]>,
+    /// The submitted buffer
+    pub buffer: BufPtr,
+}
+
+/// A packet in the BD ring
+struct InFlight {
+    cookie: DmaCookie,
+    /// The buffer of a packet submitted by `submit_queued`, the packets of the
+    /// other submissions are owned by their [`crate::Transfer`] and not recorded.
+    buffer: Option<BufPtr>,
+    /// The callback invoked instead of queuing the record
+    callback: Option<CompletionCallback>,
+}
+
+/// The packets in the ring and the completed records. The packets are kept in
+/// the order of the ring, so it is only updated with the ring lock held.
+pub(crate) struct CompletionState {
+    last_cookie: DmaCookie,
+    /// The range of the cookies dropped by the last abort
+    aborted: Option<(DmaCookie, DmaCookie)>,
+    in_flight: VecDeque<InFlight>,
+    done: VecDeque<Completion>,
+    /// The records waiting for their callbacks to be invoked
+    callbacks: VecDeque<(CompletionCallback, Completion)>,
+}
+
+impl CompletionState {
+    pub const fn new() -> Self {
+        Self {
+            last_cookie: 0,
+            aborted: None,
+            in_flight: VecDeque::new(),
+            done: VecDeque::new(),
+            callbacks: VecDeque::new(),
+        }
+    }
+
+    /// Record a packet entering the ring and return its cookie
+    pub fn push(
+        &mut self,
+        buffer: Option<BufPtr>,
+        callback: Option<CompletionCallback>,
+    ) -> DmaCookie {
+        self.last_cookie = self.last_cookie.wrapping_add(1).max(1);
+        self.in_flight.push_back(InFlight {
+            cookie: self.last_cookie,
+            buffer,
+            callback,
+        });
+        self.last_cookie
+    }
+
+    /// Drop the records of the last `count` packets, which have been given
+    /// back to the free BDs before entering the hardware
+    pub fn unpush(&mut self, count: usize) {
+        for _ in 0..count {
+            if let Some(packet) = self.in_flight.pop_back() {
+                self.last_cookie = packet.cookie.wrapping_sub(1);
+            }
+        }
+    }
+
+    /// Record the completion of the oldest packet of the ring
+    pub fn complete(&mut self, len: usize, status: CompletionStatus, app: Option<[u32; 5]>) {
+        let Some(packet) = self.in_flight.pop_front() else {
+            warn!("axidma::completion: completed packet is not recorded");
+            return;
+        };
+        self.record(packet, len, status, app);
+    }
+
+    /// Queue the record of a packet, or its callback
+    fn record(
+        &mut self,
+        packet: InFlight,
+        len: usize,
+        status: CompletionStatus,
+        app: Option<[u32; 5]>,
+    ) {
+        let Some(buffer) = packet.buffer else {
+            return;
+        };
+        let completion = Completion {
+            cookie: packet.cookie,
+            len,
+            status,
+            app,
+            buffer,
+        };
+        match packet.callback {
+            Some(callback) => self.callbacks.push_back((callback, completion)),
+            None => self.done.push_back(completion),
+        }
+    }
+
+    /// Record all the packets of the ring as aborted
+    pub fn abort_all(&mut self) {
+        if let (Some(first), Some(last)) = (self.in_flight.front(), self.in_flight.back()) {
+            self.aborted = Some((first.cookie, last.cookie));
+        }
+        while let Some(packet) = self.in_flight.pop_front() {
+            self.record(packet, 0, CompletionStatus::Aborted, None);
+        }
+    }
+
+    /// The status of a packet by its cookie. A packet still in the ring is
+    /// [`DmaStatus::InProgress`], the packets leave the ring in order.
+    pub fn status(&self, cookie: DmaCookie) -> DmaStatus {
+        if cookie == 0 || cookie > self.last_cookie {
+            return DmaStatus::Error;
+        }
+        if self.in_flight.iter().any(|packet| packet.cookie == cookie) {
+            return DmaStatus::InProgress;
+        }
+        match self.aborted {
+            Some((first, last)) if (first..=last).contains(&cookie) => DmaStatus::Error,
+            _ => DmaStatus::Complete,
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<Completion> {
+        self.done.pop_front()
+    }
+
+    pub fn take(&mut self) -> VecDeque<Completion> {
+        core::mem::take(&mut self.done)
+    }
+
+    pub fn take_callbacks(&mut self) -> VecDeque<(CompletionCallback, Completion)> {
+        core::mem::take(&mut self.callbacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr::NonNull;
+
+    fn buffer() -> BufPtr {
+        BufPtr::new(NonNull::dangling(), 0)
+    }
+
+    #[test]
+    fn packets_complete_in_order() {
+        let mut state = CompletionState::new();
+        let first = state.push(Some(buffer()), None);
+        let second = state.push(Some(buffer()), None);
+        assert!(second > first);
+        assert_eq!(state.status(first), DmaStatus::InProgress);
+
+        state.complete(8, CompletionStatus::Ok, None);
+        assert_eq!(state.status(first), DmaStatus::Complete);
+        assert_eq!(state.status(second), DmaStatus::InProgress);
+        let record = state.pop().unwrap();
+        assert_eq!((record.cookie, record.len), (first, 8));
+        assert!(state.pop().is_none());
+    }
+
+    #[test]
+    fn packets_owned_by_a_transfer_are_not_recorded() {
+        let mut state = CompletionState::new();
+        let cookie = state.push(None, None);
+        state.complete(8, CompletionStatus::Ok, None);
+        assert_eq!(state.status(cookie), DmaStatus::Complete);
+        assert!(state.take().is_empty());
+    }
+
+    #[test]
+    fn callbacks_are_queued_instead_of_records() {
+        let mut state = CompletionState::new();
+        let cookie = state.push(Some(buffer()), Some(Box::new(|_| {})));
+        state.complete(0, CompletionStatus::SlaveErr, None);
+        assert!(state.pop().is_none());
+        let callbacks = state.take_callbacks();
+        assert_eq!(callbacks.len(), 1);
+        assert_eq!(callbacks[0].1.cookie, cookie);
+        assert_eq!(callbacks[0].1.status, CompletionStatus::SlaveErr);
+    }
+
+    #[test]
+    fn unpushed_packets_are_forgotten() {
+        let mut state = CompletionState::new();
+        let kept = state.push(None, None);
+        let dropped = state.push(None, None);
+        state.push(None, None);
+        state.unpush(2);
+        assert_eq!(state.status(kept), DmaStatus::InProgress);
+        assert_eq!(state.status(dropped), DmaStatus::Error);
+        assert_eq!(state.push(None, None), dropped);
+    }
+
+    #[test]
+    fn aborted_packets_are_errors() {
+        let mut state = CompletionState::new();
+        let done = state.push(None, None);
+        state.complete(0, CompletionStatus::Ok, None);
+        let first = state.push(Some(buffer()), None);
+        let last = state.push(None, None);
+        state.abort_all();
+        assert_eq!(state.status(done), DmaStatus::Complete);
+        assert_eq!(state.status(first), DmaStatus::Error);
+        assert_eq!(state.status(last), DmaStatus::Error);
+        assert_eq!(state.pop().unwrap().status, CompletionStatus::Aborted);
+        // The later packets are not affected by the old abort
+        let next = state.push(None, None);
+        state.complete(0, CompletionStatus::Ok, None);
+        assert_eq!(state.status(next), DmaStatus::Complete);
+    }
+
+    #[test]
+    fn unknown_cookies_are_errors() {
+        let mut state = CompletionState::new();
+        assert_eq!(state.status(0), DmaStatus::Error);
+        let cookie = state.push(None, None);
+        assert_eq!(state.status(cookie + 1), DmaStatus::Error);
+    }
+}
@@ -0,0 +1,245 @@
+//! The builder of [`AxiDmaConfig`], which validates the configuration against
+//! the limits of the hardware. See [PG021](https://docs.xilinx.com/r/en-US/pg021_axi_dma)
+//! for the allowed values of the parameters.
+//!
+
+use crate::limits::{ChannelParams, EngineParams, LimitErr, CHANNEL_REG_SIZE};
+use crate::{AxiDMAErr, AxiDMAResult, AxiDmaConfig};
+use core::ops::Deref;
+
+impl AxiDmaConfig {
+    /// Check the configuration against the limits of the hardware. The
+    /// driver only supports the Scatter Gather mode, a configuration without
+    /// the SG engine fails with [`AxiDMAErr::SgNotIncluded`].
+    pub fn validate(&self) -> AxiDMAResult {
+        self.params().check().map_err(|err| {
+            error!("axidma::config: {}", err);
+            match err {
+                LimitErr::NoSg => AxiDMAErr::SgNotIncluded,
+                _ => AxiDMAErr::InValidParam,
+            }
+        })
+    }
+
+    /// The parameters checked by `validate`
+    fn params(&self) -> EngineParams {
+        EngineParams {
+            has_sg: self.has_sg,
+            is_micro_dma: self.is_micro_dma,
+            sg_length_width: self.sg_length_width,
+            addr_width: self.addr_width,
+            tx: self.has_mm2s.then_some(ChannelParams {
+                offset: self.tx_channel_offset,
+                data_width: self.mm2s_data_width,
+                burst_size: self.mm2s_burst_size,
+                has_dre: self.has_mm2s_dre,
+            }),
+            rx: self.has_s2mm.then_some(ChannelParams {
+                offset: self.rx_channel_offset,
+                data_width: self.s2mm_data_width,
+                burst_size: self.s2mm_burst_size,
+                has_dre: self.has_s2mm_dre,
+            }),
+        }
+    }
+}
+
+/// A configuration which has been validated, it cannot be modified anymore.
+/// [`crate::AxiDma::new`] only takes a validated configuration.
+#[derive(Debug)]
+pub struct ValidAxiDmaConfig(AxiDmaConfig);
+
+impl ValidAxiDmaConfig {
+    /// Wrap a configuration which has been validated when the crate was built:
+    /// the built-in default and the instances of `xparameters.h`.
+    pub(crate) const fn new_unchecked(cfg: AxiDmaConfig) -> Self {
+        Self(cfg)
+    }
+}
+
+impl Deref for ValidAxiDmaConfig {
+    type Target = AxiDmaConfig;
+
+    fn deref(&self) -> &AxiDmaConfig {
+        &self.0
+    }
+}
+
+impl TryFrom<AxiDmaConfig> for ValidAxiDmaConfig {
+    type Error = AxiDMAErr;
+
+    fn try_from(cfg: AxiDmaConfig) -> Result<Self, AxiDMAErr> {
+        cfg.validate()?;
+        Ok(Self(cfg))
+    }
+}
+
+/// The builder of [`AxiDmaConfig`]. The default values follow the default
+/// parameters of the AXI DMA IP.
+pub struct AxiDmaConfigBuilder {
+    cfg: AxiDmaConfig,
+}
+
+impl AxiDmaConfigBuilder {
+    /// Create a builder of the AxiDMA at `base_address`
+    pub fn new(base_address: usize) -> Self {
+        Self {
+            cfg: AxiDmaConfig {
+                base_address,
+                rx_channel_offset: CHANNEL_REG_SIZE,
+                tx_channel_offset: 0,
+                has_sts_cntrl_strm: false,
+                is_micro_dma: false,
+                has_mm2s: true,
+                has_mm2s_dre: false,
+                mm2s_data_width: 32,
+                mm2s_burst_size: 16,
+                has_s2mm: true,
+                has_s2mm_dre: false,
+                s2mm_data_width: 32,
+                s2mm_burst_size: 16,
+                has_sg: true,
+                sg_length_width: 14,
+                addr_width: 32,
+            },
+        }
+    }
+
+    /// Set the address offsets of the tx and the rx channel
+    pub fn channel_offsets(mut self, tx_offset: usize, rx_offset: usize) -> Self {
+        self.cfg.tx_channel_offset = tx_offset;
+        self.cfg.rx_channel_offset = rx_offset;
+        self
+    }
+
+    /// Enable the tx channel with its data width, burst size and data realignment
+    pub fn mm2s(mut self, data_width: usize, burst_size: usize, has_dre: bool) -> Self {
+        self.cfg.has_mm2s = true;
+        self.cfg.mm2s_data_width = data_width;
+        self.cfg.mm2s_burst_size = burst_size;
+        self.cfg.has_mm2s_dre = has_dre;
+        self
+    }
+
+    /// Disable the tx channel
+    pub fn no_mm2s(mut self) -> Self {
+        self.cfg.has_mm2s = false;
+        self.cfg.has_mm2s_dre = false;
+        self
+    }
+
+    /// Enable the rx channel with its data width, burst size and data realignment
+    pub fn s2mm(mut self, data_width: usize, burst_size: usize, has_dre: bool) -> Self {
+        self.cfg.has_s2mm = true;
+        self.cfg.s2mm_data_width = data_width;
+        self.cfg.s2mm_burst_size = burst_size;
+        self.cfg.has_s2mm_dre = has_dre;
+        self
+    }
+
+    /// Disable the rx channel
+    pub fn no_s2mm(mut self) -> Self {
+        self.cfg.has_s2mm = false;
+        self.cfg.has_s2mm_dre = false;
+        self
+    }
+
+    /// Set whether the status/control stream is present
+    pub fn sts_cntrl_strm(mut self, enable: bool) -> Self {
+        self.cfg.has_sts_cntrl_strm = enable;
+        self
+    }
+
+    /// Set whether the micro dma mode is enabled
+    pub fn micro_dma(mut self, enable: bool) -> Self {
+        self.cfg.is_micro_dma = enable;
+        self
+    }
+
+    /// Set whether the scatter gather engine is present, `build` rejects a
+    /// configuration without it
+    pub fn sg(mut self, enable: bool) -> Self {
+        self.cfg.has_sg = enable;
+        self
+    }
+
+    /// Set the width of the buffer length field
+    pub fn sg_length_width(mut self, width: usize) -> Self {
+        self.cfg.sg_length_width = width;
+        self
+    }
+
+    /// Set the width of address
+    pub fn addr_width(mut self, width: isize) -> Self {
+        self.cfg.addr_width = width;
+        self
+    }
+
+    /// Validate and build the configuration
+    pub fn build(self) -> Result<ValidAxiDmaConfig, AxiDMAErr> {
+        self.cfg.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(AxiDmaConfigBuilder::new(0x4040_0000).build().is_ok());
+        assert!(crate::hw::AXI_DMA_CONFIG.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_simple_mode() {
+        let cfg = AxiDmaConfigBuilder::new(0x4040_0000).sg(false).build();
+        assert!(matches!(cfg, Err(AxiDMAErr::SgNotIncluded)));
+    }
+
+    #[test]
+    fn rejects_no_channel() {
+        let builder = AxiDmaConfigBuilder::new(0).no_mm2s().no_s2mm();
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_widths() {
+        let builder = || AxiDmaConfigBuilder::new(0);
+        assert!(builder().sg_length_width(27).build().is_err());
+        assert!(builder().addr_width(48).build().is_err());
+        assert!(builder().mm2s(24, 16, false).build().is_err());
+        assert!(builder().s2mm(2048, 16, false).build().is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_bursts() {
+        let builder = || AxiDmaConfigBuilder::new(0);
+        let micro = || builder().micro_dma(true);
+        assert!(builder().mm2s(32, 12, false).build().is_err());
+        assert!(builder().mm2s(32, 512, false).build().is_err());
+        assert!(micro().mm2s(32, 128, false).build().is_err());
+        assert!(micro().mm2s(32, 64, false).build().is_ok());
+    }
+
+    #[test]
+    fn rejects_unavailable_dre() {
+        let builder = || AxiDmaConfigBuilder::new(0);
+        let micro = || builder().micro_dma(true);
+        assert!(micro().s2mm(32, 16, true).build().is_err());
+        assert!(builder().s2mm(1024, 16, true).build().is_err());
+        assert!(builder().s2mm(512, 16, true).build().is_ok());
+    }
+
+    #[test]
+    fn rejects_bad_channel_offsets() {
+        let builder = || AxiDmaConfigBuilder::new(0);
+        assert!(builder().channel_offsets(0, 0x20).build().is_err());
+        assert!(builder().channel_offsets(0, 0x32).build().is_err());
+        assert!(builder()
+            .channel_offsets(0, crate::limits::REG_WINDOW)
+            .build()
+            .is_err());
+        assert!(builder().no_mm2s().channel_offsets(0, 0).build().is_ok());
+    }
+}
@@ -0,0 +1,76 @@
+//! A generic DMA client interface modeled on the Linux
+//! [dmaengine](https://docs.kernel.org/driver-api/dmaengine/client.html) API.
+//!
+//! A peripheral driver written against [`DmaChannel`] does not depend on the
+//! DMA IP behind it. The usage is the same as with dmaengine:
+//!
+//! 1. Prepare a descriptor with [`DmaChannel::prep_slave_sg`] or [`DmaChannel::prep_cyclic`].
+//! 2. Queue it with [`DmaChannel::submit`], which returns a cookie.
+//! 3. Start the queued descriptors with [`DmaChannel::issue_pending`].
+//! 4. Check the progress of a cookie with [`DmaChannel::tx_status`].
+//!
+//! The buffers must stay valid until their descriptor is completed or terminated.
+//!
+
+use crate::{AxiDMAErr, AxiDMAResult, BufPtr};
+
+/// The cookie identifying a submitted descriptor. Cookies are increasing in the
+/// order of submission.
+pub type DmaCookie = u32;
+
+/// The direction of a slave transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmaTransferDirection {
+    /// From the memory to the device
+    MemToDev,
+    /// From the device to the memory
+    DevToMem,
+}
+
+/// The status of a descriptor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmaStatus {
+    /// The descriptor is completed
+    Complete,
+    /// The descriptor is queued or being transferred
+    InProgress,
+    /// The descriptor is queued but the channel is halted
+    Paused,
+    /// The descriptor failed or was terminated, or the cookie is unknown
+    Error,
+}
+
+/// The operations of a DMA channel used by a peripheral driver.
+pub trait DmaChannel {
+    /// The prepared descriptor
+    type Descriptor;
+
+    /// Prepare a transfer of the buffers as a single packet.
+    fn prep_slave_sg(
+        &self,
+        buffers: &[BufPtr],
+        direction: DmaTransferDirection,
+    ) -> Result<Self::Descriptor, AxiDMAErr>;
+
+    /// Prepare a cyclic transfer on a buffer, which is divided into periods of
+    /// `period_len` bytes. A completion is reported after every period and the
+    /// transfer restarts from the first period until it is terminated.
+    fn prep_cyclic(
+        &self,
+        buffer: &BufPtr,
+        period_len: usize,
+        direction: DmaTransferDirection,
+    ) -> Result<Self::Descriptor, AxiDMAErr>;
+
+    /// Queue a prepared descriptor, it is started by [`DmaChannel::issue_pending`].
+    fn submit(&self, desc: Self::Descriptor) -> Result<DmaCookie, AxiDMAErr>;
+
+    /// Start all the queued descriptors.
+    fn issue_pending(&self) -> AxiDMAResult;
+
+    /// Get the status of a submitted descriptor.
+    fn tx_status(&self, cookie: DmaCookie) -> DmaStatus;
+
+    /// Stop the channel and drop all the queued and the running descriptors.
+    fn terminate_all(&self) -> AxiDMAResult;
+}
@@ -1,3 +1,5 @@
+use crate::channel::ChannelSnapshot;
+
 /// The Error number
 #[derive(Debug)]
 pub enum AxiDMAErr {
@@ -9,6 +11,12 @@ pub enum AxiDMAErr {
     BDRingNoList,
     /// The parameter is invalid
     InValidParam,
+    /// The AxiDMA has no Scatter Gather engine, which the driver requires
+    SgNotIncluded,
     /// Interrupt on Error
     IntrErr,
+    /// The buffer breaks the transfer rules of the micro DMA mode
+    MicroDmaViolation,
+    /// The channel did not reach the expected state in time
+    Timeout(ChannelSnapshot),
 }
@@ -0,0 +1,219 @@
+//! The formats of the user application (APP) words exchanged with the AXI
+//! Ethernet subsystem through the status/control stream.
+//! See [PG138](https://docs.xilinx.com/r/en-US/pg138-axi-ethernet) for the
+//! hardware spec.
+//!
+//! The APP words are only available when the AxiDMA has the status/control
+//! stream (`has_sts_cntrl_strm`). The TX APP words carry the checksum offload
+//! command and are set with `submit_app()` of the tx channel. The RX APP words
+//! carry the frame length, the checksum results and the multicast/broadcast
+//! flags and are got with `from_hw_with()` of the rx channel.
+
+/// The checksum offload command of a transmitted frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EthTxCsum {
+    /// No checksum offload
+    None,
+    /// Partial checksum offload. The 16-bit checksum is calculated from `begin`
+    /// to the end of the frame, starting from `init`, and inserted at `insert`.
+    Partial {
+        /// The offset of the first byte to sum
+        begin: u16,
+        /// The offset where the checksum is inserted
+        insert: u16,
+        /// The initial value of the checksum, e.g. the pseudo header sum
+        init: u16,
+    },
+    /// Full checksum offload of the IPv4 header and the TCP/UDP checksum
+    Full,
+}
+
+impl EthTxCsum {
+    /// TxCsCntrl value of the partial checksum offload
+    const CNTRL_PARTIAL: u32 = 0x1;
+    /// TxCsCntrl value of the full checksum offload
+    const CNTRL_FULL: u32 = 0x2;
+
+    /// Encode the TX APP words
+    pub fn encode(&self) -> [u32; 5] {
+        match *self {
+            EthTxCsum::None => [0; 5],
+            EthTxCsum::Partial {
+                begin,
+                insert,
+                init,
+            } => [
+                Self::CNTRL_PARTIAL,
+                (begin as u32) << 16 | insert as u32,
+                init as u32,
+                0,
+                0,
+            ],
+            EthTxCsum::Full => [Self::CNTRL_FULL, 0, 0, 0, 0],
+        }
+    }
+
+    /// Decode the TX APP words
+    pub fn decode(app: &[u32; 5]) -> Self {
+        match app[0] & 0x3 {
+            Self::CNTRL_PARTIAL => EthTxCsum::Partial {
+                begin: (app[1] >> 16) as u16,
+                insert: app[1] as u16,
+                init: app[2] as u16,
+            },
+            Self::CNTRL_FULL => EthTxCsum::Full,
+            _ => EthTxCsum::None,
+        }
+    }
+}
+
+/// The result of the full checksum offload of a received frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EthRxCsum {
+    /// Neither the IP header nor the TCP/UDP checksum is checked
+    NotChecked,
+    /// The IP header checksum is valid, the TCP/UDP checksum is not checked
+    IpValid,
+    /// The IP header and the TCP checksum are valid
+    TcpValid,
+    /// The IP header and the UDP checksum are valid
+    UdpValid,
+    /// Other results, e.g. a checksum error
+    Other(u8),
+}
+
+impl From<u8> for EthRxCsum {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => EthRxCsum::NotChecked,
+            1 => EthRxCsum::IpValid,
+            2 => EthRxCsum::TcpValid,
+            3 => EthRxCsum::UdpValid,
+            other => EthRxCsum::Other(other),
+        }
+    }
+}
+
+impl From<EthRxCsum> for u8 {
+    fn from(value: EthRxCsum) -> Self {
+        match value {
+            EthRxCsum::NotChecked => 0,
+            EthRxCsum::IpValid => 1,
+            EthRxCsum::TcpValid => 2,
+            EthRxCsum::UdpValid => 3,
+            EthRxCsum::Other(other) => other,
+        }
+    }
+}
+
+/// The status of a received frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EthRxStatus {
+    /// The destination address if it is a multicast frame
+    pub mcast_addr: [u8; 6],
+    /// Whether the frame is a multicast frame
+    pub is_multicast: bool,
+    /// Whether the frame is a broadcast frame
+    pub is_broadcast: bool,
+    /// The result of the full checksum offload
+    pub csum: EthRxCsum,
+    /// The Type/Length field of the frame
+    pub type_len: u16,
+    /// The raw checksum of the partial checksum offload
+    pub raw_csum: u16,
+    /// The VLAN tag of the frame
+    pub vlan_tag: u16,
+    /// The number of bytes of the frame
+    pub frame_len: usize,
+}
+
+impl EthRxStatus {
+    /// MCAST_FRAME bit of APP2
+    const MCAST_FRAME: u32 = 1 << 0;
+    /// BCAST_FRAME bit of APP2
+    const BCAST_FRAME: u32 = 1 << 1;
+    /// The shift of the full checksum status in APP2
+    const CSUM_SHIFT: u32 = 3;
+    /// The mask of the full checksum status in APP2
+    const CSUM_MASK: u32 = 0x7 << Self::CSUM_SHIFT;
+
+    /// Decode the RX APP words
+    pub fn decode(app: &[u32; 5]) -> Self {
+        let mut mcast_addr = [0; 6];
+        mcast_addr[..2].copy_from_slice(&(app[0] as u16).to_be_bytes());
+        mcast_addr[2..].copy_from_slice(&app[1].to_be_bytes());
+        Self {
+            mcast_addr,
+            is_multicast: app[2] & Self::MCAST_FRAME != 0,
+            is_broadcast: app[2] & Self::BCAST_FRAME != 0,
+            csum: (((app[2] & Self::CSUM_MASK) >> Self::CSUM_SHIFT) as u8).into(),
+            type_len: (app[2] >> 16) as u16,
+            raw_csum: app[3] as u16,
+            vlan_tag: (app[3] >> 16) as u16,
+            frame_len: (app[4] & 0xffff) as usize,
+        }
+    }
+
+    /// Encode the RX APP words
+    pub fn encode(&self) -> [u32; 5] {
+        let [a0, a1, a2, a3, a4, a5] = self.mcast_addr;
+        let mut app2 = (self.type_len as u32) << 16
+            | ((u8::from(self.csum) as u32) << Self::CSUM_SHIFT) & Self::CSUM_MASK;
+        if self.is_multicast {
+            app2 |= Self::MCAST_FRAME;
+        }
+        if self.is_broadcast {
+            app2 |= Self::BCAST_FRAME;
+        }
+        [
+            u16::from_be_bytes([a0, a1]) as u32,
+            u32::from_be_bytes([a2, a3, a4, a5]),
+            app2,
+            (self.vlan_tag as u32) << 16 | self.raw_csum as u32,
+            (self.frame_len & 0xffff) as u32,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_csum_round_trips() {
+        let partial = EthTxCsum::Partial {
+            begin: 34,
+            insert: 50,
+            init: 0xbeef,
+        };
+        assert_eq!(partial.encode(), [0x1, 34 << 16 | 50, 0xbeef, 0, 0]);
+        for csum in [EthTxCsum::None, EthTxCsum::Full, partial] {
+            assert_eq!(EthTxCsum::decode(&csum.encode()), csum);
+        }
+    }
+
+    #[test]
+    fn rx_csum_keeps_unknown_results() {
+        for value in 0..8u8 {
+            assert_eq!(u8::from(EthRxCsum::from(value)), value);
+        }
+        assert_eq!(EthRxCsum::from(5), EthRxCsum::Other(5));
+    }
+
+    #[test]
+    fn rx_status_round_trips() {
+        let status = EthRxStatus {
+            mcast_addr: [0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb],
+            is_multicast: true,
+            is_broadcast: false,
+            csum: EthRxCsum::UdpValid,
+            type_len: 0x0800,
+            raw_csum: 0x1234,
+            vlan_tag: 0x0064,
+            frame_len: 1514,
+        };
+        let app = status.encode();
+        assert_eq!(app, [0x0100, 0x5e00_00fb, 0x0800_0019, 0x0064_1234, 1514]);
+        assert_eq!(EthRxStatus::decode(&app), status);
+    }
+}
@@ -0,0 +1,365 @@
+//! Build the [`ValidAxiDmaConfig`] from a flattened device tree.
+//!
+//! The nodes follow the Xilinx binding of the AxiDMA:
+//!
+//! ```text
+//! dma@40400000 {
+//!     compatible = "xlnx,axi-dma-1.00.a";
+//!     reg = <0x40400000 0x10000>;
+//!     xlnx,include-sg;
+//!     xlnx,addrwidth = <0x20>;
+//!     xlnx,sg-length-width = <0x1a>;
+//!     dma-channel@40400000 {
+//!         compatible = "xlnx,axi-dma-mm2s-channel";
+//!         interrupts = <0 29 4>;
+//!         xlnx,datawidth = <0x40>;
+//!         xlnx,include-dre;
+//!     };
+//!     dma-channel@40400030 {
+//!         compatible = "xlnx,axi-dma-s2mm-channel";
+//!         interrupts = <0 30 4>;
+//!         xlnx,datawidth = <0x40>;
+//!     };
+//! };
+//! ```
+//!
+//! The properties emitted by the Xilinx device tree generator,
+//! `xlnx,micro-dma`, `xlnx,sg-include-stscntrl-strm`, `xlnx,mm2s-burst-size`
+//! and `xlnx,s2mm-burst-size`, are also recognized. The missing properties
+//! take the defaults of [`AxiDmaConfigBuilder`]. A DMA node which cannot be
+//! supported, e.g. without `xlnx,include-sg` or with an invalid data width,
+//! is skipped with a warning, only
+//! a malformed blob fails the parsing.
+//!
+
+use crate::{AxiDMAErr, AxiDmaConfigBuilder, ValidAxiDmaConfig};
+use alloc::vec::Vec;
+
+const FDT_MAGIC: u32 = 0xd00d_feed;
+const FDT_BEGIN_NODE: u32 = 1;
+const FDT_END_NODE: u32 = 2;
+const FDT_PROP: u32 = 3;
+const FDT_NOP: u32 = 4;
+const FDT_END: u32 = 9;
+
+const COMPATIBLE_DMA: &[u8] = b"xlnx,axi-dma-1.00.a";
+const COMPATIBLE_MM2S: &[u8] = b"xlnx,axi-dma-mm2s-channel";
+const COMPATIBLE_S2MM: &[u8] = b"xlnx,axi-dma-s2mm-channel";
+
+/// An AxiDMA described in the device tree
+#[derive(Debug)]
+pub struct AxiDmaFdtNode {
+    /// The validated configuration of the AxiDMA
+    pub config: ValidAxiDmaConfig,
+    /// The interrupt of the tx channel
+    pub tx_irq: Option<u32>,
+    /// The interrupt of the rx channel
+    pub rx_irq: Option<u32>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Dma,
+    Mm2s,
+    S2mm,
+    Other,
+}
+
+/// A DMA channel node, folded into its parent DMA node
+struct ChannelNode {
+    kind: NodeKind,
+    data_width: Option<u32>,
+    has_dre: bool,
+    irq: Option<u32>,
+}
+
+/// A node being parsed
+struct Frame<'a> {
+    name: &'a str,
+    kind: NodeKind,
+    /// `#address-cells` for the children
+    addr_cells: u32,
+    props: Vec<(&'a str, &'a [u8])>,
+    channels: Vec<ChannelNode>,
+}
+
+impl<'a> Frame<'a> {
+    fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            kind: NodeKind::Other,
+            addr_cells: 2,
+            props: Vec::new(),
+            channels: Vec::new(),
+        }
+    }
+
+    fn prop(&self, name: &str) -> Option<&'a [u8]> {
+        self.props.iter().find(|(n, _)| *n == name).map(|(_, v)| *v)
+    }
+
+    fn prop_u32(&self, name: &str) -> Option<u32> {
+        self.prop(name).and_then(|v| be32(v, 0))
+    }
+
+    /// A flag is either an empty property or a non-zero cell
+    fn prop_flag(&self, name: &str) -> bool {
+        match self.prop(name) {
+            Some([]) => true,
+            Some(v) => be32(v, 0).is_some_and(|v| v != 0),
+            None => false,
+        }
+    }
+
+    /// The interrupt number of the first interrupt specifier. For the 3-cell
+    /// specifier of the GIC, the SPI and PPI numbers are converted to the
+    /// hardware interrupt ID.
+    fn irq(&self) -> Option<u32> {
+        let v = self.prop("interrupts")?;
+        match v.len() / 4 {
+            0 => None,
+            3 => {
+                let num = be32(v, 4)?;
+                Some(if be32(v, 0)? == 0 { num + 32 } else { num + 16 })
+            }
+            _ => be32(v, 0),
+        }
+    }
+}
+
+fn be32(data: &[u8], off: usize) -> Option<u32> {
+    let bytes = data.get(off..off.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn align4(off: usize) -> usize {
+    (off + 3) & !3
+}
+
+/// Read a nul terminated string
+fn cstr(data: &[u8], off: usize) -> Option<(&str, usize)> {
+    let tail = data.get(off..)?;
+    let len = tail.iter().position(|&b| b == 0)?;
+    let s = core::str::from_utf8(&tail[..len]).ok()?;
+    Some((s, off + len + 1))
+}
+
+fn malformed(what: &str) -> AxiDMAErr {
+    error!("axidma::fdt: malformed device tree, {}", what);
+    AxiDMAErr::InValidParam
+}
+
+/// Parse the flattened device tree and build the configuration of every
+/// AxiDMA node in it.
+pub fn parse_fdt(dtb: &[u8]) -> Result<Vec<AxiDmaFdtNode>, AxiDMAErr> {
+    if be32(dtb, 0) != Some(FDT_MAGIC) {
+        return Err(malformed("bad magic"));
+    }
+    let total_size = be32(dtb, 4).ok_or_else(|| malformed("no header"))? as usize;
+    let struct_off = be32(dtb, 8).ok_or_else(|| malformed("no header"))? as usize;
+    let strings_off = be32(dtb, 12).ok_or_else(|| malformed("no header"))? as usize;
+    let dtb = dtb
+        .get(..total_size)
+        .ok_or_else(|| malformed("truncated blob"))?;
+    let strings = dtb
+        .get(strings_off..)
+        .ok_or_else(|| malformed("bad strings offset"))?;
+
+    let mut nodes = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut off = struct_off;
+    loop {
+        let token = be32(dtb, off).ok_or_else(|| malformed("truncated structure"))?;
+        off += 4;
+        match token {
+            FDT_BEGIN_NODE => {
+                let (name, end) = cstr(dtb, off).ok_or_else(|| malformed("bad node name"))?;
+                off = align4(end);
+                stack.push(Frame::new(name));
+            }
+            FDT_PROP => {
+                let len = be32(dtb, off).ok_or_else(|| malformed("truncated property"))? as usize;
+                let name_off = be32(dtb, off + 4).ok_or_else(|| malformed("truncated property"))?;
+                // `len` comes from the blob, it may overflow on 32-bit targets
+                let end = off
+                    .checked_add(8)
+                    .and_then(|start| start.checked_add(len))
+                    .ok_or_else(|| malformed("bad property length"))?;
+                let value = dtb
+                    .get(off + 8..end)
+                    .ok_or_else(|| malformed("truncated property"))?;
+                off = align4(end);
+                let (name, _) = cstr(strings, name_off as usize)
+                    .ok_or_else(|| malformed("bad property name"))?;
+                let frame = stack
+                    .last_mut()
+                    .ok_or_else(|| malformed("property outside of a node"))?;
+                match name {
+                    "#address-cells" => frame.addr_cells = be32(value, 0).unwrap_or(2),
+                    "compatible" => {
+                        for compatible in value.split(|&b| b == 0) {
+                            frame.kind = match compatible {
+                                COMPATIBLE_DMA => NodeKind::Dma,
+                                COMPATIBLE_MM2S => NodeKind::Mm2s,
+                                COMPATIBLE_S2MM => NodeKind::S2mm,
+                                _ => continue,
+                            };
+                        }
+                    }
+                    _ => {}
+                }
+                frame.props.push((name, value));
+            }
+            FDT_END_NODE => {
+                let frame = stack.pop().ok_or_else(|| malformed("unbalanced node"))?;
+                match frame.kind {
+                    NodeKind::Mm2s | NodeKind::S2mm => {
+                        if let Some(parent) = stack.last_mut() {
+                            parent.channels.push(ChannelNode {
+                                kind: frame.kind,
+                                data_width: frame.prop_u32("xlnx,datawidth"),
+                                has_dre: frame.prop_flag("xlnx,include-dre"),
+                                irq: frame.irq(),
+                            });
+                        }
+                    }
+                    NodeKind::Dma => {
+                        let addr_cells = stack.last().map_or(2, |parent| parent.addr_cells);
+                        match build_node(&frame, addr_cells) {
+                            Ok(node) => nodes.push(node),
+                            Err(_) => {
+                                warn!("axidma::fdt: {} is not supported, skipped", frame.name)
+                            }
+                        }
+                    }
+                    NodeKind::Other => {}
+                }
+            }
+            FDT_NOP => {}
+            FDT_END => break,
+            _ => return Err(malformed("unknown token")),
+        }
+    }
+    Ok(nodes)
+}
+
+/// Build the configuration of an AxiDMA node
+fn build_node(frame: &Frame, addr_cells: u32) -> Result<AxiDmaFdtNode, AxiDMAErr> {
+    let reg = frame.prop("reg").unwrap_or_default();
+    let base_address = match addr_cells {
+        1 => be32(reg, 0).map(|addr| addr as u64),
+        2 => be32(reg, 0)
+            .zip(be32(reg, 4))
+            .map(|(hi, lo)| ((hi as u64) << 32) | lo as u64),
+        _ => None,
+    };
+    let Some(base_address) = base_address else {
+        error!("axidma::fdt: {} has no valid reg", frame.name);
+        return Err(AxiDMAErr::InValidParam);
+    };
+
+    let mut builder = AxiDmaConfigBuilder::new(base_address as usize)
+        .sg(frame.prop_flag("xlnx,include-sg"))
+        .micro_dma(frame.prop_flag("xlnx,micro-dma"))
+        .sts_cntrl_strm(frame.prop_flag("xlnx,sg-include-stscntrl-strm"))
+        .no_mm2s()
+        .no_s2mm();
+    if let Some(width) = frame.prop_u32("xlnx,addrwidth") {
+        builder = builder.addr_width(width as isize);
+    }
+    if let Some(width) = frame.prop_u32("xlnx,sg-length-width") {
+        builder = builder.sg_length_width(width as usize);
+    }
+
+    let mut tx_irq = None;
+    let mut rx_irq = None;
+    for channel in frame.channels.iter() {
+        let data_width = channel.data_width.unwrap_or(32) as usize;
+        if channel.kind == NodeKind::Mm2s {
+            let burst_size = frame.prop_u32("xlnx,mm2s-burst-size").unwrap_or(16) as usize;
+            builder = builder.mm2s(data_width, burst_size, channel.has_dre);
+            tx_irq = channel.irq;
+        } else {
+            let burst_size = frame.prop_u32("xlnx,s2mm-burst-size").unwrap_or(16) as usize;
+            builder = builder.s2mm(data_width, burst_size, channel.has_dre);
+            rx_irq = channel.irq;
+        }
+    }
+    trace!(
+        "axidma::fdt: dma node at 0x{:x}, tx irq {:?}, rx irq {:?}",
+        base_address,
+        tx_irq,
+        rx_irq
+    );
+    Ok(AxiDmaFdtNode {
+        config: builder.build()?,
+        tx_irq,
+        rx_irq,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The blobs are compiled from the `.dts` next to them
+    const ZYNQ: &[u8] = include_bytes!("../tests/fixtures/zynq.dtb");
+    const ZYNQMP: &[u8] = include_bytes!("../tests/fixtures/zynqmp.dtb");
+
+    #[test]
+    fn parses_sg_node() {
+        let nodes = parse_fdt(ZYNQ).unwrap();
+        assert_eq!(nodes.len(), 1);
+
+        let sg = &nodes[0];
+        assert_eq!(sg.config.base_address, 0x4040_0000);
+        assert!(sg.config.has_sg);
+        assert_eq!(sg.config.sg_length_width, 26);
+        assert_eq!(sg.config.addr_width, 32);
+        assert!(sg.config.has_mm2s && sg.config.has_mm2s_dre);
+        assert_eq!(sg.config.mm2s_data_width, 64);
+        assert_eq!(sg.config.mm2s_burst_size, 32);
+        assert!(sg.config.has_s2mm && !sg.config.has_s2mm_dre);
+        assert_eq!(sg.config.s2mm_burst_size, 16);
+        // GIC SPIs are shifted to the hardware interrupt IDs
+        assert_eq!(sg.tx_irq, Some(61));
+        assert_eq!(sg.rx_irq, Some(62));
+    }
+
+    #[test]
+    fn skips_unsupported_nodes() {
+        let nodes = parse_fdt(ZYNQ).unwrap();
+        // The simple mode node and the node with an invalid data width
+        assert!(nodes
+            .iter()
+            .all(|node| ![0x4041_0000, 0x4042_0000].contains(&node.config.base_address)));
+    }
+
+    #[test]
+    fn parses_two_cell_reg() {
+        let nodes = parse_fdt(ZYNQMP).unwrap();
+        assert_eq!(nodes.len(), 1);
+        let node = &nodes[0];
+        assert_eq!(node.config.base_address, 0xa000_0000);
+        assert_eq!(node.config.addr_width, 64);
+        assert!(node.config.has_sts_cntrl_strm);
+        assert_eq!(node.config.mm2s_data_width, 128);
+        assert_eq!(node.tx_irq, Some(121));
+        // A single cell specifier is taken as is
+        assert_eq!(node.rx_irq, Some(5));
+    }
+
+    #[test]
+    fn rejects_malformed_blobs() {
+        assert!(parse_fdt(&[0; 64]).is_err());
+        assert!(parse_fdt(&ZYNQ[..ZYNQ.len() / 2]).is_err());
+
+        // The length of the first property of the root node
+        let struct_off = be32(ZYNQMP, 8).unwrap() as usize;
+        let len_off = struct_off + 12;
+        assert_eq!(be32(ZYNQMP, len_off - 4), Some(FDT_PROP));
+        let mut dtb = ZYNQMP.to_vec();
+        dtb[len_off..len_off + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(parse_fdt(&dtb).is_err());
+    }
+}
@@ -1,7 +1,23 @@
+#[cfg(not(axidma_xparameters))]
 use crate::AxiDmaConfig;
+use crate::ValidAxiDmaConfig;
+
+/// The configurations generated from the `xparameters.h` given by the
+/// `AXIDMA_XPARAMETERS` environment variable at build time.
+#[cfg(axidma_xparameters)]
+pub mod xparameters {
+    use crate::{AxiDmaConfig, ValidAxiDmaConfig};
+
+    include!(concat!(env!("OUT_DIR"), "/xparameters.rs"));
+}
+
+/// The default configuration of the AxiDMA, the instance 0 of `xparameters.h`
+#[cfg(axidma_xparameters)]
+pub const AXI_DMA_CONFIG: ValidAxiDmaConfig = xparameters::XPAR_AXIDMA_0_CONFIG;
 
 /// The default configuration of the AxiDMA
-pub const AXI_DMA_CONFIG: AxiDmaConfig = AxiDmaConfig {
+#[cfg(not(axidma_xparameters))]
+pub const AXI_DMA_CONFIG: ValidAxiDmaConfig = ValidAxiDmaConfig::new_unchecked(AxiDmaConfig {
     base_address: 0x6010_0000,
     rx_channel_offset: 0x30,
     tx_channel_offset: 0,
@@ -18,4 +34,4 @@ pub const AXI_DMA_CONFIG: AxiDmaConfig = AxiDmaConfig {
     has_sg: true,
     sg_length_width: 16,
     addr_width: 32,
-};
+});
@@ -0,0 +1,145 @@
+//! The registry routing the interrupts of many AxiDMA engines.
+//!
+//! Every engine has its own base address, so any number of [`AxiDma`] can be
+//! created from their configurations, e.g. from [`crate::parse_fdt`]. The
+//! registry maps the IRQ numbers to the channels, and the platform interrupt
+//! entry calls [`axidma_handle_irq`] for every interrupt it receives:
+//!
+//! ```ignore
+//! AXI_DMA_IRQ_REGISTRY.register(61, &eth0, DmaTransferDirection::MemToDev)?;
+//! AXI_DMA_IRQ_REGISTRY.register(62, &eth0, DmaTransferDirection::DevToMem)?;
+//!
+//! fn external_irq(irq: u32) {
+//!     let _ = axidma_handle_irq(irq);
+//! }
+//! ```
+//!
+//! The registry keeps the channels themselves, so the halves returned by
+//! [`AxiDma::split`] are registered with [`AxiDmaIrqRegistry::register_half`].
+//! Its lock is an [`IrqMutex`], because [`AxiDmaIrqRegistry::dispatch`] runs
+//! in interrupt context.
+//!
+
+use crate::channel::AxiDMAChannel;
+use crate::lock::IrqMutex;
+use crate::split::{ChannelHalf, HalfDirection};
+use crate::{AxiDMAErr, AxiDMAResult, AxiDma, DmaTransferDirection};
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+
+/// The global registry used by [`axidma_handle_irq`]
+pub static AXI_DMA_IRQ_REGISTRY: AxiDmaIrqRegistry = AxiDmaIrqRegistry::new();
+
+/// Route the interrupt `irq` to its channel through [`AXI_DMA_IRQ_REGISTRY`]
+pub fn axidma_handle_irq(irq: u32) -> AxiDMAResult {
+    AXI_DMA_IRQ_REGISTRY.dispatch(irq)
+}
+
+/// The map from the IRQ numbers to the channels of the engines
+pub struct AxiDmaIrqRegistry {
+    entries: IrqMutex<BTreeMap<u32, Arc<AxiDMAChannel>>>,
+}
+
+impl Default for AxiDmaIrqRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AxiDmaIrqRegistry {
+    /// Create an empty registry
+    pub const fn new() -> Self {
+        Self {
+            entries: IrqMutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Route `irq` to the tx (`MemToDev`) or the rx (`DevToMem`) channel of `dma`
+    pub fn register(
+        &self,
+        irq: u32,
+        dma: &Arc<AxiDma>,
+        direction: DmaTransferDirection,
+    ) -> AxiDMAResult {
+        let channel = match direction {
+            DmaTransferDirection::MemToDev => dma.tx_channel.as_ref(),
+            DmaTransferDirection::DevToMem => dma.rx_channel.as_ref(),
+        };
+        let Some(channel) = channel else {
+            error!(
+                "axidma::irq: engine 0x{:x} has no {:?} channel",
+                dma.base_address(), direction
+            );
+            return Err(AxiDMAErr::InValidParam);
+        };
+        self.insert(irq, channel)?;
+        trace!(
+            "axidma::irq: irq {} -> engine 0x{:x} {:?}",
+            irq,
+            dma.base_address(),
+            direction
+        );
+        Ok(())
+    }
+
+    /// Route `irq` to the channel of a half of a split engine
+    pub fn register_half<D: HalfDirection>(&self, irq: u32, half: &ChannelHalf<D>) -> AxiDMAResult {
+        self.insert(irq, half.channel())?;
+        trace!("axidma::irq: irq {} -> {} half", irq, D::NAME);
+        Ok(())
+    }
+
+    fn insert(&self, irq: u32, channel: &Arc<AxiDMAChannel>) -> AxiDMAResult {
+        let mut entries = self.entries.lock();
+        if entries.contains_key(&irq) {
+            error!("axidma::irq: irq {} is already registered", irq);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        entries.insert(irq, channel.clone());
+        Ok(())
+    }
+
+    /// Register the interrupts of both channels of `dma`
+    pub fn register_engine(
+        &self,
+        dma: &Arc<AxiDma>,
+        tx_irq: Option<u32>,
+        rx_irq: Option<u32>,
+    ) -> AxiDMAResult {
+        if let Some(irq) = tx_irq {
+            self.register(irq, dma, DmaTransferDirection::MemToDev)?;
+        }
+        if let Some(irq) = rx_irq {
+            if let Err(err) = self.register(irq, dma, DmaTransferDirection::DevToMem) {
+                if let Some(irq) = tx_irq {
+                    self.unregister(irq);
+                }
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the route of `irq`, it returns whether `irq` was registered
+    pub fn unregister(&self, irq: u32) -> bool {
+        self.entries.lock().remove(&irq).is_some()
+    }
+
+    /// Remove all the routes to the channels of `dma`
+    pub fn unregister_engine(&self, dma: &Arc<AxiDma>) {
+        let mut channels = dma.tx_channel.iter().chain(dma.rx_channel.iter());
+        self.entries
+            .lock()
+            .retain(|_, channel| !channels.any(|owned| Arc::ptr_eq(owned, channel)));
+    }
+
+    /// Call the interrupt handler of the channel routed from `irq`
+    pub fn dispatch(&self, irq: u32) -> AxiDMAResult {
+        // The handler runs without the registry lock
+        let Some(channel) = self.entries.lock().get(&irq).cloned() else {
+            error!("axidma::irq: unknown irq {}", irq);
+            return Err(AxiDMAErr::InValidParam);
+        };
+        channel.intr_handler()
+    }
+}
@@ -7,6 +7,9 @@
 //!     - [x] Interrupts
 //!     - [ ] Programmable interrupt coalescing for SGDMA
 //!     - [ ] APIs to manage Buffer Descriptors (BD) movement to and from the SGDMA engine
+//!     - [x] Multichannel DMA (MCDMA), see [`AxiMcdma`]
+//!     - [x] Central DMA (CDMA) for memory to memory copy, see [`AxiCdma`]
+//!     - [x] Video DMA (VDMA) for frame buffers, see [`AxiVdma`]
 //!
 //! ### Simple DMA
 //!
@@ -271,18 +274,73 @@ extern crate alloc;
 
 mod bd;
 mod buffer;
+mod cdma;
 mod channel;
+mod clock;
+mod completion;
+mod config;
+mod dmaengine;
 mod errno;
+mod eth;
+#[cfg(feature = "fdt")]
+mod fdt;
 mod hw;
+mod irq;
+mod limits;
+mod lock;
+mod mcdma;
+mod napi;
+#[cfg(feature = "smoltcp")]
+mod phy;
+mod probe;
+#[cfg(feature = "async")]
+mod ready;
+mod ring;
+mod split;
+mod staging;
+mod stats;
 mod transfer;
+mod typestate;
+mod vdma;
+mod watchdog;
 
 use alloc::sync::Arc;
+use axidma_pac::{axi_dma, Instance};
 pub use buffer::BufPtr;
+pub use cdma::{AxiCdma, AxiCdmaConfig, CdmaCopy};
+pub use channel::{AxiDmaDescriptor, ChannelSnapshot};
+pub use clock::{clock, set_clock, MonotonicClock, FALLBACK_POLLS_PER_US};
+pub use completion::{Completion, CompletionCallback, CompletionStatus};
+pub use config::{AxiDmaConfigBuilder, ValidAxiDmaConfig};
 use channel::AxiDMAChannel;
+use clock::Deadline;
 use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+pub use dmaengine::{DmaChannel, DmaCookie, DmaStatus, DmaTransferDirection};
 use errno::AxiDMAErr;
+pub use eth::{EthRxCsum, EthRxStatus, EthTxCsum};
+#[cfg(feature = "fdt")]
+pub use fdt::{parse_fdt, AxiDmaFdtNode};
 use hw::AXI_DMA_CONFIG;
-pub use transfer::Transfer;
+#[cfg(axidma_xparameters)]
+pub use hw::xparameters;
+#[cfg(feature = "smoltcp")]
+pub use phy::{AxiDmaDevice, AxiDmaRxToken, AxiDmaTxToken, ETH_FRAME_LEN};
+pub use irq::{axidma_handle_irq, AxiDmaIrqRegistry, AXI_DMA_IRQ_REGISTRY};
+pub use lock::{IrqMutex, IrqMutexGuard};
+pub use mcdma::{AxiMcdma, AxiMcdmaChannel, AxiMcdmaConfig, McdmaSchedule, McdmaSideband};
+pub use napi::NapiConfig;
+pub use probe::{AxiDmaProbeErr, ProbeChannel};
+#[cfg(feature = "async")]
+pub use ready::SubmitReady;
+pub use split::{ChannelHalf, HalfDirection, Rx, RxChannel, Tx, TxChannel};
+pub use stats::{ChannelStats, ErrorCounts};
+pub use transfer::{StagedTransfer, Transfer, TransferChannel};
+#[cfg(feature = "async")]
+pub use transfer::TransferTimeout;
+pub use typestate::{Channel, Created, Engine, Halted, Initialized, Running, Uninitialized};
+pub use vdma::{AxiVdma, AxiVdmaChannel, AxiVdmaConfig, VdmaFrameFormat, VdmaMode};
+pub use watchdog::{StallRecovery, StallReport, WatchdogConfig};
 
 pub type AxiDMAResult = Result<(), AxiDMAErr>;
 
@@ -290,14 +348,13 @@ pub type AxiDMAResult = Result<(), AxiDMAErr>;
 /// engine in use.
 pub struct AxiDma {
     // Immutable
-    /// The base address of the AxiDMA
+    /// The registers of the AxiDMA
     #[allow(unused)]
-    base_address: usize,
+    regs: Instance<axi_dma::RegisterBlock>,
     /// Has Scatter Gather mode
     #[allow(unused)]
     has_sg: bool,
     /// Whether the micro dma mode is enable
-    #[allow(unused)]
     is_micro_dma: bool,
     /// the width of address
     #[allow(unused)]
@@ -357,10 +414,12 @@ impl Default for AxiDma {
 
 impl AxiDma {
     /// Reset time out
-    const RESET_TIMEOUT: isize = 500;
+    const RESET_TIMEOUT: Duration = Duration::from_millis(10);
+    /// Halt time out of the pause
+    const HALT_TIMEOUT: Duration = Duration::from_millis(10);
 
-    /// Create the AxiDMA instance according to the configuration
-    pub fn new(cfg: AxiDmaConfig) -> Self {
+    /// Create the AxiDMA instance according to the validated configuration
+    pub fn new(cfg: ValidAxiDmaConfig) -> Self {
         let tx_channel = if cfg.has_mm2s {
             Some(Arc::new(AxiDMAChannel::new(channel::Direaction::TX, &cfg)))
         } else {
@@ -372,7 +431,7 @@ impl AxiDma {
             None
         };
         Self {
-            base_address: cfg.base_address,
+            regs: unsafe { Instance::from_base(cfg.base_address) },
             has_sg: cfg.has_sg,
             is_micro_dma: cfg.is_micro_dma,
             addr_width: cfg.addr_width,
@@ -385,31 +444,101 @@ impl AxiDma {
     /// Get the registers of the AxiDMA
     #[inline]
     #[allow(unused)]
-    fn hardware(&self) -> &axidma_pac::axi_dma::RegisterBlock {
-        unsafe { &*(self.base_address as *const _) }
+    fn hardware(&self) -> &axi_dma::RegisterBlock {
+        &self.regs
+    }
+
+    /// Fail with `NotInit` if the AxiDMA has not been reset, `op` names the
+    /// operation in the log
+    fn check_initialized(&self, op: &str) -> AxiDMAResult {
+        if !self.is_initialized.load(Ordering::Relaxed) {
+            error!("{}: Driver not initialized", op);
+            return Err(AxiDMAErr::NotInit);
+        }
+        Ok(())
     }
 
     /// Reset the AxiDMA
     pub fn reset(self: &Arc<Self>) -> AxiDMAResult {
+        self.reset_timeout(AxiDma::RESET_TIMEOUT)
+    }
+
+    /// Reset the AxiDMA and wait at most `timeout` for the reset to be done
+    pub fn reset_timeout(self: &Arc<Self>, timeout: Duration) -> AxiDMAResult {
         if let Some(tx_channel) = self.tx_channel.as_ref() {
             tx_channel.reset()?;
         }
         if let Some(rx_channel) = self.rx_channel.as_ref() {
             rx_channel.reset()?;
         }
-        let mut timeout = AxiDma::RESET_TIMEOUT;
-        while timeout > 0 && !self.reset_is_done() {
-            timeout -= 1;
+        let mut deadline = Deadline::after(timeout);
+        while !self.reset_is_done() {
+            if !deadline.expired() {
+                core::hint::spin_loop();
+                continue;
+            }
+            let stuck = self
+                .tx_channel
+                .iter()
+                .chain(self.rx_channel.iter())
+                .find(|channel| !channel.reset_is_done());
+            if let Some(channel) = stuck {
+                error!("AXIDMA: failed reset in intialization");
+                return Err(AxiDMAErr::Timeout(channel.snapshot()));
+            }
         }
-        if timeout > 0 {
-            self.is_initialized.store(true, Ordering::Relaxed);
-        } else {
-            error!("AXIDMA: failed reset in intialization");
-            return Err(AxiDMAErr::DMAErr);
+        self.is_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Sample the progress of both channels with their stall watchdog, it
+    /// must be called periodically. It returns the reports of the tx and the
+    /// rx channel. A stalled channel is recovered as configured, and the other
+    /// channel resubmits its packets because the reset stops it as well.
+    pub fn watchdog_check(
+        self: &Arc<Self>,
+    ) -> Result<(Option<StallReport>, Option<StallReport>), AxiDMAErr> {
+        let tx = self.tx_channel.as_ref().and_then(|c| c.watchdog_sample());
+        let rx = self.rx_channel.as_ref().and_then(|c| c.watchdog_sample());
+        let recovery = |report: &Option<StallReport>| match report {
+            Some(report) => report.recovery,
+            None => StallRecovery::None,
+        };
+        self.recover(recovery(&tx), recovery(&rx))?;
+        Ok((tx, rx))
+    }
+
+    /// Reset the AxiDMA and recover the pending packets of both channels with
+    /// `tx` and `rx`. DMACR of both channels is restored. Nothing is done if
+    /// both are [`StallRecovery::None`], otherwise a channel with
+    /// [`StallRecovery::None`] resubmits its packets.
+    pub fn recover(self: &Arc<Self>, tx: StallRecovery, rx: StallRecovery) -> AxiDMAResult {
+        if tx == StallRecovery::None && rx == StallRecovery::None {
+            return Ok(());
+        }
+        let tx_dmacr = self.tx_channel.as_ref().map(|c| c.recover_begin()).transpose()?;
+        let rx_dmacr = self.rx_channel.as_ref().map(|c| c.recover_begin()).transpose()?;
+        self.reset()?;
+        if let (Some(channel), Some(dmacr)) = (self.tx_channel.as_ref(), tx_dmacr) {
+            channel.recover_end(dmacr, tx)?;
+        }
+        if let (Some(channel), Some(dmacr)) = (self.rx_channel.as_ref(), rx_dmacr) {
+            channel.recover_end(dmacr, rx)?;
         }
         Ok(())
     }
 
+    /// The base address of the AxiDMA
+    pub fn base_address(&self) -> usize {
+        self.regs.base_address()
+    }
+
+    /// Whether the AxiDMA works in the micro dma mode, in which every BD
+    /// stays within one burst and the data realignment is not available.
+    pub fn is_micro_dma(&self) -> bool {
+        self.is_micro_dma
+    }
+
     // Check reset is done when both went normal
     fn reset_is_done(self: &Arc<Self>) -> bool {
         if let Some(tx_channel) = self.tx_channel.as_ref() {
@@ -447,10 +576,7 @@ impl AxiDma {
 
     /// Start the AxiDMA
     pub fn start(self: &Arc<Self>) -> AxiDMAResult {
-        if !self.is_initialized.load(Ordering::Relaxed) {
-            error!("Start: Driver not initialized");
-            return Err(AxiDMAErr::NotInit);
-        }
+        self.check_initialized("Start")?;
         if let Some(tx_channel) = self.tx_channel.as_ref() {
             tx_channel.start()?;
         }
@@ -460,28 +586,41 @@ impl AxiDma {
         Ok(())
     }
 
-    /// Pause the AxiDMA
+    /// Pause the AxiDMA, the channels are stopped gracefully and their
+    /// completed BDs are retrieved.
     pub fn pause(self: &Arc<Self>) -> AxiDMAResult {
-        if !self.is_initialized.load(Ordering::Relaxed) {
-            error!("Pause: Driver not initialized");
-            return Err(AxiDMAErr::NotInit);
+        self.check_initialized("Pause")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            tx_channel.quiesce(AxiDma::HALT_TIMEOUT)?;
         }
+        if let Some(rx_channel) = self.rx_channel.as_ref() {
+            rx_channel.quiesce(AxiDma::HALT_TIMEOUT)?;
+        }
+        Ok(())
+    }
+
+    /// Pause the AxiDMA as [`AxiDma::pause`], then abort all the pending
+    /// transfers, see `AxiDMAChannel::quiesce_abort`.
+    pub fn pause_abort(self: &Arc<Self>) -> AxiDMAResult {
+        self.check_initialized("Pause")?;
         if let Some(tx_channel) = self.tx_channel.as_ref() {
-            tx_channel.stop()?;
+            tx_channel.quiesce_abort(AxiDma::HALT_TIMEOUT)?;
         }
         if let Some(rx_channel) = self.rx_channel.as_ref() {
-            rx_channel.stop()?;
+            rx_channel.quiesce_abort(AxiDma::HALT_TIMEOUT)?;
         }
         Ok(())
     }
 
-    /// Resume the AxiDMA
+    /// Resume the AxiDMA from where the channels stopped
     pub fn resume(self: &Arc<Self>) -> AxiDMAResult {
-        if !self.is_initialized.load(Ordering::Relaxed) {
-            error!("Resume: Driver not initialized");
-            return Err(AxiDMAErr::NotInit);
+        self.check_initialized("Resume")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            tx_channel.resume()?;
+        }
+        if let Some(rx_channel) = self.rx_channel.as_ref() {
+            rx_channel.resume()?;
         }
-        self.start()?;
         Ok(())
     }
 
@@ -507,6 +646,7 @@ impl AxiDma {
 
     /// Initialize the tx channel
     pub fn tx_channel_create(self: &Arc<Self>, bd_count: usize) -> AxiDMAResult {
+        self.check_initialized("Create tx channel")?;
         if let Some(tx_channel) = self.tx_channel.as_ref() {
             tx_channel.intr_disable();
             tx_channel.create(bd_count)?;
@@ -517,6 +657,7 @@ impl AxiDma {
 
     /// Initialize the rx channel
     pub fn rx_channel_create(self: &Arc<Self>, bd_count: usize) -> AxiDMAResult {
+        self.check_initialized("Create rx channel")?;
         if let Some(rx_channel) = self.rx_channel.as_ref() {
             rx_channel.intr_disable();
             rx_channel.create(bd_count)?;
@@ -527,6 +668,7 @@ impl AxiDma {
 
     /// Submit a buffer to the tx channel
     pub fn tx_submit(self: &Arc<Self>, buffer: BufPtr) -> Result<Transfer, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
         if let Some(tx_channel) = self.tx_channel.as_ref() {
             let transfer = Transfer::new(tx_channel.submit(buffer)?, tx_channel.clone());
             tx_channel.to_hw()?;
@@ -536,8 +678,145 @@ impl AxiDma {
         Err(AxiDMAErr::BDRingNoList)
     }
 
+    /// Submit a buffer to the tx channel, waiting for free BDs if the ring is
+    /// full. It waits at most `timeout`, `None` waits forever.
+    pub fn tx_submit_blocking(
+        self: &Arc<Self>,
+        buffer: BufPtr,
+        timeout: Option<Duration>,
+    ) -> Result<Transfer, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            let buffer = tx_channel.submit_blocking(buffer, timeout)?;
+            let transfer = Transfer::new(buffer, tx_channel.clone());
+            tx_channel.to_hw()?;
+            return Ok(transfer);
+        }
+        error!("axidma::tx_submit_blocking: no tx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the tx channel once enough BDs are free
+    #[cfg(feature = "async")]
+    pub async fn tx_submit_ready(self: &Arc<Self>, buffer: BufPtr) -> Result<Transfer, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            let buffer = tx_channel.submit_ready(buffer)?.await?;
+            let transfer = Transfer::new(buffer, tx_channel.clone());
+            tx_channel.to_hw()?;
+            return Ok(transfer);
+        }
+        error!("axidma::tx_submit_ready: no tx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the tx channel with the user application words,
+    /// see [`EthTxCsum`] for the checksum offload of AXI Ethernet.
+    pub fn tx_submit_app(
+        self: &Arc<Self>,
+        buffer: BufPtr,
+        app: &[u32; 5],
+    ) -> Result<Transfer, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            let transfer = Transfer::new(tx_channel.submit_app(buffer, app)?, tx_channel.clone());
+            tx_channel.to_hw()?;
+            return Ok(transfer);
+        }
+        error!("axidma::tx_submit_app: no tx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the tx channel from any core, see [`AxiDMAChannel::submit_mp`].
+    /// The buffer may only be staged, see [`StagedTransfer::into_transfer`].
+    pub fn tx_submit_mp(self: &Arc<Self>, buffer: BufPtr) -> Result<StagedTransfer, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            let in_ring = tx_channel.submit_mp_tracked(&buffer)?;
+            let transfer = Transfer::new(buffer, tx_channel.clone());
+            return Ok(StagedTransfer::new(transfer, in_ring));
+        }
+        error!("axidma::tx_submit_mp: no tx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the tx channel and hand it over to the channel, it is
+    /// given back by the completion queue of the channel.
+    pub fn tx_submit_queued(self: &Arc<Self>, buffer: BufPtr) -> Result<DmaCookie, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            let cookie = tx_channel.submit_queued(buffer)?;
+            tx_channel.to_hw()?;
+            return Ok(cookie);
+        }
+        error!("axidma::tx_submit_queued: no tx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the rx channel and hand it over to the channel, it is
+    /// given back by the completion queue of the channel.
+    pub fn rx_submit_queued(self: &Arc<Self>, buffer: BufPtr) -> Result<DmaCookie, AxiDMAErr> {
+        self.check_initialized("Rx submit")?;
+        if let Some(rx_channel) = self.rx_channel.as_ref() {
+            let cookie = rx_channel.submit_queued(buffer)?;
+            rx_channel.to_hw()?;
+            return Ok(cookie);
+        }
+        error!("axidma::rx_submit_queued: no rx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the tx channel, `callback` is invoked by the interrupt
+    /// handler of the channel once the buffer is transferred.
+    pub fn tx_submit_with(
+        self: &Arc<Self>,
+        buffer: BufPtr,
+        callback: CompletionCallback,
+    ) -> Result<DmaCookie, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
+        if let Some(tx_channel) = self.tx_channel.as_ref() {
+            let cookie = tx_channel.submit_with_callback(buffer, callback)?;
+            tx_channel.to_hw()?;
+            return Ok(cookie);
+        }
+        error!("axidma::tx_submit_with: no tx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the rx channel, `callback` is invoked by the interrupt
+    /// handler of the channel once the buffer is filled.
+    pub fn rx_submit_with(
+        self: &Arc<Self>,
+        buffer: BufPtr,
+        callback: CompletionCallback,
+    ) -> Result<DmaCookie, AxiDMAErr> {
+        self.check_initialized("Rx submit")?;
+        if let Some(rx_channel) = self.rx_channel.as_ref() {
+            let cookie = rx_channel.submit_with_callback(buffer, callback)?;
+            rx_channel.to_hw()?;
+            return Ok(cookie);
+        }
+        error!("axidma::rx_submit_with: no rx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Take all the completion records of the tx channel
+    pub fn tx_drain_completions<F: FnMut(Completion)>(self: &Arc<Self>, f: F) -> usize {
+        self.tx_channel
+            .as_ref()
+            .map_or(0, |tx_channel| tx_channel.drain_completions(f))
+    }
+
+    /// Take all the completion records of the rx channel
+    pub fn rx_drain_completions<F: FnMut(Completion)>(self: &Arc<Self>, f: F) -> usize {
+        self.rx_channel
+            .as_ref()
+            .map_or(0, |rx_channel| rx_channel.drain_completions(f))
+    }
+
     /// Submit a buffer to the rx channel
     pub fn rx_submit(self: &Arc<Self>, buffer: BufPtr) -> Result<Transfer, AxiDMAErr> {
+        self.check_initialized("Rx submit")?;
         if let Some(rx_channel) = self.rx_channel.as_ref() {
             let transfer = Transfer::new(rx_channel.submit(buffer)?, rx_channel.clone());
             rx_channel.to_hw()?;
@@ -546,12 +825,52 @@ impl AxiDma {
         error!("axidma::rx_submit: no rx ring!");
         Err(AxiDMAErr::BDRingNoList)
     }
+
+    /// Submit a buffer to the rx channel, waiting for free BDs if the ring is
+    /// full. It waits at most `timeout`, `None` waits forever.
+    pub fn rx_submit_blocking(
+        self: &Arc<Self>,
+        buffer: BufPtr,
+        timeout: Option<Duration>,
+    ) -> Result<Transfer, AxiDMAErr> {
+        self.check_initialized("Rx submit")?;
+        if let Some(rx_channel) = self.rx_channel.as_ref() {
+            let buffer = rx_channel.submit_blocking(buffer, timeout)?;
+            let transfer = Transfer::new(buffer, rx_channel.clone());
+            rx_channel.to_hw()?;
+            return Ok(transfer);
+        }
+        error!("axidma::rx_submit_blocking: no rx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to the rx channel once enough BDs are free
+    #[cfg(feature = "async")]
+    pub async fn rx_submit_ready(self: &Arc<Self>, buffer: BufPtr) -> Result<Transfer, AxiDMAErr> {
+        self.check_initialized("Rx submit")?;
+        if let Some(rx_channel) = self.rx_channel.as_ref() {
+            let buffer = rx_channel.submit_ready(buffer)?.await?;
+            let transfer = Transfer::new(buffer, rx_channel.clone());
+            rx_channel.to_hw()?;
+            return Ok(transfer);
+        }
+        error!("axidma::rx_submit_ready: no rx ring!");
+        Err(AxiDMAErr::BDRingNoList)
+    }
 }
 
 ///
 #[inline]
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
 pub fn io_fence() {
     unsafe {
         core::arch::asm!("fence iorw,iorw");
     }
 }
+
+/// The memory fence of the other targets, e.g. the host running the tests
+#[inline]
+#[cfg(not(any(target_arch = "riscv32", target_arch = "riscv64")))]
+pub fn io_fence() {
+    core::sync::atomic::fence(core::sync::atomic::Ordering::SeqCst);
+}
@@ -0,0 +1,145 @@
+//! The limits of the AxiDMA parameters, see
+//! [PG021](https://docs.xilinx.com/r/en-US/pg021_axi_dma).
+//!
+//! This module is shared by [`AxiDmaConfig::validate`](crate::AxiDmaConfig::validate)
+//! and `build.rs`, which includes it by path, so it only depends on `core`.
+//!
+
+use core::fmt;
+
+/// The size of the register window of the AxiDMA
+pub(crate) const REG_WINDOW: usize = 0x1_0000;
+/// The size of the registers of a channel, also the default offset of the rx channel
+pub(crate) const CHANNEL_REG_SIZE: usize = 0x30;
+
+/// The parameters of a channel
+pub(crate) struct ChannelParams {
+    pub(crate) offset: usize,
+    pub(crate) data_width: usize,
+    pub(crate) burst_size: usize,
+    pub(crate) has_dre: bool,
+}
+
+/// The parameters of an engine, the absent channels are `None`
+pub(crate) struct EngineParams {
+    pub(crate) has_sg: bool,
+    pub(crate) is_micro_dma: bool,
+    pub(crate) sg_length_width: usize,
+    pub(crate) addr_width: isize,
+    pub(crate) tx: Option<ChannelParams>,
+    pub(crate) rx: Option<ChannelParams>,
+}
+
+/// The parameter which breaks a limit
+#[derive(Debug)]
+pub(crate) enum LimitErr {
+    NoChannel,
+    NoSg,
+    SgLengthWidth(usize),
+    AddrWidth(isize),
+    Offset(&'static str, usize),
+    DataWidth(&'static str, usize),
+    BurstSize(&'static str, usize),
+    MicroDmaDre(&'static str),
+    WideDre(&'static str, usize),
+    MicroDmaBurst(&'static str, usize),
+    Overlap(usize, usize),
+}
+
+impl fmt::Display for LimitErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoChannel => write!(f, "neither tx nor rx channel"),
+            Self::NoSg => write!(f, "no scatter gather engine, the driver needs the SG mode"),
+            Self::SgLengthWidth(width) => {
+                write!(f, "invalid sg length width {}, must be 8 ~ 26", width)
+            }
+            Self::AddrWidth(width) => {
+                write!(f, "invalid address width {}, must be 32 or 64", width)
+            }
+            Self::Offset(name, offset) => {
+                write!(
+                    f,
+                    "{} offset 0x{:x} is out of the register window",
+                    name, offset
+                )
+            }
+            Self::DataWidth(name, width) => write!(
+                f,
+                "invalid {} data width {}, must be a power of two in 32 ~ 1024",
+                name, width
+            ),
+            Self::BurstSize(name, size) => write!(
+                f,
+                "invalid {} burst size {}, must be a power of two in 2 ~ 256",
+                name, size
+            ),
+            Self::MicroDmaDre(name) => {
+                write!(f, "{} dre is not available in micro dma mode", name)
+            }
+            Self::WideDre(name, width) => {
+                write!(f, "{} dre is not available with data width {}", name, width)
+            }
+            Self::MicroDmaBurst(name, size) => write!(
+                f,
+                "invalid {} burst size {} in micro dma mode, must be 2 ~ 64",
+                name, size
+            ),
+            Self::Overlap(tx, rx) => {
+                write!(f, "tx offset 0x{:x} overlaps rx offset 0x{:x}", tx, rx)
+            }
+        }
+    }
+}
+
+impl EngineParams {
+    /// Check the parameters against the limits of the hardware
+    pub(crate) fn check(&self) -> Result<(), LimitErr> {
+        if self.tx.is_none() && self.rx.is_none() {
+            return Err(LimitErr::NoChannel);
+        }
+        if !self.has_sg {
+            return Err(LimitErr::NoSg);
+        }
+        if !(8..=26).contains(&self.sg_length_width) {
+            return Err(LimitErr::SgLengthWidth(self.sg_length_width));
+        }
+        if self.addr_width != 32 && self.addr_width != 64 {
+            return Err(LimitErr::AddrWidth(self.addr_width));
+        }
+        if let Some(tx) = self.tx.as_ref() {
+            self.check_channel("tx", tx)?;
+        }
+        if let Some(rx) = self.rx.as_ref() {
+            self.check_channel("rx", rx)?;
+        }
+        if let (Some(tx), Some(rx)) = (self.tx.as_ref(), self.rx.as_ref()) {
+            if tx.offset.abs_diff(rx.offset) < CHANNEL_REG_SIZE {
+                return Err(LimitErr::Overlap(tx.offset, rx.offset));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_channel(&self, name: &'static str, channel: &ChannelParams) -> Result<(), LimitErr> {
+        if !channel.offset.is_multiple_of(4) || channel.offset > REG_WINDOW - CHANNEL_REG_SIZE {
+            return Err(LimitErr::Offset(name, channel.offset));
+        }
+        if !channel.data_width.is_power_of_two() || !(32..=1024).contains(&channel.data_width) {
+            return Err(LimitErr::DataWidth(name, channel.data_width));
+        }
+        if !channel.burst_size.is_power_of_two() || !(2..=256).contains(&channel.burst_size) {
+            return Err(LimitErr::BurstSize(name, channel.burst_size));
+        }
+        if channel.has_dre && self.is_micro_dma {
+            return Err(LimitErr::MicroDmaDre(name));
+        }
+        if channel.has_dre && channel.data_width > 512 {
+            return Err(LimitErr::WideDre(name, channel.data_width));
+        }
+        if self.is_micro_dma && channel.burst_size > 64 {
+            return Err(LimitErr::MicroDmaBurst(name, channel.burst_size));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! The lock of the BD rings, which is safe to take from the interrupt handlers.
+//!
+//! A plain spin lock deadlocks when an interrupt handler of the same core tries
+//! to take the lock held by the interrupted thread. [`IrqMutex`] enters a
+//! critical section before spinning, so the holder can not be interrupted on
+//! its own core, while the spin lock still excludes the other cores.
+//!
+//! The critical section is provided by the platform through the
+//! [critical-section](https://docs.rs/critical-section) crate, e.g. the
+//! `critical-section-single-hart` feature of the `riscv` crate, which masks
+//! the interrupts of the current hart.
+//!
+
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use critical_section::RestoreState;
+use spin::{Mutex, MutexGuard};
+
+/// A spin lock taken within a critical section
+pub struct IrqMutex<T> {
+    inner: Mutex<T>,
+}
+
+/// The guard of [`IrqMutex`], the critical section ends when it is dropped.
+pub struct IrqMutexGuard<'a, T> {
+    guard: ManuallyDrop<MutexGuard<'a, T>>,
+    state: RestoreState,
+}
+
+impl<T> IrqMutex<T> {
+    /// Create the lock
+    pub const fn new(value: T) -> Self {
+        Self {
+            inner: Mutex::new(value),
+        }
+    }
+
+    /// Enter a critical section and take the lock
+    pub fn lock(&self) -> IrqMutexGuard<'_, T> {
+        // Safety: the state is released exactly once by the guard
+        let state = unsafe { critical_section::acquire() };
+        IrqMutexGuard {
+            guard: ManuallyDrop::new(self.inner.lock()),
+            state,
+        }
+    }
+
+    /// Enter a critical section and try to take the lock once. It returns
+    /// `None` if the lock is held, e.g. by another core.
+    pub fn try_lock(&self) -> Option<IrqMutexGuard<'_, T>> {
+        let state = unsafe { critical_section::acquire() };
+        match self.inner.try_lock() {
+            Some(guard) => Some(IrqMutexGuard {
+                guard: ManuallyDrop::new(guard),
+                state,
+            }),
+            None => {
+                unsafe { critical_section::release(state) };
+                None
+            }
+        }
+    }
+}
+
+impl<T> Deref for IrqMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for IrqMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T> Drop for IrqMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // Release the lock before leaving the critical section
+        unsafe {
+            ManuallyDrop::drop(&mut self.guard);
+            critical_section::release(self.state);
+        }
+    }
+}
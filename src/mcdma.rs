@@ -0,0 +1,851 @@
+//! This is the driver API for the AXI Multichannel DMA (MCDMA) engine.
+//! See [PG288](https://docs.xilinx.com/r/en-US/pg288-axi-mcdma) for the
+//! hardware spec.
+//!
+//! The MCDMA has at most 16 MM2S channels and 16 S2MM channels. Each channel
+//! owns its BD ring, which is managed by the same [`BDRing`] as the AXI DMA.
+//! The engine of one direction is shared by all its channels:
+//!
+//! - The Common Control register starts or resets the engine.
+//!
+//! - The Channel Enable register decides which channels are serviced.
+//!
+//! - The MM2S scheduler decides in which order the enabled MM2S channels are
+//!   serviced. The S2MM channel is selected by the TDEST of the incoming stream.
+//!
+//! - The Interrupt Status register tells which channels have raised an
+//!   interrupt. Each channel has its own interrupt enable and coalescing
+//!   control.
+//!
+//! The register space of the channels is placed behind the common registers:
+//!
+//! <pre>
+//!   MM2S common: base + 0x000, MM2S channel n: base + 0x040 + n * 0x40
+//!   S2MM common: base + 0x500, S2MM channel n: base + 0x540 + n * 0x40
+//! </pre>
+//!
+
+use crate::bd::McdmaBD;
+use crate::channel::{ChannelSnapshot, Direaction};
+use crate::clock::Deadline;
+use crate::lock::IrqMutex;
+use crate::ring::BDRing;
+use crate::transfer::TransferChannel;
+use crate::{io_fence, AxiDMAErr, AxiDMAResult, BufPtr, Transfer};
+use alloc::{sync::Arc, vec::Vec};
+use axidma_pac::{mcdma, mcdma_channel, Instance};
+use core::sync::atomic::{compiler_fence, fence, AtomicBool, Ordering, Ordering::SeqCst};
+use core::time::Duration;
+
+/// The configuration structure for AXI MCDMA engine.
+#[derive(Debug)]
+pub struct AxiMcdmaConfig {
+    /// The base address of the AxiMCDMA
+    pub base_address: usize,
+    /// Whether the status/control stream
+    pub has_sts_cntrl_strm: bool,
+    /// Has tx channels
+    pub has_mm2s: bool,
+    /// The number of tx channels
+    pub mm2s_num_channels: usize,
+    /// The data width of tx channels
+    pub mm2s_data_width: usize,
+    /// Has rx channels
+    pub has_s2mm: bool,
+    /// The number of rx channels
+    pub s2mm_num_channels: usize,
+    /// The data width of rx channels
+    pub s2mm_data_width: usize,
+    /// The width of the buffer length field
+    pub sg_length_width: usize,
+    /// the width of address
+    pub addr_width: isize,
+}
+
+/// The order in which the enabled MM2S channels are serviced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McdmaSchedule {
+    /// Every enabled channel is serviced in turn
+    RoundRobin = 0,
+    /// The channel with the highest index has the highest priority
+    StrictPriority = 1,
+    /// Every enabled channel is serviced in turn according to its weight
+    WeightedRoundRobin = 2,
+}
+
+/// The stream sideband of a packet.
+/// It is driven on the stream by MM2S and captured from the stream by S2MM.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct McdmaSideband {
+    /// TID of the stream
+    pub tid: u8,
+    /// TDEST of the stream, only the low 5 bits are valid
+    pub tdest: u8,
+    /// TUSER of the stream
+    pub tuser: u16,
+}
+
+/// The structure of a MCDMA channel for descriptor storage control.
+pub struct AxiMcdmaChannel {
+    // Immutable
+    /// The registers of the MCDMA
+    engine: Instance<mcdma::RegisterBlock>,
+    /// The registers of the channel
+    regs: Instance<mcdma_channel::RegisterBlock>,
+    /// The direction of the channel
+    direction: Direaction,
+    /// The index of the channel
+    id: usize,
+    /// Whether has stscntrl stream
+    has_sts_cntrl_strm: bool,
+    /// The data width of the channel
+    data_width: usize,
+    /// The max length of a single buffer
+    max_transfer_len: usize,
+
+    // Mutable
+    /// Buffer descriptor ring
+    pub ring: IrqMutex<BDRing<McdmaBD>>,
+}
+
+impl AxiMcdmaChannel {
+    /// Create a new channel without any buffer descriptor.
+    pub fn new(direction: Direaction, id: usize, cfg: &AxiMcdmaConfig) -> Self {
+        let common_offset = match direction {
+            Direaction::TX => AxiMcdma::MM2S_OFFSET,
+            Direaction::RX => AxiMcdma::S2MM_OFFSET,
+        };
+        Self {
+            engine: unsafe { Instance::from_base(cfg.base_address) },
+            regs: unsafe {
+                Instance::from_base(
+                    cfg.base_address
+                        + common_offset
+                        + AxiMcdma::CHANNEL_OFFSET
+                        + id * AxiMcdma::CHANNEL_STRIDE,
+                )
+            },
+            direction,
+            id,
+            has_sts_cntrl_strm: cfg.has_sts_cntrl_strm,
+            data_width: match direction {
+                Direaction::TX => cfg.mm2s_data_width,
+                Direaction::RX => cfg.s2mm_data_width,
+            },
+            max_transfer_len: (1usize << cfg.sg_length_width) - 1,
+            ring: IrqMutex::new(BDRing::new()),
+        }
+    }
+
+    /// The index of this channel
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Creates and setup the BD ring.
+    pub fn create(&self, bd_count: usize) -> AxiDMAResult {
+        self.ring.lock().create(bd_count, || {
+            McdmaBD::new(self.has_sts_cntrl_strm, (self.data_width / 8) as _)
+        })
+    }
+
+    /// Set interrupt coalescing parameters for the given descriptor ring channel.
+    /// Only supported interrupt threshold count not timer delay
+    /// The valid threshold is 1 ~ 255
+    pub fn set_coalesce(&self, threshold: usize) -> AxiDMAResult {
+        if threshold == 0 || threshold > 0xff {
+            error!("invalid coalescing threshold {}", threshold);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        self.hardware()
+            .cr()
+            .modify(|_, w| unsafe { w.irq_threshold().bits(threshold as _) });
+        Ok(())
+    }
+
+    /// Get the interrupt threshold count
+    pub fn get_coalesce(&self) -> usize {
+        self.hardware().cr().read().irq_threshold().bits() as _
+    }
+
+    /// Submit a buffer to ring, the sideband is only used by the tx channel.
+    pub fn submit(&self, buffer: BufPtr, sideband: McdmaSideband) -> Result<BufPtr, AxiDMAErr> {
+        if buffer.len() > self.max_transfer_len {
+            error!("single buffer len has exceed max transfer len");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        self.ring.lock().submit(|bd| {
+            bd.set_buf(&buffer)?;
+            if let Direaction::TX = self.direction {
+                bd.set_sideband(sideband.tid, sideband.tdest, sideband.tuser);
+            }
+            Ok(())
+        })?;
+        Ok(buffer)
+    }
+
+    /// Retrieve the BD from hardware
+    pub fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        let mut ring = self.ring.lock();
+        compiler_fence(SeqCst);
+        fence(SeqCst);
+        io_fence();
+        Ok(ring.from_hw())
+    }
+
+    /// Start a transfer. The channel fetches the BDs only if it is enabled
+    /// and the engine of its direction is running.
+    pub fn to_hw(&self) -> AxiDMAResult {
+        let mut ring = self.ring.lock();
+        if ring.is_halted {
+            let addr = ring.head_desc_addr();
+            self.update_cur_bd(addr);
+            trace!("mcdma::to_hw: ch{} cur desc addr: 0x{:x}", self.id, addr);
+        }
+        compiler_fence(SeqCst);
+        fence(SeqCst);
+        io_fence();
+        self.hardware().cr().modify(|_, w| w.fetch().set_bit());
+        self.engine_ccr().modify(|_, w| w.run_stop().set_bit());
+        if let Some(addr) = ring.to_hw() {
+            // update tail desc
+            self.update_tail_bd(addr);
+        }
+        Ok(())
+    }
+
+    /// Check out whether the channel has free block descriptor
+    pub fn has_free_bd(&self) -> bool {
+        self.ring.lock().has_free_bd()
+    }
+
+    /// Wait the channel completing a transaction synchronously.
+    pub fn wait(&self) {
+        let mut status = self.hardware().sr().read();
+        while status.idle().bit_is_clear()
+            && status.ioc_irq().bit_is_clear()
+            && status.dly_irq().bit_is_clear()
+            && status.err_irq().bit_is_clear()
+        {
+            status = self.hardware().sr().read();
+        }
+    }
+
+    /// Disable the interrupt of this channel.
+    pub fn intr_disable(&self) {
+        trace!("mcdma ch{} intr_disable", self.id);
+        self.hardware().cr().modify(|_, w| {
+            w.dly_irq_en()
+                .clear_bit()
+                .err_irq_en()
+                .clear_bit()
+                .ioc_irq_en()
+                .clear_bit()
+        });
+    }
+
+    /// Enable the interrupt of this channel.
+    pub fn intr_enable(&self) {
+        trace!("mcdma ch{} intr_enable", self.id);
+        self.hardware().cr().modify(|_, w| {
+            w.dly_irq_en()
+                .set_bit()
+                .err_irq_en()
+                .set_bit()
+                .ioc_irq_en()
+                .set_bit()
+        });
+    }
+
+    /// Check whether a transaction is completed
+    pub fn check_cmplt(&self) -> bool {
+        let status = self.hardware().sr().read();
+        status.err_irq().bit_is_set()
+            || status.dly_irq().bit_is_set()
+            || status.ioc_irq().bit_is_set()
+    }
+
+    /// The interrupt handler
+    pub fn intr_handler(&self) -> AxiDMAResult {
+        let sr = self.hardware().sr();
+        let status = sr.read();
+        if status.err_irq().bit_is_set() {
+            trace!("mcdma_intr: ch{} err intr detected", self.id);
+            self.dump_regs();
+            sr.modify(|_, w| w.err_irq().set_bit());
+            return Err(AxiDMAErr::IntrErr);
+        }
+        if status.ioc_irq().bit_is_set() {
+            trace!("mcdma_intr: ch{} cplt intr detected", self.id);
+            sr.modify(|_, w| w.ioc_irq().set_bit());
+        }
+        if status.dly_irq().bit_is_set() {
+            trace!("mcdma_intr: ch{} dly intr detected", self.id);
+            sr.modify(|_, w| w.dly_irq().set_bit());
+        }
+        Ok(())
+    }
+
+    /// Dump the register of channel
+    pub fn dump_regs(&self) {
+        let hw = self.hardware();
+        info!(
+            "CH{} CR: 0b{:b}, SR: 0b{:b}",
+            self.id,
+            hw.cr().read().bits(),
+            hw.sr().read().bits()
+        );
+        info!(
+            "CDESC_MSB: 0x{:x}, CDESC: 0x{:x}",
+            hw.curdesc_msb().read().bits(),
+            hw.curdesc().read().bits()
+        );
+        info!(
+            "TDESC_MSB: 0x{:x}, TDESC: 0x{:x}",
+            hw.taildesc_msb().read().bits(),
+            hw.taildesc().read().bits()
+        );
+    }
+
+    /// Take a snapshot of the registers and the ring of this channel
+    pub fn snapshot(&self) -> ChannelSnapshot {
+        let hw = self.hardware();
+        let ring = self.ring.lock();
+        ChannelSnapshot {
+            dmacr: hw.cr().read().bits(),
+            dmasr: hw.sr().read().bits(),
+            curdesc: ((hw.curdesc_msb().read().bits() as u64) << 32)
+                | hw.curdesc().read().bits() as u64,
+            taildesc: ((hw.taildesc_msb().read().bits() as u64) << 32)
+                | hw.taildesc().read().bits() as u64,
+            free_cnt: ring.free_cnt,
+            pending_cnt: ring.pending_cnt,
+            submit_cnt: ring.submit_cnt,
+        }
+    }
+
+    /// Get the registers of the channel
+    #[inline]
+    fn hardware(&self) -> &mcdma_channel::RegisterBlock {
+        &self.regs
+    }
+
+    /// Get the Common Control register of the channel direction
+    #[inline]
+    fn engine_ccr(&self) -> &mcdma::Mm2sCcr {
+        match self.direction {
+            Direaction::TX => self.engine.mm2s_ccr(),
+            Direaction::RX => self.engine.s2mm_ccr(),
+        }
+    }
+
+    /// Update the current buffer descriptor of the channel
+    fn update_cur_bd(&self, addr: usize) {
+        let addr_lsb = ((addr & 0xFFFF_FFFF) >> 6) as _;
+        let addr_msb = (addr >> 32) as _;
+        unsafe {
+            self.hardware()
+                .curdesc()
+                .write(|w| w.curdesc_ptr().bits(addr_lsb));
+            self.hardware()
+                .curdesc_msb()
+                .write(|w| w.curdesc_ptr().bits(addr_msb));
+        }
+    }
+
+    /// Update the tail buffer descriptor of the channel
+    fn update_tail_bd(&self, addr: usize) {
+        let addr_lsb = ((addr & 0xFFFF_FFFF) >> 6) as _;
+        let addr_msb = (addr >> 32) as _;
+        unsafe {
+            self.hardware()
+                .taildesc()
+                .write(|w| w.taildesc_ptr().bits(addr_lsb));
+            self.hardware()
+                .taildesc_msb()
+                .write(|w| w.taildesc_ptr().bits(addr_msb));
+        }
+    }
+}
+
+impl TransferChannel for AxiMcdmaChannel {
+    fn wait(&self) {
+        AxiMcdmaChannel::wait(self)
+    }
+
+    fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        AxiMcdmaChannel::from_hw(self)
+    }
+
+    fn intr_handler(&self) -> AxiDMAResult {
+        AxiMcdmaChannel::intr_handler(self)
+    }
+}
+
+/// The AxiMcdma driver instance structure. An instance must be allocated for each
+/// MCDMA engine in use.
+pub struct AxiMcdma {
+    // Immutable
+    /// The registers of the AxiMCDMA
+    regs: Instance<mcdma::RegisterBlock>,
+
+    // Mutable
+    /// Whether the AxiMCDMA is initialized
+    is_initialized: AtomicBool,
+    /// The tx channels, indexed by the channel id
+    pub tx_channels: Vec<Arc<AxiMcdmaChannel>>,
+    /// The rx channels, indexed by the channel id
+    pub rx_channels: Vec<Arc<AxiMcdmaChannel>>,
+}
+
+impl AxiMcdma {
+    /// Reset time out
+    const RESET_TIMEOUT: Duration = Duration::from_millis(10);
+    /// The maximum number of channels of each direction
+    pub const MAX_CHANNELS: usize = 16;
+    /// The address offset of the MM2S common registers
+    const MM2S_OFFSET: usize = 0;
+    /// The address offset of the S2MM common registers
+    const S2MM_OFFSET: usize = 0x500;
+    /// The address offset of the first channel behind the common registers
+    const CHANNEL_OFFSET: usize = 0x40;
+    /// The size of the register space of a channel
+    const CHANNEL_STRIDE: usize = 0x40;
+
+    /// Create the AxiMCDMA instance according to the configuration. Each
+    /// enabled direction must have 1 ~ [`AxiMcdma::MAX_CHANNELS`] channels.
+    pub fn new(cfg: AxiMcdmaConfig) -> Result<Self, AxiDMAErr> {
+        let tx_num = if cfg.has_mm2s {
+            cfg.mm2s_num_channels
+        } else {
+            0
+        };
+        let rx_num = if cfg.has_s2mm {
+            cfg.s2mm_num_channels
+        } else {
+            0
+        };
+        for (name, enabled, num) in [("tx", cfg.has_mm2s, tx_num), ("rx", cfg.has_s2mm, rx_num)] {
+            if enabled && !(1..=Self::MAX_CHANNELS).contains(&num) {
+                error!("mcdma::new: invalid number of {} channels {}", name, num);
+                return Err(AxiDMAErr::InValidParam);
+            }
+        }
+        let tx_channels = (0..tx_num)
+            .map(|id| Arc::new(AxiMcdmaChannel::new(Direaction::TX, id, &cfg)))
+            .collect();
+        let rx_channels = (0..rx_num)
+            .map(|id| Arc::new(AxiMcdmaChannel::new(Direaction::RX, id, &cfg)))
+            .collect();
+        Ok(Self {
+            regs: unsafe { Instance::from_base(cfg.base_address) },
+            is_initialized: AtomicBool::new(false),
+            tx_channels,
+            rx_channels,
+        })
+    }
+
+    /// Get the registers of the AxiMCDMA
+    #[inline]
+    fn hardware(&self) -> &mcdma::RegisterBlock {
+        &self.regs
+    }
+
+    /// Fail with `NotInit` if the AxiMCDMA has not been reset, `op` names the
+    /// operation in the log
+    fn check_initialized(&self, op: &str) -> AxiDMAResult {
+        if !self.is_initialized.load(Ordering::Relaxed) {
+            error!("{}: Driver not initialized", op);
+            return Err(AxiDMAErr::NotInit);
+        }
+        Ok(())
+    }
+
+    /// Reset the AxiMCDMA, both directions are reset together.
+    pub fn reset(self: &Arc<Self>) -> AxiDMAResult {
+        let hw = self.hardware();
+        hw.mm2s_ccr().modify(|_, w| w.reset().set_bit());
+        let mut deadline = Deadline::after(AxiMcdma::RESET_TIMEOUT);
+        while hw.mm2s_ccr().read().reset().bit_is_set() || hw.s2mm_ccr().read().reset().bit_is_set()
+        {
+            if deadline.expired() {
+                error!("AXIMCDMA: failed reset in intialization");
+                return Err(AxiDMAErr::Timeout(self.engine_snapshot()));
+            }
+            core::hint::spin_loop();
+        }
+        for channel in self.tx_channels.iter().chain(self.rx_channels.iter()) {
+            channel.ring.lock().is_halted = true;
+        }
+        self.is_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Take a snapshot of the common registers of the direction whose reset
+    /// is not done, or of the MM2S direction. It has no CURDESC, TAILDESC and
+    /// ring, see [`AxiMcdmaChannel::snapshot`] for a channel.
+    pub fn engine_snapshot(&self) -> ChannelSnapshot {
+        let hw = self.hardware();
+        if hw.s2mm_ccr().read().reset().bit_is_set() && !hw.mm2s_ccr().read().reset().bit_is_set() {
+            ChannelSnapshot::registers(
+                hw.s2mm_ccr().read().bits(),
+                hw.s2mm_csr().read().bits(),
+                0,
+                0,
+            )
+        } else {
+            ChannelSnapshot::registers(
+                hw.mm2s_ccr().read().bits(),
+                hw.mm2s_csr().read().bits(),
+                0,
+                0,
+            )
+        }
+    }
+
+    /// Start the AxiMCDMA
+    pub fn start(self: &Arc<Self>) -> AxiDMAResult {
+        self.check_initialized("Start")?;
+        let hw = self.hardware();
+        if !self.tx_channels.is_empty() {
+            hw.mm2s_ccr().modify(|_, w| w.run_stop().set_bit());
+        }
+        if !self.rx_channels.is_empty() {
+            hw.s2mm_ccr().modify(|_, w| w.run_stop().set_bit());
+        }
+        Ok(())
+    }
+
+    /// Pause the AxiMCDMA
+    pub fn pause(self: &Arc<Self>) -> AxiDMAResult {
+        self.check_initialized("Pause")?;
+        let hw = self.hardware();
+        hw.mm2s_ccr().modify(|_, w| w.run_stop().clear_bit());
+        hw.s2mm_ccr().modify(|_, w| w.run_stop().clear_bit());
+        for channel in self.tx_channels.iter().chain(self.rx_channels.iter()) {
+            channel.ring.lock().is_halted = true;
+        }
+        Ok(())
+    }
+
+    /// Enable the tx channels whose bits are set in `mask`
+    pub fn tx_channel_enable(self: &Arc<Self>, mask: u16) -> AxiDMAResult {
+        let mask = self.channel_mask(&self.tx_channels, mask)?;
+        self.hardware()
+            .mm2s_chen()
+            .modify(|r, w| unsafe { w.chen().bits(r.chen().bits() | mask) });
+        Ok(())
+    }
+
+    /// Disable the tx channels whose bits are set in `mask`
+    pub fn tx_channel_disable(self: &Arc<Self>, mask: u16) -> AxiDMAResult {
+        let mask = self.channel_mask(&self.tx_channels, mask)?;
+        self.hardware()
+            .mm2s_chen()
+            .modify(|r, w| unsafe { w.chen().bits(r.chen().bits() & !mask) });
+        Ok(())
+    }
+
+    /// Enable the rx channels whose bits are set in `mask`
+    pub fn rx_channel_enable(self: &Arc<Self>, mask: u16) -> AxiDMAResult {
+        let mask = self.channel_mask(&self.rx_channels, mask)?;
+        self.hardware()
+            .s2mm_chen()
+            .modify(|r, w| unsafe { w.chen().bits(r.chen().bits() | mask) });
+        Ok(())
+    }
+
+    /// Disable the rx channels whose bits are set in `mask`
+    pub fn rx_channel_disable(self: &Arc<Self>, mask: u16) -> AxiDMAResult {
+        let mask = self.channel_mask(&self.rx_channels, mask)?;
+        self.hardware()
+            .s2mm_chen()
+            .modify(|r, w| unsafe { w.chen().bits(r.chen().bits() & !mask) });
+        Ok(())
+    }
+
+    /// Set the order in which the enabled tx channels are serviced
+    pub fn set_tx_schedule(self: &Arc<Self>, schedule: McdmaSchedule) {
+        self.hardware()
+            .mm2s_ch_schd_type()
+            .write(|w| unsafe { w.schd_type().bits(schedule as _) });
+    }
+
+    /// Set the weight of a tx channel used by the weighted round robin scheduler.
+    /// The valid weight is 0 ~ 15
+    pub fn set_tx_weight(self: &Arc<Self>, channel: usize, weight: u8) -> AxiDMAResult {
+        if channel >= self.tx_channels.len() || weight > 0xf {
+            error!("invalid weight {} for tx channel {}", weight, channel);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let hw = self.hardware();
+        let shift = (channel % 8) * 4;
+        let set = |bits: u32| (bits & !(0xf << shift)) | ((weight as u32) << shift);
+        if channel < 8 {
+            hw.mm2s_wrr_reg1()
+                .modify(|r, w| unsafe { w.bits(set(r.bits())) });
+        } else {
+            hw.mm2s_wrr_reg2()
+                .modify(|r, w| unsafe { w.bits(set(r.bits())) });
+        }
+        Ok(())
+    }
+
+    /// The tx channels which have a pending interrupt
+    pub fn tx_intr_status(self: &Arc<Self>) -> u16 {
+        self.hardware()
+            .mm2s_intr_status()
+            .read()
+            .intr_status()
+            .bits()
+    }
+
+    /// The rx channels which have a pending interrupt
+    pub fn rx_intr_status(self: &Arc<Self>) -> u16 {
+        self.hardware()
+            .s2mm_intr_status()
+            .read()
+            .intr_status()
+            .bits()
+    }
+
+    /// The interrupt handler of the tx direction. It calls the handler of every
+    /// tx channel which has a pending interrupt.
+    pub fn tx_intr_handler(self: &Arc<Self>) -> AxiDMAResult {
+        Self::dispatch_intr(&self.tx_channels, self.tx_intr_status())
+    }
+
+    /// The interrupt handler of the rx direction. It calls the handler of every
+    /// rx channel which has a pending interrupt.
+    pub fn rx_intr_handler(self: &Arc<Self>) -> AxiDMAResult {
+        Self::dispatch_intr(&self.rx_channels, self.rx_intr_status())
+    }
+
+    /// Disable the interrupt of all channels
+    pub fn intr_disable(self: &Arc<Self>) {
+        for channel in self.tx_channels.iter().chain(self.rx_channels.iter()) {
+            channel.intr_disable();
+        }
+    }
+
+    /// Enable the interrupt of all channels
+    pub fn intr_enable(self: &Arc<Self>) {
+        for channel in self.tx_channels.iter().chain(self.rx_channels.iter()) {
+            channel.intr_enable();
+        }
+    }
+
+    /// Initialize a tx channel
+    pub fn tx_channel_create(self: &Arc<Self>, channel: usize, bd_count: usize) -> AxiDMAResult {
+        self.check_initialized("Create tx channel")?;
+        if let Some(tx_channel) = self.tx_channels.get(channel) {
+            tx_channel.intr_disable();
+            tx_channel.create(bd_count)?;
+            return Ok(());
+        }
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Initialize a rx channel
+    pub fn rx_channel_create(self: &Arc<Self>, channel: usize, bd_count: usize) -> AxiDMAResult {
+        self.check_initialized("Create rx channel")?;
+        if let Some(rx_channel) = self.rx_channels.get(channel) {
+            rx_channel.intr_disable();
+            rx_channel.create(bd_count)?;
+            return Ok(());
+        }
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to a tx channel, the packet is sent with the given sideband
+    pub fn tx_submit(
+        self: &Arc<Self>,
+        channel: usize,
+        buffer: BufPtr,
+        sideband: McdmaSideband,
+    ) -> Result<Transfer<AxiMcdmaChannel>, AxiDMAErr> {
+        self.check_initialized("Tx submit")?;
+        if let Some(tx_channel) = self.tx_channels.get(channel) {
+            let transfer = Transfer::new(tx_channel.submit(buffer, sideband)?, tx_channel.clone());
+            tx_channel.to_hw()?;
+            return Ok(transfer);
+        }
+        error!("mcdma::tx_submit: no tx ring {}!", channel);
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    /// Submit a buffer to a rx channel
+    pub fn rx_submit(
+        self: &Arc<Self>,
+        channel: usize,
+        buffer: BufPtr,
+    ) -> Result<Transfer<AxiMcdmaChannel>, AxiDMAErr> {
+        self.check_initialized("Rx submit")?;
+        if let Some(rx_channel) = self.rx_channels.get(channel) {
+            let transfer = Transfer::new(
+                rx_channel.submit(buffer, McdmaSideband::default())?,
+                rx_channel.clone(),
+            );
+            rx_channel.to_hw()?;
+            return Ok(transfer);
+        }
+        error!("mcdma::rx_submit: no rx ring {}!", channel);
+        Err(AxiDMAErr::BDRingNoList)
+    }
+
+    // Check the channel mask against the existing channels
+    fn channel_mask(&self, channels: &[Arc<AxiMcdmaChannel>], mask: u16) -> Result<u16, AxiDMAErr> {
+        let valid = ((1u32 << channels.len()) - 1) as u16;
+        if mask & !valid != 0 {
+            error!("invalid channel mask 0x{:x}, valid: 0x{:x}", mask, valid);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        Ok(mask)
+    }
+
+    // Call the handler of every channel whose bit is set in `status`
+    fn dispatch_intr(channels: &[Arc<AxiMcdmaChannel>], status: u16) -> AxiDMAResult {
+        let mut result = Ok(());
+        for channel in channels.iter() {
+            if status & (1 << channel.id()) != 0 {
+                if let Err(e) = channel.intr_handler() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use core::ptr::NonNull;
+
+    /// A zeroed memory standing in for the registers of the MCDMA
+    #[repr(C, align(64))]
+    struct FakeRegs([u32; 0x400]);
+
+    fn config(base_address: usize, tx: usize, rx: usize) -> AxiMcdmaConfig {
+        AxiMcdmaConfig {
+            base_address,
+            has_sts_cntrl_strm: false,
+            has_mm2s: tx > 0,
+            mm2s_num_channels: tx,
+            mm2s_data_width: 32,
+            has_s2mm: rx > 0,
+            s2mm_num_channels: rx,
+            s2mm_data_width: 32,
+            sg_length_width: 14,
+            addr_width: 32,
+        }
+    }
+
+    /// An MCDMA with `tx` and `rx` channels on fake registers, which must
+    /// outlive it
+    fn mcdma(tx: usize, rx: usize) -> (Box<FakeRegs>, Arc<AxiMcdma>) {
+        let regs = Box::new(FakeRegs([0; 0x400]));
+        let base_address = &*regs as *const _ as usize;
+        let mcdma = AxiMcdma::new(config(base_address, tx, rx)).unwrap();
+        (regs, Arc::new(mcdma))
+    }
+
+    #[test]
+    fn rejects_invalid_channel_numbers() {
+        assert!(AxiMcdma::new(config(0, 17, 1)).is_err());
+        assert!(AxiMcdma::new(config(0, 1, 17)).is_err());
+        let mut cfg = config(0, 1, 1);
+        cfg.mm2s_num_channels = 0;
+        assert!(AxiMcdma::new(cfg).is_err());
+
+        let mcdma = AxiMcdma::new(config(0, 16, 0)).unwrap();
+        assert_eq!(mcdma.tx_channels.len(), 16);
+        assert!(mcdma.rx_channels.is_empty());
+    }
+
+    #[test]
+    fn channel_mask_covers_the_existing_channels() {
+        let (_regs, mcdma) = mcdma(3, 16);
+        assert_eq!(
+            mcdma.channel_mask(&mcdma.tx_channels, 0b111).unwrap(),
+            0b111
+        );
+        assert!(mcdma.channel_mask(&mcdma.tx_channels, 0b1000).is_err());
+        assert_eq!(
+            mcdma.channel_mask(&mcdma.rx_channels, 0xffff).unwrap(),
+            0xffff
+        );
+
+        mcdma.tx_channel_enable(0b101).unwrap();
+        mcdma.tx_channel_disable(0b001).unwrap();
+        assert_eq!(mcdma.hardware().mm2s_chen().read().chen().bits(), 0b100);
+        assert!(mcdma.tx_channel_enable(0b1000).is_err());
+    }
+
+    #[test]
+    fn wrr_weights_are_bounded() {
+        let (_regs, mcdma) = mcdma(16, 0);
+        assert!(mcdma.set_tx_weight(0, 16).is_err());
+        assert!(mcdma.set_tx_weight(16, 1).is_err());
+
+        mcdma.set_tx_weight(1, 0xf).unwrap();
+        mcdma.set_tx_weight(7, 0x3).unwrap();
+        mcdma.set_tx_weight(9, 0x5).unwrap();
+        let hw = mcdma.hardware();
+        assert_eq!(hw.mm2s_wrr_reg1().read().bits(), 0x3000_00f0);
+        assert_eq!(hw.mm2s_wrr_reg2().read().bits(), 0x0000_0050);
+
+        // Another weight of the same channel replaces the old one
+        mcdma.set_tx_weight(1, 0x2).unwrap();
+        assert_eq!(hw.mm2s_wrr_reg1().read().bits(), 0x3000_0020);
+    }
+
+    #[test]
+    fn sideband_fields_of_a_bd() {
+        let bd = McdmaBD::new(false, 4);
+        bd.set_sideband(0xa5, 0x1f, 0xbeef);
+        assert_eq!(bd.sideband(), (0xa5, 0x1f, 0xbeef));
+        // TDEST only has 5 bits, the others are not touched
+        bd.set_sideband(0x01, 0x3f, 0);
+        assert_eq!(bd.desc.sideband().read().bits(), 0x011f_0000);
+    }
+
+    #[test]
+    fn only_tx_bds_carry_the_sideband() {
+        let (_regs, mcdma) = mcdma(1, 1);
+        let mut data = [0u32; 4];
+        let buffer = BufPtr::new(NonNull::from(&mut data).cast(), 16);
+        let sideband = McdmaSideband {
+            tid: 1,
+            tdest: 2,
+            tuser: 3,
+        };
+        for channel in [&mcdma.tx_channels[0], &mcdma.rx_channels[0]] {
+            channel.create(2).unwrap();
+            channel.submit(buffer.clone(), sideband).unwrap();
+        }
+        assert_eq!(
+            mcdma.tx_channels[0].ring.lock().bds[0].sideband(),
+            (1, 2, 3)
+        );
+        assert_eq!(
+            mcdma.rx_channels[0].ring.lock().bds[0].sideband(),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn buffers_are_aligned_with_the_data_width() {
+        let (_regs, mcdma) = mcdma(1, 0);
+        let channel = &mcdma.tx_channels[0];
+        channel.create(2).unwrap();
+        let mut data = [0u32; 4];
+        let unaligned = NonNull::from(&mut data)
+            .cast::<u8>()
+            .as_ptr()
+            .wrapping_add(2);
+        let buffer = BufPtr::new(NonNull::new(unaligned).unwrap(), 8);
+        assert!(channel.submit(buffer, McdmaSideband::default()).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+//! The interrupt-then-poll mode of an AxiDMA channel.
+//!
+//! In this mode, `AxiDMAChannel::intr_handler` does not retrieve any BD. On
+//! the first IOC or delay interrupt it disables the interrupts of the channel
+//! and schedules the channel for polling. The thread context then calls
+//! `AxiDMAChannel::poll` with a budget of packets until it returns less than
+//! the budget, which means the ring is drained and the interrupts are enabled
+//! again.
+//!
+//! At the end of every polling round, the interrupt threshold and delay of the
+//! channel are tuned from the number of packets completed during the round:
+//! a round completing at least twice the threshold doubles it, a round
+//! completing less than the threshold (i.e. woken by the delay timer) halves
+//! it. The delay follows the threshold linearly within its bounds.
+//!
+
+use crate::{AxiDMAErr, AxiDMAResult};
+
+/// The bounds of the adaptive interrupt coalescing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NapiConfig {
+    /// The minimum interrupt threshold, 1 ~ 255
+    pub threshold_min: u8,
+    /// The maximum interrupt threshold, 1 ~ 255
+    pub threshold_max: u8,
+    /// The interrupt delay used with the minimum threshold
+    pub delay_min: u8,
+    /// The interrupt delay used with the maximum threshold
+    pub delay_max: u8,
+}
+
+impl NapiConfig {
+    /// Check the bounds. The delay timer must be on whenever the threshold may
+    /// be more than 1, otherwise the last packets of a burst never interrupt.
+    pub fn validate(&self) -> AxiDMAResult {
+        if self.threshold_min == 0 || self.threshold_min > self.threshold_max {
+            error!(
+                "axidma::napi: invalid threshold bounds {} ~ {}",
+                self.threshold_min, self.threshold_max
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        if self.delay_min > self.delay_max {
+            error!(
+                "axidma::napi: invalid delay bounds {} ~ {}",
+                self.delay_min, self.delay_max
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        if self.threshold_max > 1 && self.delay_min == 0 {
+            error!("axidma::napi: the delay timer is required by a threshold above 1");
+            return Err(AxiDMAErr::InValidParam);
+        }
+        Ok(())
+    }
+}
+
+impl Default for NapiConfig {
+    fn default() -> Self {
+        Self {
+            threshold_min: 1,
+            threshold_max: 64,
+            delay_min: 1,
+            delay_max: 16,
+        }
+    }
+}
+
+/// The state of the polling mode, only accessed with its lock held
+pub(crate) struct NapiState {
+    pub config: NapiConfig,
+    /// Whether the interrupts are disabled and the channel waits for polling
+    pub scheduled: bool,
+    /// The current interrupt threshold
+    pub threshold: u8,
+    /// The number of packets completed during the current round
+    pub round_pkts: usize,
+}
+
+impl NapiState {
+    pub fn new(config: NapiConfig) -> Self {
+        Self {
+            config,
+            scheduled: false,
+            threshold: config.threshold_min,
+            round_pkts: 0,
+        }
+    }
+
+    /// The interrupt delay matching the current threshold
+    pub fn delay(&self) -> u8 {
+        let c = &self.config;
+        let span = (c.threshold_max - c.threshold_min) as usize;
+        if span == 0 {
+            return c.delay_min;
+        }
+        let pos = (self.threshold - c.threshold_min) as usize;
+        let delay = c.delay_min as usize + (c.delay_max - c.delay_min) as usize * pos / span;
+        delay as u8
+    }
+
+    /// Count the `pkts` retrieved by a poll of `budget`. The round goes on
+    /// while the budget is exhausted, it returns `None` then. Otherwise the
+    /// round ends, the interrupts must be enabled again and it returns whether
+    /// the threshold is changed.
+    pub fn end_poll(&mut self, pkts: usize, budget: usize) -> Option<bool> {
+        self.round_pkts += pkts;
+        if pkts >= budget || !self.scheduled {
+            return None;
+        }
+        Some(self.end_round())
+    }
+
+    /// End the current round and tune the threshold. It returns whether the
+    /// threshold is changed.
+    pub fn end_round(&mut self) -> bool {
+        let pkts = core::mem::take(&mut self.round_pkts);
+        let threshold = self.threshold as usize;
+        let tuned = if pkts >= threshold * 2 {
+            (threshold * 2).min(self.config.threshold_max as usize)
+        } else if pkts < threshold {
+            (threshold / 2).max(self.config.threshold_min as usize)
+        } else {
+            threshold
+        };
+        self.scheduled = false;
+        if tuned == threshold {
+            return false;
+        }
+        trace!(
+            "axidma::napi: {} packets in round, threshold {} -> {}",
+            pkts,
+            threshold,
+            tuned
+        );
+        self.threshold = tuned as u8;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheduled() -> NapiState {
+        let mut state = NapiState::new(NapiConfig::default());
+        state.scheduled = true;
+        state
+    }
+
+    #[test]
+    fn exhausted_budget_keeps_polling() {
+        let mut state = scheduled();
+        assert_eq!(state.end_poll(4, 4), None);
+        assert!(state.scheduled);
+        // The ring is drained, the interrupts are enabled with the threshold
+        // tuned from the whole round
+        assert_eq!(state.end_poll(2, 4), Some(true));
+        assert!(!state.scheduled);
+        assert_eq!(state.threshold, 2);
+        assert_eq!(state.round_pkts, 0);
+    }
+
+    #[test]
+    fn unscheduled_poll_does_not_end_the_round() {
+        let mut state = NapiState::new(NapiConfig::default());
+        assert_eq!(state.end_poll(1, 4), None);
+        assert_eq!(state.round_pkts, 1);
+    }
+
+    #[test]
+    fn threshold_follows_the_round_within_bounds() {
+        let mut state = scheduled();
+        state.threshold = 64;
+        assert_eq!(state.end_poll(200, 256), Some(false));
+        assert_eq!(state.delay(), 16);
+        state.scheduled = true;
+        assert_eq!(state.end_poll(10, 256), Some(true));
+        assert_eq!(state.threshold, 32);
+        state.threshold = 1;
+        state.scheduled = true;
+        assert_eq!(state.end_poll(0, 256), Some(false));
+        assert_eq!(state.delay(), 1);
+    }
+}
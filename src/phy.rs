@@ -0,0 +1,288 @@
+//! The [smoltcp](https://docs.rs/smoltcp) `phy::Device` adapter over the TX/RX
+//! channels of an AxiDMA connected to an AXI Ethernet MAC.
+//!
+//! A fixed number of RX buffers is pre-posted to the rx channel, every received
+//! frame is handed to smoltcp through a `RxToken` and its buffer is posted again
+//! once the frame has been consumed. The TX buffers are recycled from the
+//! completed transmissions, so no buffer is allocated after the creation.
+//!
+//! The channels must have been created and the AxiDMA must have been started
+//! before the adapter is created.
+//!
+
+use crate::channel::AxiDMAChannel;
+use crate::{AxiDMAErr, AxiDma, AxiDmaConfig, BufPtr, EthTxCsum};
+use alloc::{boxed::Box, collections::VecDeque, sync::Arc, vec, vec::Vec};
+use core::ptr::NonNull;
+use smoltcp::phy::{self, Checksum, ChecksumCapabilities, DeviceCapabilities, Medium};
+use smoltcp::time::Instant;
+
+/// The max length of a standard Ethernet frame without FCS
+pub const ETH_FRAME_LEN: usize = 1514;
+
+/// Allocate a buffer which is aligned with 8 bytes
+fn alloc_buf(len: usize) -> BufPtr {
+    let buf: Box<[u64]> = vec![0u64; len.div_ceil(8)].into_boxed_slice();
+    let ptr = Box::into_raw(buf) as *mut u8;
+    BufPtr::new(unsafe { NonNull::new_unchecked(ptr) }, len)
+}
+
+/// Free a buffer allocated by [`alloc_buf`]
+fn free_buf(mut buf: BufPtr) {
+    let words = buf.len().div_ceil(8);
+    let ptr = buf.as_mut_ptr() as *mut u64;
+    let _buf = unsafe { Box::from_raw(core::ptr::slice_from_raw_parts_mut(ptr, words)) };
+}
+
+/// The RX side of the adapter
+struct RxPool {
+    /// The rx channel
+    channel: Arc<AxiDMAChannel>,
+    /// The buffers posted to the rx channel, in the order of completion
+    posted: VecDeque<BufPtr>,
+    /// The length of the received frames at the front of `posted`
+    received: VecDeque<usize>,
+}
+
+impl RxPool {
+    /// Retrieve the received frames from the rx channel
+    fn poll(&mut self) {
+        let received = &mut self.received;
+        if let Err(e) = self.channel.from_hw_with(|len, _| received.push_back(len)) {
+            error!("axidma::phy: failed to retrieve rx frames {:?}", e);
+        }
+    }
+
+    /// Post a buffer to the rx channel, the buffer is freed if it cannot be posted.
+    fn post(&mut self, buf: BufPtr) -> Result<(), AxiDMAErr> {
+        if let Err(e) = self.channel.submit(buf.clone()) {
+            free_buf(buf);
+            return Err(e);
+        }
+        self.posted.push_back(buf);
+        self.channel.to_hw()
+    }
+}
+
+/// The TX side of the adapter
+struct TxPool {
+    /// The tx channel
+    channel: Arc<AxiDMAChannel>,
+    /// The buffers ready to be filled
+    free: Vec<BufPtr>,
+    /// The buffers submitted to the tx channel, in the order of completion
+    inflight: VecDeque<BufPtr>,
+    /// Whether the checksum is computed by the AXI Ethernet
+    csum_offload: bool,
+}
+
+impl TxPool {
+    /// Recycle the buffers of the completed transmissions
+    fn poll(&mut self) {
+        let mut completed = 0;
+        if let Err(e) = self.channel.from_hw_with(|_, _| completed += 1) {
+            error!("axidma::phy: failed to retrieve tx frames {:?}", e);
+        }
+        for _ in 0..completed {
+            if let Some(buf) = self.inflight.pop_front() {
+                self.free.push(buf);
+            }
+        }
+    }
+
+    /// Whether a frame can be transmitted
+    fn is_ready(&self) -> bool {
+        !self.free.is_empty() && self.channel.has_free_bd()
+    }
+
+    /// Transmit the first `len` bytes of a buffer
+    fn send(&mut self, buf: BufPtr, len: usize) -> Result<(), AxiDMAErr> {
+        let frame = BufPtr::new(
+            unsafe { NonNull::new_unchecked(buf.as_ptr() as *mut u8) },
+            len,
+        );
+        let submitted = if self.csum_offload {
+            self.channel.submit_app(frame, &EthTxCsum::Full.encode())
+        } else {
+            self.channel.submit(frame)
+        };
+        if let Err(e) = submitted {
+            self.free.push(buf);
+            return Err(e);
+        }
+        self.inflight.push_back(buf);
+        self.channel.to_hw()
+    }
+}
+
+/// The smoltcp `phy::Device` over an AxiDMA.
+pub struct AxiDmaDevice {
+    /// The RX side
+    rx: RxPool,
+    /// The TX side
+    tx: TxPool,
+    /// The capabilities reported to smoltcp
+    caps: DeviceCapabilities,
+    /// Keep the AxiDMA alive while the adapter uses its channels
+    _dma: Arc<AxiDma>,
+}
+
+impl AxiDmaDevice {
+    /// Create the adapter, `rx_count` buffers are pre-posted to the rx channel
+    /// and `tx_count` buffers are allocated for the tx channel. The rings of
+    /// the channels must be able to hold them.
+    pub fn new(
+        dma: Arc<AxiDma>,
+        cfg: &AxiDmaConfig,
+        rx_count: usize,
+        tx_count: usize,
+    ) -> Result<Self, AxiDMAErr> {
+        let (rx_channel, tx_channel) = match (dma.rx_channel.as_ref(), dma.tx_channel.as_ref()) {
+            (Some(rx), Some(tx)) => (rx.clone(), tx.clone()),
+            _ => {
+                error!("axidma::phy: both the tx and the rx channel are required");
+                return Err(AxiDMAErr::InValidParam);
+            }
+        };
+        if rx_count == 0 || tx_count == 0 {
+            error!(
+                "axidma::phy: invalid buffer number, rx: {}, tx: {}",
+                rx_count, tx_count
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let caps = Self::capabilities_of(cfg);
+        let buf_len = caps.max_transmission_unit;
+        let mut rx = RxPool {
+            channel: rx_channel,
+            posted: VecDeque::with_capacity(rx_count),
+            received: VecDeque::with_capacity(rx_count),
+        };
+        for _ in 0..rx_count {
+            if let Err(e) = rx.post(alloc_buf(buf_len)) {
+                error!("axidma::phy: failed to post rx buffer {:?}", e);
+                return Err(e);
+            }
+        }
+        let tx = TxPool {
+            channel: tx_channel,
+            free: (0..tx_count).map(|_| alloc_buf(buf_len)).collect(),
+            inflight: VecDeque::with_capacity(tx_count),
+            csum_offload: cfg.has_sts_cntrl_strm,
+        };
+        Ok(Self {
+            rx,
+            tx,
+            caps,
+            _dma: dma,
+        })
+    }
+
+    /// The capabilities derived from the configuration. The MTU is limited by
+    /// the width of the buffer length field and the checksum is offloaded to
+    /// the AXI Ethernet when the status/control stream is present.
+    pub fn capabilities_of(cfg: &AxiDmaConfig) -> DeviceCapabilities {
+        let max_transfer_len = (1usize << cfg.sg_length_width) - 1;
+        let mut caps = DeviceCapabilities::default();
+        caps.medium = Medium::Ethernet;
+        caps.max_transmission_unit = core::cmp::min(max_transfer_len, ETH_FRAME_LEN);
+        if cfg.has_sts_cntrl_strm {
+            // The checksum is computed by the hardware when sending, the
+            // received frames are still verified because the hardware only
+            // reports the result.
+            let mut checksum = ChecksumCapabilities::default();
+            checksum.ipv4 = Checksum::Rx;
+            checksum.tcp = Checksum::Rx;
+            checksum.udp = Checksum::Rx;
+            caps.checksum = checksum;
+        }
+        caps
+    }
+}
+
+impl Drop for AxiDmaDevice {
+    fn drop(&mut self) {
+        // The posted and inflight buffers may still be accessed by the hardware
+        for buf in self.tx.free.drain(..) {
+            free_buf(buf);
+        }
+    }
+}
+
+/// The token of a received frame
+pub struct AxiDmaRxToken<'a> {
+    rx: &'a mut RxPool,
+    buf: BufPtr,
+    len: usize,
+}
+
+impl phy::RxToken for AxiDmaRxToken<'_> {
+    fn consume<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        let result = f(&self.buf.packet()[..self.len]);
+        if let Err(e) = self.rx.post(self.buf) {
+            error!("axidma::phy: failed to post rx buffer again {:?}", e);
+        }
+        result
+    }
+}
+
+/// The token of a frame to transmit
+pub struct AxiDmaTxToken<'a> {
+    tx: &'a mut TxPool,
+}
+
+impl phy::TxToken for AxiDmaTxToken<'_> {
+    fn consume<R, F>(self, len: usize, f: F) -> R
+    where
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        let mut buf = self
+            .tx
+            .free
+            .pop()
+            .unwrap_or_else(|| unreachable!("tx token without free buffer"));
+        let result = f(&mut buf.packet_mut()[..len]);
+        if let Err(e) = self.tx.send(buf, len) {
+            error!("axidma::phy: failed to transmit frame {:?}", e);
+        }
+        result
+    }
+}
+
+impl phy::Device for AxiDmaDevice {
+    type RxToken<'a> = AxiDmaRxToken<'a>;
+    type TxToken<'a> = AxiDmaTxToken<'a>;
+
+    fn receive(&mut self, _timestamp: Instant) -> Option<(Self::RxToken<'_>, Self::TxToken<'_>)> {
+        self.rx.poll();
+        self.tx.poll();
+        if self.rx.received.is_empty() || !self.tx.is_ready() {
+            return None;
+        }
+        let len = self.rx.received.pop_front()?;
+        let buf = self.rx.posted.pop_front()?;
+        Some((
+            AxiDmaRxToken {
+                rx: &mut self.rx,
+                buf,
+                len,
+            },
+            AxiDmaTxToken { tx: &mut self.tx },
+        ))
+    }
+
+    fn transmit(&mut self, _timestamp: Instant) -> Option<Self::TxToken<'_>> {
+        self.tx.poll();
+        if !self.tx.is_ready() {
+            return None;
+        }
+        Some(AxiDmaTxToken { tx: &mut self.tx })
+    }
+
+    fn capabilities(&self) -> DeviceCapabilities {
+        self.caps.clone()
+    }
+}
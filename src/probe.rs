@@ -0,0 +1,179 @@
+//! Probe the AxiDMA hardware and check the configuration against it.
+//!
+//! The probe resets the engine, so it must run before the engine is used.
+//! After the reset, an existing channel reports `halted` in its DMASR, while
+//! the registers of a channel which is not built into the bitstream read as 0.
+//! The `sg_incld` bit of DMASR tells whether the Scatter Gather engine is included.
+//!
+
+use crate::channel::ChannelSnapshot;
+use crate::clock::Deadline;
+use crate::{AxiDMAErr, AxiDmaConfig};
+use axidma_pac::{channel, Instance};
+use core::time::Duration;
+
+/// Reset time out of the probe
+const PROBE_RESET_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// A channel of the AxiDMA
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeChannel {
+    /// The tx (MM2S) channel
+    Tx,
+    /// The rx (S2MM) channel
+    Rx,
+}
+
+/// A mismatch between the configuration and the hardware
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxiDmaProbeErr {
+    /// The channel is in the configuration but not in the hardware
+    ChannelMissing(ProbeChannel),
+    /// The channel is in the hardware but not in the configuration
+    ChannelUnexpected(ProbeChannel),
+    /// `has_sg` of the configuration disagrees with the hardware
+    SgMismatch {
+        /// The configured value
+        expected: bool,
+        /// The value reported by the hardware
+        found: bool,
+    },
+    /// The reset of the channel does not complete, with the registers of
+    /// the channel
+    ResetTimeout(ProbeChannel, ChannelSnapshot),
+    /// No channel responds, the base address may be wrong
+    NoChannel,
+}
+
+impl From<AxiDmaProbeErr> for AxiDMAErr {
+    fn from(err: AxiDmaProbeErr) -> Self {
+        match err {
+            AxiDmaProbeErr::ResetTimeout(_, snapshot) => AxiDMAErr::Timeout(snapshot),
+            _ => AxiDMAErr::InValidParam,
+        }
+    }
+}
+
+/// The capabilities read from the hardware
+struct ProbedCaps {
+    has_mm2s: bool,
+    has_s2mm: bool,
+    has_sg: bool,
+}
+
+impl AxiDmaConfig {
+    /// Check the configuration against the hardware: whether every channel
+    /// exists, whether the Scatter Gather engine is included, and whether the
+    /// reset completes. It resets the engine.
+    pub fn probe(&self) -> Result<(), AxiDmaProbeErr> {
+        let caps = self.probe_caps()?;
+        for (channel, expected, found) in [
+            (ProbeChannel::Tx, self.has_mm2s, caps.has_mm2s),
+            (ProbeChannel::Rx, self.has_s2mm, caps.has_s2mm),
+        ] {
+            if expected && !found {
+                error!("axidma::probe: {:?} channel is missing", channel);
+                return Err(AxiDmaProbeErr::ChannelMissing(channel));
+            }
+            if !expected && found {
+                error!("axidma::probe: {:?} channel is not configured", channel);
+                return Err(AxiDmaProbeErr::ChannelUnexpected(channel));
+            }
+        }
+        if self.has_sg != caps.has_sg {
+            error!(
+                "axidma::probe: has_sg is {}, but the hardware reports {}",
+                self.has_sg, caps.has_sg
+            );
+            return Err(AxiDmaProbeErr::SgMismatch {
+                expected: self.has_sg,
+                found: caps.has_sg,
+            });
+        }
+        Ok(())
+    }
+
+    /// Fill `has_mm2s`, `has_s2mm` and `has_sg` from the hardware instead of
+    /// checking them. It resets the engine.
+    pub fn probe_fill(&mut self) -> Result<(), AxiDmaProbeErr> {
+        let caps = self.probe_caps()?;
+        self.has_mm2s = caps.has_mm2s;
+        self.has_s2mm = caps.has_s2mm;
+        self.has_sg = caps.has_sg;
+        if !caps.has_mm2s {
+            self.has_mm2s_dre = false;
+        }
+        if !caps.has_s2mm {
+            self.has_s2mm_dre = false;
+        }
+        trace!(
+            "axidma::probe: tx {}, rx {}, sg {}",
+            caps.has_mm2s,
+            caps.has_s2mm,
+            caps.has_sg
+        );
+        Ok(())
+    }
+
+    /// Get the registers of a channel
+    fn probe_hardware(&self, channel: ProbeChannel) -> Instance<channel::RegisterBlock> {
+        let offset = match channel {
+            ProbeChannel::Tx => self.tx_channel_offset,
+            ProbeChannel::Rx => self.rx_channel_offset,
+        };
+        unsafe { Instance::from_base(self.base_address + offset) }
+    }
+
+    /// Take a snapshot of the registers of a channel, the ring does not
+    /// exist yet
+    fn probe_snapshot(&self, channel: ProbeChannel) -> ChannelSnapshot {
+        let hw = self.probe_hardware(channel);
+        ChannelSnapshot::registers(
+            hw.dmacr().read().bits(),
+            hw.dmasr().read().bits(),
+            ((hw.curdesc_msb().read().bits() as u64) << 32) | hw.curdesc().read().bits() as u64,
+            ((hw.taildesc_msb().read().bits() as u64) << 32) | hw.taildesc().read().bits() as u64,
+        )
+    }
+
+    /// Reset the engine and read the capabilities of the channels
+    fn probe_caps(&self) -> Result<ProbedCaps, AxiDmaProbeErr> {
+        let channels = [ProbeChannel::Tx, ProbeChannel::Rx];
+        for channel in channels {
+            self.probe_hardware(channel)
+                .dmacr()
+                .write(|w| w.reset().reset());
+        }
+        for channel in channels {
+            let mut deadline = Deadline::after(PROBE_RESET_TIMEOUT);
+            while self
+                .probe_hardware(channel)
+                .dmacr()
+                .read()
+                .reset()
+                .is_reset()
+            {
+                if deadline.expired() {
+                    error!("axidma::probe: {:?} channel failed reset", channel);
+                    return Err(AxiDmaProbeErr::ResetTimeout(
+                        channel,
+                        self.probe_snapshot(channel),
+                    ));
+                }
+                core::hint::spin_loop();
+            }
+        }
+        let tx_sr = self.probe_hardware(ProbeChannel::Tx).dmasr().read();
+        let rx_sr = self.probe_hardware(ProbeChannel::Rx).dmasr().read();
+        let caps = ProbedCaps {
+            has_mm2s: tx_sr.halted().is_halted(),
+            has_s2mm: rx_sr.halted().is_halted(),
+            has_sg: tx_sr.sg_incld().is_sg_enabled() || rx_sr.sg_incld().is_sg_enabled(),
+        };
+        if !caps.has_mm2s && !caps.has_s2mm {
+            error!("axidma::probe: no channel at 0x{:x}", self.base_address);
+            return Err(AxiDmaProbeErr::NoChannel);
+        }
+        Ok(caps)
+    }
+}
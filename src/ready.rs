@@ -0,0 +1,84 @@
+//! The backpressure of a full BD ring.
+//!
+//! A producer either checks `AxiDMAChannel::ready` before submitting, waits
+//! for free BDs with `AxiDMAChannel::submit_blocking`, or awaits the
+//! [`SubmitReady`] future. The future registers its waker with the ring lock
+//! held, and `AxiDMAChannel::from_hw` wakes all the registered wakers once it
+//! has freed BDs, so no wakeup is lost between the check and the registration.
+//!
+
+use crate::channel::AxiDMAChannel;
+use crate::clock::Deadline;
+use crate::{AxiDMAErr, BufPtr};
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+/// The future of a buffer submitted to the ring once enough BDs are free.
+/// It resolves to the buffer as [`AxiDMAChannel::submit`], the packet is not
+/// started until `to_hw` is called.
+pub struct SubmitReady<'a> {
+    channel: &'a AxiDMAChannel,
+    /// The (address, length) of the BDs
+    chunks: Vec<(usize, usize)>,
+    buffer: Option<BufPtr>,
+    /// The end of the timeout, it is checked whenever the future is polled
+    deadline: Option<Deadline>,
+    /// Whether the full ring has been counted in the statistics
+    counted: bool,
+}
+
+impl<'a> SubmitReady<'a> {
+    pub(crate) fn new(
+        channel: &'a AxiDMAChannel,
+        chunks: Vec<(usize, usize)>,
+        buffer: BufPtr,
+    ) -> Self {
+        Self {
+            channel,
+            chunks,
+            buffer: Some(buffer),
+            deadline: None,
+            counted: false,
+        }
+    }
+
+    /// Fail if the buffer is not submitted within `timeout`. The future is
+    /// only woken when the channel retrieves BDs, no timer is registered for
+    /// the deadline, so the caller must supply one as for
+    /// [`crate::TransferTimeout`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Deadline::after(timeout));
+        self
+    }
+}
+
+impl Unpin for SubmitReady<'_> {}
+
+impl Future for SubmitReady<'_> {
+    type Output = Result<BufPtr, AxiDMAErr>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.buffer.is_none() {
+            error!("axidma::submit_ready: polled after completion");
+            return Poll::Ready(Err(AxiDMAErr::InValidParam));
+        }
+        let Self {
+            channel,
+            chunks,
+            counted,
+            ..
+        } = &mut *self;
+        match channel.poll_submit(chunks, counted, cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(self.buffer.take().ok_or(AxiDMAErr::InValidParam)),
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+            Poll::Pending if self.deadline.as_mut().is_some_and(Deadline::expired) => {
+                error!("axidma::submit_ready: no free bd in time");
+                Poll::Ready(Err(AxiDMAErr::Timeout(self.channel.snapshot())))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
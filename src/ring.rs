@@ -0,0 +1,495 @@
+//! The BD ring shared by the DMA engines with a Scatter Gather core.
+//! See [xaxidma_bdring.h](https://github.com/Xilinx/embeddedsw/blob/master/XilinxProcessorIPLib/drivers/axidma/src/xaxidma_bdring.h)
+//! for more information on how a BD ring is managed.
+//!
+//! The ring only does the bookkeeping of the BD groups. Programming the
+//! CURDESC/TAILDESC registers is left to the channel which owns the ring.
+//!
+
+use crate::bd::{AxiDmaBD, Descriptor};
+use crate::{AxiDMAErr, AxiDMAResult};
+use alloc::{boxed::Box, collections::VecDeque};
+use core::pin::Pin;
+
+/// The structure of BDRing, it must be access exclusively.
+pub struct BDRing<D = AxiDmaBD> {
+    /// Whether channel is halted
+    pub(crate) is_halted: bool,
+    /// BD ring
+    pub(crate) bds: VecDeque<Pin<Box<D>>>,
+    /// The index of first BD in the work group
+    pub(crate) bd_head: usize,
+    /// The index of last BD in the work group
+    pub(crate) bd_tail: usize,
+    /// BD to load when channel is started
+    pub(crate) bd_restart: usize,
+    /// Number of BDs in free group
+    pub(crate) free_cnt: usize,
+    /// Total Number of BDs for channel
+    pub(crate) all_cnt: usize,
+    /// Used for multiple transaction
+    pub(crate) pending_cnt: usize,
+    /// Used for multiple transaction
+    pub(crate) submit_cnt: usize,
+    /// The first BD of the hardware group not completed when the channel
+    /// halted, the channel is resumed from it
+    pub(crate) resume_at: Option<usize>,
+    /// Whether the channel is stopped on purpose, the packets are then only
+    /// started by an explicit `to_hw`
+    pub(crate) is_stopped: bool,
+}
+
+// `to_hw`/`from_hw` follow XAxiDma_BdRingToHw/XAxiDma_BdRingFromHw
+#[allow(clippy::wrong_self_convention)]
+impl<D: Descriptor> BDRing<D> {
+    /// Create an empty ring without any buffer descriptor.
+    pub const fn new() -> Self {
+        Self {
+            is_halted: true,
+            bds: VecDeque::new(),
+            bd_head: 0,
+            bd_tail: 0,
+            bd_restart: 0,
+            free_cnt: 0,
+            all_cnt: 0,
+            pending_cnt: 0,
+            submit_cnt: 0,
+            resume_at: None,
+            is_stopped: false,
+        }
+    }
+
+    /// Creates and setup the BD ring, every BD is built by `new_bd`.
+    pub fn create<F>(&mut self, bd_count: usize, mut new_bd: F) -> AxiDMAResult
+    where
+        F: FnMut() -> D,
+    {
+        if bd_count == 0 {
+            error!("non-positive BD number {}", bd_count);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        self.bds.clear();
+        self.bds.reserve(bd_count);
+        for _ in 0..bd_count {
+            self.bds.push_back(Box::pin(new_bd()));
+        }
+        // link bd chain
+        for i in 0..bd_count {
+            let next_addr = self.bds[(i + 1) % bd_count].desc_addr();
+            self.bds[i].set_next_desc_addr(next_addr);
+        }
+        self.pending_cnt = 0;
+        self.submit_cnt = 0;
+        self.is_halted = true;
+        self.all_cnt = bd_count;
+        self.free_cnt = bd_count;
+        self.bd_head = 0;
+        self.bd_tail = 0;
+        self.bd_restart = 0;
+        self.resume_at = None;
+        self.is_stopped = false;
+        Ok(())
+    }
+
+    /// the pointer of head buffer descriptor in the work group
+    pub fn head_desc_addr(&self) -> usize {
+        self.bds[self.bd_head].desc_addr()
+    }
+
+    /// the pointer of tail buffer descriptor in the work group
+    pub fn tail_desc_addr(&self) -> usize {
+        self.bds[self.bd_tail].desc_addr()
+    }
+
+    /// The index of the first BD of the hardware group which is not completed
+    pub fn first_uncompleted(&self) -> Option<usize> {
+        (0..self.submit_cnt)
+            .map(|i| (self.bd_head + i) % self.all_cnt)
+            .find(|&i| !self.bds[i].is_cmplt())
+    }
+
+    /// Whether the ring has free block descriptor
+    pub fn has_free_bd(&self) -> bool {
+        self.free_cnt > 0
+    }
+
+    /// Take a free BD, let `setup` fill it and put it into the pre-process group
+    /// as a whole packet.
+    pub fn submit<F>(&mut self, mut setup: F) -> AxiDMAResult
+    where
+        F: FnMut(&D) -> AxiDMAResult,
+    {
+        self.submit_packet(1, |_, bd| setup(bd))
+    }
+
+    /// Take `bd_count` free BDs, let `setup` fill the i-th one of them and put
+    /// them into the pre-process group as a whole packet. Nothing is submitted
+    /// if `setup` fails on any BD.
+    pub fn submit_packet<F>(&mut self, bd_count: usize, mut setup: F) -> AxiDMAResult
+    where
+        F: FnMut(usize, &D) -> AxiDMAResult,
+    {
+        if bd_count == 0 {
+            error!("non-positive BD number {}", bd_count);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        if bd_count > self.free_cnt {
+            error!("no free bd, need: {}, free: {}", bd_count, self.free_cnt);
+            return Err(AxiDMAErr::BDRingNoList);
+        }
+        let start = self.bd_restart;
+        for i in 0..bd_count {
+            let bd = &self.bds[(start + i) % self.all_cnt];
+            bd.clear();
+            setup(i, bd)?;
+        }
+        self.bd_restart = (start + bd_count) % self.all_cnt;
+        self.bd_tail = if self.bd_restart == 0 {
+            self.bds.len() - 1
+        } else {
+            self.bd_restart - 1
+        };
+        self.bds[start].set_sof();
+        self.bds[self.bd_tail].set_eof();
+
+        self.free_cnt -= bd_count;
+        self.pending_cnt += bd_count;
+        trace!(
+            "bd_ring::submit: done, restart: {}, tail: {}, free: {}, pending: {}",
+            self.bd_restart,
+            self.bd_tail,
+            self.free_cnt,
+            self.pending_cnt
+        );
+        Ok(())
+    }
+
+    /// Give the last `bd_count` BDs of the pre-process group back to the free
+    /// group, as XAxiDma_BdRingUnAlloc. They must be whole packets.
+    pub fn unalloc(&mut self, bd_count: usize) -> AxiDMAResult {
+        if bd_count > self.pending_cnt {
+            error!(
+                "bd_ring::unalloc: {} BDs, but {} are pending",
+                bd_count, self.pending_cnt
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        for _ in 0..bd_count {
+            self.bd_restart = (self.bd_restart + self.all_cnt - 1) % self.all_cnt;
+            self.bds[self.bd_restart].clear();
+        }
+        self.bd_tail = if self.bd_restart == 0 {
+            self.bds.len() - 1
+        } else {
+            self.bd_restart - 1
+        };
+        self.free_cnt += bd_count;
+        self.pending_cnt -= bd_count;
+        Ok(())
+    }
+
+    /// Drop all the BDs of the pre-process and the hardware group, they are
+    /// back to the free group. The channel must be halted, the next transfer
+    /// starts from the current position of the ring.
+    pub fn discard(&mut self) {
+        self.free_cnt = self.all_cnt;
+        self.pending_cnt = 0;
+        self.submit_cnt = 0;
+        self.bd_head = self.bd_restart;
+        self.bd_tail = self.bd_restart;
+        self.is_halted = true;
+        self.resume_at = None;
+        for bd in self.bds.iter() {
+            bd.clear();
+        }
+    }
+
+    /// Move the pre-process group to the hardware group. It returns the address
+    /// of the new tail BD if the TAILDESC register must be updated.
+    pub fn to_hw(&mut self) -> Option<usize> {
+        self.is_halted = false;
+        if self.pending_cnt > 0 {
+            self.submit_cnt += self.pending_cnt;
+            self.pending_cnt = 0;
+            return Some(self.tail_desc_addr());
+        }
+        None
+    }
+
+    /// Retrieve the completed BDs from the hardware group, only whole packets
+    /// are retrieved. It returns the number of retrieved BDs.
+    pub fn from_hw(&mut self) -> usize {
+        self.from_hw_with(|_| {})
+    }
+
+    /// Retrieve the completed BDs from the hardware group as [`BDRing::from_hw`],
+    /// `f` is called on every retrieved BD in order before it is freed.
+    pub fn from_hw_with<F>(&mut self, f: F) -> usize
+    where
+        F: FnMut(&D),
+    {
+        self.from_hw_limit(usize::MAX, f)
+    }
+
+    /// Retrieve at most `max_pkts` completed packets from the hardware group as
+    /// [`BDRing::from_hw_with`].
+    pub fn from_hw_limit<F>(&mut self, max_pkts: usize, mut f: F) -> usize
+    where
+        F: FnMut(&D),
+    {
+        if max_pkts == 0 || self.submit_cnt == 0 {
+            return 0;
+        }
+        let mut pkt_cnt = 0;
+        let mut bd_cnt = 0;
+        let mut partial_cnt = 0;
+        let mut cur_bd = self.bd_head;
+        trace!(
+            "bd_ring::from_hw: head: {}, tail: {}",
+            self.bd_head,
+            self.bd_tail
+        );
+        loop {
+            let bd = &self.bds[cur_bd];
+            // Check the status of buffer descriptor, if is not completed, this action must be stopped.
+            if !bd.is_cmplt() {
+                trace!("bd_ring::from_hw: Uncompleted BD found at {}", cur_bd);
+                break;
+            }
+            bd_cnt += 1;
+            // check that this buffer descriptor is the end of the transaction
+            // It is found a eof of a transaction, but maybe there are more buffer descriptor under the hardware.
+            if bd.is_eof() {
+                trace!("bd_ring::from_hw: EOF found at {}", cur_bd);
+                partial_cnt = 0;
+                pkt_cnt += 1;
+                if pkt_cnt == max_pkts {
+                    break;
+                }
+            } else {
+                partial_cnt += 1;
+            }
+            // Only the BDs of the hardware group may be completed
+            if bd_cnt == self.submit_cnt {
+                break;
+            }
+            cur_bd += 1;
+            if cur_bd == self.all_cnt {
+                cur_bd = 0;
+            }
+        }
+        trace!(
+            "bd_ring::from_hw: bd_cnt: {}, partial: {}",
+            bd_cnt,
+            partial_cnt
+        );
+        bd_cnt -= partial_cnt;
+        for i in 0..bd_cnt {
+            let bd = &self.bds[(self.bd_head + i) % self.all_cnt];
+            f(bd);
+            bd.clear_status();
+        }
+        if bd_cnt > 0 {
+            self.bd_head = (self.bd_head + bd_cnt) % self.all_cnt;
+            self.submit_cnt -= bd_cnt;
+            self.free_cnt += bd_cnt;
+            trace!("bd_ring::from_hw: free_cnt: {}", self.free_cnt);
+        }
+        bd_cnt
+    }
+}
+
+impl<D: Descriptor> Default for BDRing<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    /// A BD whose completion is set by the test instead of the hardware
+    #[derive(Default)]
+    struct MockBD {
+        next: Cell<usize>,
+        sof: Cell<bool>,
+        eof: Cell<bool>,
+        cmplt: Cell<bool>,
+    }
+
+    impl Descriptor for MockBD {
+        fn desc_addr(&self) -> usize {
+            self as *const _ as usize
+        }
+
+        fn clear(&self) {
+            self.sof.set(false);
+            self.eof.set(false);
+            self.cmplt.set(false);
+        }
+
+        fn clear_status(&self) {
+            self.cmplt.set(false);
+        }
+
+        fn set_next_desc_addr(&self, addr: usize) {
+            self.next.set(addr);
+        }
+
+        fn set_sof(&self) {
+            self.sof.set(true);
+        }
+
+        fn set_eof(&self) {
+            self.eof.set(true);
+        }
+
+        fn is_cmplt(&self) -> bool {
+            self.cmplt.get()
+        }
+
+        fn is_eof(&self) -> bool {
+            self.eof.get()
+        }
+    }
+
+    fn ring(bd_count: usize) -> BDRing<MockBD> {
+        let mut ring = BDRing::new();
+        ring.create(bd_count, MockBD::default).unwrap();
+        ring
+    }
+
+    /// Submit `pkts` packets of `bds` BDs each and start them
+    fn submit(ring: &mut BDRing<MockBD>, pkts: usize, bds: usize) {
+        for _ in 0..pkts {
+            ring.submit_packet(bds, |_, _| Ok(())).unwrap();
+        }
+        ring.to_hw();
+    }
+
+    /// Complete the first `n` BDs of the hardware group
+    fn complete(ring: &BDRing<MockBD>, n: usize) {
+        for i in 0..n {
+            ring.bds[(ring.bd_head + i) % ring.all_cnt].cmplt.set(true);
+        }
+    }
+
+    #[test]
+    fn bds_are_linked_in_a_ring() {
+        let ring = ring(3);
+        for i in 0..3 {
+            assert_eq!(ring.bds[i].next.get(), ring.bds[(i + 1) % 3].desc_addr());
+        }
+    }
+
+    #[test]
+    fn retrieves_across_the_wrap() {
+        let mut ring = ring(4);
+        submit(&mut ring, 3, 1);
+        complete(&ring, 3);
+        assert_eq!(ring.from_hw(), 3);
+
+        // BDs 3, 0 and 1
+        submit(&mut ring, 3, 1);
+        assert_eq!(ring.free_cnt, 1);
+        complete(&ring, 3);
+        assert_eq!(ring.from_hw(), 3);
+        assert_eq!(ring.free_cnt, 4);
+        assert_eq!(ring.submit_cnt, 0);
+        assert_eq!(ring.bd_head, 2);
+    }
+
+    #[test]
+    fn unalloc_returns_pending_bds() {
+        let mut ring = ring(4);
+        submit(&mut ring, 1, 1);
+        ring.submit_packet(2, |_, _| Ok(())).unwrap();
+        assert!(ring.unalloc(3).is_err());
+        ring.unalloc(2).unwrap();
+        assert_eq!((ring.free_cnt, ring.pending_cnt), (3, 0));
+        assert_eq!((ring.bd_restart, ring.bd_tail), (1, 0));
+        assert!(!ring.bds[1].sof.get() && !ring.bds[2].eof.get());
+        // The freed BDs are taken again from the same position
+        submit(&mut ring, 1, 3);
+        assert_eq!(ring.free_cnt, 0);
+        assert!(ring.bds[1].sof.get() && ring.bds[3].eof.get());
+    }
+
+    #[test]
+    fn partial_packet_is_not_retrieved() {
+        let mut ring = ring(4);
+        submit(&mut ring, 1, 2);
+        assert!(ring.bds[0].sof.get() && ring.bds[1].eof.get());
+        complete(&ring, 1);
+        assert_eq!(ring.from_hw(), 0);
+        complete(&ring, 2);
+        assert_eq!(ring.from_hw(), 2);
+    }
+
+    #[test]
+    fn stale_bds_beyond_the_hardware_group_are_not_retrieved() {
+        let mut ring = ring(4);
+        submit(&mut ring, 2, 1);
+        // Every BD looks completed, e.g. left over from a previous run
+        complete(&ring, 4);
+        for bd in ring.bds.iter() {
+            bd.eof.set(true);
+        }
+        assert_eq!(ring.from_hw(), 2);
+        assert_eq!(ring.submit_cnt, 0);
+        assert_eq!(ring.free_cnt, 4);
+        assert_eq!(ring.from_hw(), 0);
+    }
+
+    #[test]
+    fn retrieved_bds_lose_their_status() {
+        let mut ring = ring(2);
+        submit(&mut ring, 1, 1);
+        complete(&ring, 1);
+        assert_eq!(ring.from_hw(), 1);
+        assert!(!ring.bds[0].is_cmplt());
+        assert_eq!(ring.first_uncompleted(), None);
+    }
+
+    #[test]
+    fn from_hw_limit_stops_after_max_packets() {
+        let mut ring = ring(4);
+        submit(&mut ring, 3, 1);
+        complete(&ring, 3);
+        assert_eq!(ring.from_hw_limit(0, |_| {}), 0);
+        assert_eq!(ring.from_hw_limit(2, |_| {}), 2);
+        assert_eq!(ring.from_hw(), 1);
+    }
+
+    #[test]
+    fn full_or_failed_submission_takes_no_bd() {
+        let mut ring = ring(2);
+        assert!(matches!(
+            ring.submit_packet(3, |_, _| Ok(())),
+            Err(AxiDMAErr::BDRingNoList)
+        ));
+        assert!(ring
+            .submit_packet(2, |i, _| match i {
+                0 => Ok(()),
+                _ => Err(AxiDMAErr::InValidParam),
+            })
+            .is_err());
+        assert_eq!(ring.free_cnt, 2);
+        assert_eq!(ring.pending_cnt, 0);
+        assert_eq!(ring.to_hw(), None);
+    }
+
+    #[test]
+    fn discard_frees_all_the_groups() {
+        let mut ring = ring(4);
+        submit(&mut ring, 2, 1);
+        ring.submit_packet(1, |_, _| Ok(())).unwrap();
+        ring.discard();
+        assert_eq!(ring.free_cnt, 4);
+        assert_eq!((ring.pending_cnt, ring.submit_cnt), (0, 0));
+        assert_eq!(ring.bd_head, ring.bd_restart);
+        assert!(ring.is_halted);
+    }
+}
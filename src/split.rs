@@ -0,0 +1,205 @@
+//! The independently owned TX and RX halves of an AxiDMA.
+//!
+//! [`AxiDma::split`] consumes the engine and returns a [`TxChannel`] and a
+//! [`RxChannel`], the two directions of [`ChannelHalf`], which can be moved to different tasks or cores. Every half
+//! only touches the registers and the BD ring of its own channel.
+//!
+//! A reset of either channel resets the whole engine, so the engine-wide
+//! operations are only available on the [`AxiDma`] rebuilt from both halves
+//! by [`AxiDma::join`].
+//!
+
+use crate::channel::{AxiDMAChannel, ChannelSnapshot};
+use crate::{
+    AxiDMAErr, AxiDMAResult, AxiDma, BufPtr, ChannelStats, Completion, DmaCookie, Transfer,
+};
+use alloc::sync::Arc;
+use axidma_pac::Instance;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// The parameters of the engine carried by both halves, so that the engine
+/// can be rebuilt.
+#[derive(Debug, Clone, Copy)]
+struct EngineInfo {
+    base_address: usize,
+    has_sg: bool,
+    is_micro_dma: bool,
+    addr_width: isize,
+    is_initialized: bool,
+}
+
+/// The direction of a [`ChannelHalf`]
+pub trait HalfDirection {
+    /// The name of the direction in the logs
+    const NAME: &'static str;
+}
+
+/// The tx (MM2S) direction
+pub struct Tx;
+/// The rx (S2MM) direction
+pub struct Rx;
+
+impl HalfDirection for Tx {
+    const NAME: &'static str = "tx";
+}
+
+impl HalfDirection for Rx {
+    const NAME: &'static str = "rx";
+}
+
+/// The owned handle of a channel of an AxiDMA. It only exposes the operations
+/// of its own channel, the engine-wide ones such as the reset need both
+/// halves joined again.
+pub struct ChannelHalf<D: HalfDirection> {
+    channel: Arc<AxiDMAChannel>,
+    info: EngineInfo,
+    _direction: PhantomData<D>,
+}
+
+/// The owned handle of the tx channel of an AxiDMA.
+pub type TxChannel = ChannelHalf<Tx>;
+
+/// The owned handle of the rx channel of an AxiDMA.
+pub type RxChannel = ChannelHalf<Rx>;
+
+impl AxiDma {
+    /// Split the engine into the owned tx and rx halves. Both channels are required.
+    pub fn split(self) -> Result<(TxChannel, RxChannel), AxiDMAErr> {
+        let info = EngineInfo {
+            base_address: self.base_address(),
+            has_sg: self.has_sg,
+            is_micro_dma: self.is_micro_dma,
+            addr_width: self.addr_width,
+            is_initialized: self.is_initialized.load(Ordering::Relaxed),
+        };
+        match (self.tx_channel, self.rx_channel) {
+            (Some(tx), Some(rx)) => Ok((ChannelHalf::new(tx, info), ChannelHalf::new(rx, info))),
+            _ => {
+                error!("axidma::split: both the tx and the rx channel are required");
+                Err(AxiDMAErr::InValidParam)
+            }
+        }
+    }
+
+    /// Rebuild the engine from the halves returned by [`AxiDma::split`].
+    /// The halves must come from the same engine.
+    pub fn join(tx: TxChannel, rx: RxChannel) -> Result<Self, AxiDMAErr> {
+        if tx.info.base_address != rx.info.base_address {
+            error!(
+                "axidma::join: halves of different engines 0x{:x}, 0x{:x}",
+                tx.info.base_address, rx.info.base_address
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let info = tx.info;
+        Ok(Self {
+            regs: unsafe { Instance::from_base(info.base_address) },
+            has_sg: info.has_sg,
+            is_micro_dma: info.is_micro_dma,
+            addr_width: info.addr_width,
+            is_initialized: AtomicBool::new(tx.info.is_initialized && rx.info.is_initialized),
+            tx_channel: Some(tx.channel),
+            rx_channel: Some(rx.channel),
+        })
+    }
+}
+
+impl<D: HalfDirection> ChannelHalf<D> {
+    fn new(channel: Arc<AxiDMAChannel>, info: EngineInfo) -> Self {
+        Self {
+            channel,
+            info,
+            _direction: PhantomData,
+        }
+    }
+
+    /// Creates and setup the BD ring of the channel
+    pub fn create(&mut self, bd_count: usize) -> AxiDMAResult {
+        self.channel.intr_disable();
+        self.channel.create(bd_count)
+    }
+
+    /// Start the channel
+    pub fn start(&mut self) -> AxiDMAResult {
+        if !self.info.is_initialized {
+            error!("axidma::{}: Driver not initialized", D::NAME);
+            return Err(AxiDMAErr::NotInit);
+        }
+        self.channel.start()
+    }
+
+    /// Stop the channel
+    pub fn stop(&mut self) -> AxiDMAResult {
+        self.channel.stop()
+    }
+
+    /// Submit a buffer to the channel
+    pub fn submit(&mut self, buffer: BufPtr) -> Result<Transfer, AxiDMAErr> {
+        let transfer = Transfer::new(self.channel.submit(buffer)?, self.channel.clone());
+        self.channel.to_hw()?;
+        Ok(transfer)
+    }
+
+    /// Submit a buffer and hand it over to the channel, it is given back by
+    /// [`ChannelHalf::pop_completion`]
+    pub fn submit_queued(&mut self, buffer: BufPtr) -> Result<DmaCookie, AxiDMAErr> {
+        let cookie = self.channel.submit_queued(buffer)?;
+        self.channel.to_hw()?;
+        Ok(cookie)
+    }
+
+    /// Take the oldest record of the completion queue
+    pub fn pop_completion(&self) -> Option<Completion> {
+        self.channel.pop_completion()
+    }
+
+    /// Retrieve the completed BDs
+    pub fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        self.channel.from_hw()
+    }
+
+    /// Wait the channel completing a transaction synchronously
+    pub fn wait(&self) {
+        self.channel.wait()
+    }
+
+    /// Enable the interrupt of the channel
+    pub fn intr_enable(&self) {
+        self.channel.intr_enable()
+    }
+
+    /// Disable the interrupt of the channel
+    pub fn intr_disable(&self) {
+        self.channel.intr_disable()
+    }
+
+    /// The interrupt handler of the channel
+    pub fn intr_handler(&self) -> AxiDMAResult {
+        self.channel.intr_handler()
+    }
+
+    /// Take a snapshot of the registers and the ring of the channel
+    pub fn snapshot(&self) -> ChannelSnapshot {
+        self.channel.snapshot()
+    }
+
+    /// Take a snapshot of the statistics counters of the channel
+    pub fn stats(&self) -> ChannelStats {
+        self.channel.stats()
+    }
+
+    /// The channel, for the interrupt registry
+    pub(crate) fn channel(&self) -> &Arc<AxiDMAChannel> {
+        &self.channel
+    }
+}
+
+impl ChannelHalf<Tx> {
+    /// Submit a buffer to the channel with the user application words
+    pub fn submit_app(&mut self, buffer: BufPtr, app: &[u32; 5]) -> Result<Transfer, AxiDMAErr> {
+        let transfer = Transfer::new(self.channel.submit_app(buffer, app)?, self.channel.clone());
+        self.channel.to_hw()?;
+        Ok(transfer)
+    }
+}
@@ -0,0 +1,248 @@
+//! The staging queue of the multi-producer submission path.
+//!
+//! Producers push their packets with a single compare-and-swap and never wait
+//! for each other. The holder of the ring lock takes the whole queue at once,
+//! moves the packets into the BD ring in the order of their pushes and updates
+//! TAILDESC once for the batch, so TAILDESC is only written under the ring lock.
+//!
+//! A producer which fails to take the ring lock leaves its packet to the
+//! holder, so every release of the ring lock goes through
+//! [`StagingQueue::release`] and flushes the packets pushed meanwhile. The
+//! `SeqCst` fences between the push and the lock attempt of the producer, and
+//! between the unlock and the check of the holder, ensure that at least one of
+//! them sees the other. The protocol is checked with loom:
+//! `RUSTFLAGS="--cfg loom" cargo test --release staging`.
+//!
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::ptr;
+use core::sync::atomic::AtomicBool;
+#[cfg(not(all(test, loom)))]
+use core::sync::atomic::{fence, AtomicPtr, Ordering};
+#[cfg(all(test, loom))]
+use loom::sync::atomic::{fence, AtomicPtr, Ordering};
+
+/// A packet waiting to be moved into the BD ring
+pub(crate) struct StagedPacket {
+    /// The (address, length) of the BDs
+    pub chunks: Vec<(usize, usize)>,
+    /// The user application words of the first BD
+    pub app: Option<[u32; 5]>,
+    /// Set once the packet is moved into the BD ring
+    pub in_ring: Option<Arc<AtomicBool>>,
+}
+
+struct Node {
+    packet: StagedPacket,
+    next: *mut Node,
+}
+
+/// A lock-free multi-producer queue, the consumer always takes all the packets.
+pub(crate) struct StagingQueue {
+    /// The last pushed node, the nodes are linked from the newest to the oldest
+    head: AtomicPtr<Node>,
+}
+
+unsafe impl Send for StagingQueue {}
+unsafe impl Sync for StagingQueue {}
+
+impl StagingQueue {
+    pub fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Push a packet
+    pub fn push(&self, packet: StagedPacket) {
+        let node = Box::into_raw(Box::new(Node {
+            packet,
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*node).next = head };
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::SeqCst, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                Err(cur) => head = cur,
+            }
+        }
+    }
+
+    /// Take all the packets in the order of their pushes
+    pub fn take_all(&self) -> Vec<StagedPacket> {
+        let mut node = self.head.swap(ptr::null_mut(), Ordering::SeqCst);
+        let mut packets = Vec::new();
+        while !node.is_null() {
+            // The nodes taken by the swap are not reachable by anyone else
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next;
+            packets.push(boxed.packet);
+        }
+        packets.reverse();
+        packets
+    }
+
+    /// Whether no packet is staged
+    pub fn is_empty(&self) -> bool {
+        self.head.load(Ordering::SeqCst).is_null()
+    }
+
+    /// Move the staged packets with `drain` under the lock taken by
+    /// `try_lock`. It returns at once if the lock is held, its holder flushes
+    /// the packets when releasing it.
+    pub fn flush<G, E>(
+        &self,
+        mut try_lock: impl FnMut() -> Option<G>,
+        mut drain: impl FnMut(&mut G, Vec<StagedPacket>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        loop {
+            fence(Ordering::SeqCst);
+            let Some(mut guard) = try_lock() else {
+                return Ok(());
+            };
+            drain(&mut guard, self.take_all())?;
+            // A producer may have pushed while the lock was held
+            if !self.release(guard) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Release the lock held by `guard`, it returns whether packets were
+    /// pushed meanwhile and must be flushed.
+    pub fn release<G>(&self, guard: G) -> bool {
+        drop(guard);
+        fence(Ordering::SeqCst);
+        !self.is_empty()
+    }
+}
+
+impl Drop for StagingQueue {
+    fn drop(&mut self) {
+        self.take_all();
+    }
+}
+
+#[cfg(all(test, loom))]
+mod tests {
+    use super::*;
+    use loom::cell::UnsafeCell;
+    use loom::sync::atomic::AtomicBool;
+    use loom::sync::Arc;
+    use loom::thread;
+
+    /// A ring which only counts the packets moved into it, behind a spin lock
+    /// as the ring lock of a channel
+    struct Ring {
+        locked: AtomicBool,
+        moved: UnsafeCell<usize>,
+    }
+
+    unsafe impl Sync for Ring {}
+
+    struct RingGuard<'a>(&'a Ring);
+
+    impl Drop for RingGuard<'_> {
+        fn drop(&mut self) {
+            self.0.locked.store(false, Ordering::Release);
+        }
+    }
+
+    impl Ring {
+        fn new() -> Self {
+            Self {
+                locked: AtomicBool::new(false),
+                moved: UnsafeCell::new(0),
+            }
+        }
+
+        fn try_lock(&self) -> Option<RingGuard<'_>> {
+            self.locked
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .ok()
+                .map(|_| RingGuard(self))
+        }
+
+        fn lock(&self) -> RingGuard<'_> {
+            loop {
+                if let Some(guard) = self.try_lock() {
+                    return guard;
+                }
+                thread::yield_now();
+            }
+        }
+
+        fn moved(&self) -> usize {
+            let _guard = self.lock();
+            self.moved.with(|moved| unsafe { *moved })
+        }
+    }
+
+    fn packet() -> StagedPacket {
+        StagedPacket {
+            chunks: Vec::new(),
+            app: None,
+            in_ring: None,
+        }
+    }
+
+    fn flush(queue: &StagingQueue, ring: &Ring) {
+        queue
+            .flush(
+                || ring.try_lock(),
+                |guard, packets| {
+                    guard
+                        .0
+                        .moved
+                        .with_mut(|moved| unsafe { *moved += packets.len() });
+                    Ok::<(), ()>(())
+                },
+            )
+            .unwrap();
+    }
+
+    fn producer(queue: &Arc<StagingQueue>, ring: &Arc<Ring>) -> thread::JoinHandle<()> {
+        let (queue, ring) = (queue.clone(), ring.clone());
+        thread::spawn(move || {
+            queue.push(packet());
+            flush(&queue, &ring);
+        })
+    }
+
+    #[test]
+    fn packet_pushed_while_locked_is_flushed_by_holder() {
+        loom::model(|| {
+            let queue = Arc::new(StagingQueue::new());
+            let ring = Arc::new(Ring::new());
+            let producer = producer(&queue, &ring);
+            // Another user of the ring lock, e.g. a plain submission
+            let guard = ring.lock();
+            if queue.release(guard) {
+                flush(&queue, &ring);
+            }
+            producer.join().unwrap();
+            assert!(queue.is_empty());
+            assert_eq!(ring.moved(), 1);
+        });
+    }
+
+    #[test]
+    fn concurrent_producers_are_all_flushed() {
+        loom::model(|| {
+            let queue = Arc::new(StagingQueue::new());
+            let ring = Arc::new(Ring::new());
+            let producers = [producer(&queue, &ring), producer(&queue, &ring)];
+            for producer in producers {
+                producer.join().unwrap();
+            }
+            assert!(queue.is_empty());
+            assert_eq!(ring.moved(), 2);
+        });
+    }
+}
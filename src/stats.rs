@@ -0,0 +1,238 @@
+//! The statistics counters of an AxiDMA channel.
+//!
+//! The counters are updated with relaxed atomics by the submission, the
+//! retrieval of the BDs and the interrupt handler. They are compiled only with
+//! the `stats` feature, without it every update is an empty inline function
+//! and the snapshot is all zero.
+//!
+
+#[cfg(feature = "stats")]
+use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+/// The error interrupts of a channel, by the error bits of DMASR
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ErrorCounts {
+    /// DMA internal errors
+    pub dma_int: usize,
+    /// DMA slave errors
+    pub dma_slv: usize,
+    /// DMA decode errors
+    pub dma_dec: usize,
+    /// SG internal errors
+    pub sg_int: usize,
+    /// SG slave errors
+    pub sg_slv: usize,
+    /// SG decode errors
+    pub sg_dec: usize,
+}
+
+/// A snapshot of the statistics of a channel
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelStats {
+    /// The number of completed packets
+    pub packets: usize,
+    /// The number of bytes of the completed packets
+    pub bytes: usize,
+    /// The number of submissions which found the ring full, whether they
+    /// failed, waited for free BDs or were left staged
+    pub submit_full: usize,
+    /// The error interrupts by type
+    pub errors: ErrorCounts,
+    /// The number of IOC interrupts
+    pub ioc_irqs: usize,
+    /// The number of delay interrupts
+    pub dly_irqs: usize,
+    /// The highest number of BDs in use
+    pub occupancy_hwm: usize,
+    /// The number of resets of the channel
+    pub resets: usize,
+}
+
+/// The error bits of DMASR, in the order of [`ErrorCounts`]
+pub(crate) type ErrorBits = [bool; 6];
+
+/// The counters of a channel
+#[cfg(feature = "stats")]
+#[derive(Default)]
+pub(crate) struct StatsCounters {
+    packets: AtomicUsize,
+    bytes: AtomicUsize,
+    submit_full: AtomicUsize,
+    errors: [AtomicUsize; 6],
+    ioc_irqs: AtomicUsize,
+    dly_irqs: AtomicUsize,
+    occupancy_hwm: AtomicUsize,
+    resets: AtomicUsize,
+}
+
+#[cfg(feature = "stats")]
+impl StatsCounters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn packet(&self, len: usize) {
+        self.packets.fetch_add(1, Relaxed);
+        self.bytes.fetch_add(len, Relaxed);
+    }
+
+    pub fn submit_full(&self) {
+        self.submit_full.fetch_add(1, Relaxed);
+    }
+
+    pub fn error(&self, bits: ErrorBits) {
+        for (counter, bit) in self.errors.iter().zip(bits) {
+            if bit {
+                counter.fetch_add(1, Relaxed);
+            }
+        }
+    }
+
+    pub fn ioc_irq(&self) {
+        self.ioc_irqs.fetch_add(1, Relaxed);
+    }
+
+    pub fn dly_irq(&self) {
+        self.dly_irqs.fetch_add(1, Relaxed);
+    }
+
+    pub fn occupancy(&self, used: usize) {
+        self.occupancy_hwm.fetch_max(used, Relaxed);
+    }
+
+    pub fn reset(&self) {
+        self.resets.fetch_add(1, Relaxed);
+    }
+
+    pub fn snapshot(&self) -> ChannelStats {
+        let errors = &self.errors;
+        ChannelStats {
+            packets: self.packets.load(Relaxed),
+            bytes: self.bytes.load(Relaxed),
+            submit_full: self.submit_full.load(Relaxed),
+            errors: ErrorCounts {
+                dma_int: errors[0].load(Relaxed),
+                dma_slv: errors[1].load(Relaxed),
+                dma_dec: errors[2].load(Relaxed),
+                sg_int: errors[3].load(Relaxed),
+                sg_slv: errors[4].load(Relaxed),
+                sg_dec: errors[5].load(Relaxed),
+            },
+            ioc_irqs: self.ioc_irqs.load(Relaxed),
+            dly_irqs: self.dly_irqs.load(Relaxed),
+            occupancy_hwm: self.occupancy_hwm.load(Relaxed),
+            resets: self.resets.load(Relaxed),
+        }
+    }
+
+    /// Clear all the counters
+    pub fn clear(&self) {
+        for counter in [
+            &self.packets,
+            &self.bytes,
+            &self.submit_full,
+            &self.ioc_irqs,
+            &self.dly_irqs,
+            &self.occupancy_hwm,
+            &self.resets,
+        ]
+        .into_iter()
+        .chain(self.errors.iter())
+        {
+            counter.store(0, Relaxed);
+        }
+    }
+}
+
+/// The counters of a channel, compiled out without the `stats` feature
+#[cfg(not(feature = "stats"))]
+pub(crate) struct StatsCounters;
+
+#[cfg(not(feature = "stats"))]
+impl StatsCounters {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[inline(always)]
+    pub fn packet(&self, _len: usize) {}
+
+    #[inline(always)]
+    pub fn submit_full(&self) {}
+
+    #[inline(always)]
+    pub fn error(&self, _bits: ErrorBits) {}
+
+    #[inline(always)]
+    pub fn ioc_irq(&self) {}
+
+    #[inline(always)]
+    pub fn dly_irq(&self) {}
+
+    #[inline(always)]
+    pub fn occupancy(&self, _used: usize) {}
+
+    #[inline(always)]
+    pub fn reset(&self) {}
+
+    #[inline(always)]
+    pub fn snapshot(&self) -> ChannelStats {
+        ChannelStats::default()
+    }
+
+    #[inline(always)]
+    pub fn clear(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counters() -> StatsCounters {
+        let stats = StatsCounters::new();
+        stats.packet(64);
+        stats.packet(1500);
+        stats.submit_full();
+        stats.error([false, true, false, false, false, true]);
+        stats.ioc_irq();
+        stats.dly_irq();
+        stats.dly_irq();
+        stats.occupancy(3);
+        stats.occupancy(2);
+        stats.reset();
+        stats
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn snapshot_counts_until_cleared() {
+        let stats = counters();
+        let expected = ChannelStats {
+            packets: 2,
+            bytes: 1564,
+            submit_full: 1,
+            errors: ErrorCounts {
+                dma_slv: 1,
+                sg_dec: 1,
+                ..ErrorCounts::default()
+            },
+            ioc_irqs: 1,
+            dly_irqs: 2,
+            occupancy_hwm: 3,
+            resets: 1,
+        };
+        assert_eq!(stats.snapshot(), expected);
+        // A snapshot does not clear the counters
+        assert_eq!(stats.snapshot(), expected);
+        stats.clear();
+        assert_eq!(stats.snapshot(), ChannelStats::default());
+        stats.packet(8);
+        assert_eq!(stats.snapshot().bytes, 8);
+    }
+
+    #[cfg(not(feature = "stats"))]
+    #[test]
+    fn snapshot_is_zero_without_stats() {
+        assert_eq!(counters().snapshot(), ChannelStats::default());
+    }
+}
@@ -1,29 +1,69 @@
 use alloc::sync::Arc;
 use core::hint;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
 
-use crate::{channel::AxiDMAChannel, errno::AxiDMAErr, BufPtr};
+#[cfg(feature = "async")]
+use crate::clock::Deadline;
+use crate::{channel::AxiDMAChannel, errno::AxiDMAErr, AxiDMAResult, BufPtr};
+
+/// The operations of a DMA channel that a [`Transfer`] relies on.
+pub trait TransferChannel {
+    /// Wait the channel completing a transaction synchronously.
+    fn wait(&self);
+    /// Retrieve the completed BDs from hardware
+    #[allow(clippy::wrong_self_convention)]
+    fn from_hw(&self) -> Result<usize, AxiDMAErr>;
+    /// The interrupt handler
+    fn intr_handler(&self) -> AxiDMAResult;
+    /// Deal the completion of a transaction once `wait` returns. The BDs are
+    /// retrieved only once, by `from_hw` or by the interrupt handler.
+    fn finish(&self) -> AxiDMAResult {
+        self.from_hw()?;
+        self.intr_handler()
+    }
+}
 
 /// The transfer structure of a transaction. It is combined with the AxiDMA channel.
 /// It also combined the Rust memory mode, when the buffer has been transported to the hardware,
 /// while this transaction has not been finished. The target buffer must be pinned and cannot be droppped.
 /// The target buffer cannot be taken from this transfer structure until the transaction is finished.
 /// Then it can be dropped or used for other function.
-pub struct Transfer {
+pub struct Transfer<C: TransferChannel = AxiDMAChannel> {
     // NOTE: always `Some` variant
     buffer: Option<BufPtr>,
     // The channel related with the transfer
-    channel: Arc<AxiDMAChannel>,
+    channel: Arc<C>,
+    // Whether the buffer is freed when the transfer is dropped, the buffers
+    // are never freed with `driver_test`
+    #[cfg(not(feature = "driver_test"))]
+    owned: bool,
     /// Completed flag
     #[cfg(feature = "async")]
     flag: bool,
 }
 
-impl Transfer {
+impl<C: TransferChannel> Transfer<C> {
     /// Create a new tranfer
-    pub fn new(buf: BufPtr, channel: Arc<AxiDMAChannel>) -> Self {
+    pub fn new(buf: BufPtr, channel: Arc<C>) -> Self {
         Self {
             buffer: Some(buf),
             channel,
+            #[cfg(not(feature = "driver_test"))]
+            owned: true,
+            #[cfg(feature = "async")]
+            flag: false,
+        }
+    }
+
+    /// Create a new transfer on a buffer which is still owned by the driver,
+    /// such as a VDMA frame store. The buffer is not freed when the transfer is dropped.
+    pub fn borrowed(buf: BufPtr, channel: Arc<C>) -> Self {
+        Self {
+            buffer: Some(buf),
+            channel,
+            #[cfg(not(feature = "driver_test"))]
+            owned: false,
             #[cfg(feature = "async")]
             flag: false,
         }
@@ -32,9 +72,8 @@ impl Transfer {
     /// Blocks until the transfer is done and returns the buffer, the
     pub fn wait(mut self) -> Result<BufPtr, AxiDMAErr> {
         self.channel.wait();
-        self.channel.from_hw()?;
         // Deal the interrupt
-        self.channel.intr_handler()?;
+        self.channel.finish()?;
         Ok(self
             .buffer
             .take()
@@ -51,10 +90,91 @@ impl Transfer {
     }
 }
 
+impl Transfer<AxiDMAChannel> {
+    /// Blocks at most `timeout` until the transfer is done, then [`Transfer::wait`]
+    /// returns the buffer without blocking. On timeout the transfer must be
+    /// kept, as its buffer may still be accessed by the hardware.
+    pub fn wait_timeout(&self, timeout: Duration) -> AxiDMAResult {
+        self.channel.wait_timeout(timeout)
+    }
+
+    /// The future of the transfer, which fails if it is not done within
+    /// `timeout`. The deadline is checked whenever the future is polled, see
+    /// [`TransferTimeout`] for the timer the caller must supply.
+    #[cfg(feature = "async")]
+    pub fn timeout(self, timeout: Duration) -> TransferTimeout {
+        TransferTimeout {
+            transfer: Some(self),
+            deadline: Deadline::after(timeout),
+        }
+    }
+}
+
+/// A transfer submitted by [`crate::AxiDma::tx_submit_mp`], its buffer may
+/// still be staged and not moved into the BD ring yet. It only turns into a
+/// [`Transfer`] once the buffer is in the ring, so that waiting on it waits for
+/// a transaction which has been started.
+pub struct StagedTransfer {
+    transfer: Option<Transfer<AxiDMAChannel>>,
+    // Set by the channel once the buffer is moved into the ring
+    in_ring: Arc<AtomicBool>,
+}
+
+impl StagedTransfer {
+    pub(crate) fn new(transfer: Transfer<AxiDMAChannel>, in_ring: Arc<AtomicBool>) -> Self {
+        Self {
+            transfer: Some(transfer),
+            in_ring,
+        }
+    }
+
+    /// Whether the buffer has been moved into the BD ring
+    pub fn in_ring(&self) -> bool {
+        self.in_ring.load(Ordering::Acquire)
+    }
+
+    /// Flush the staged buffers and take the transfer once the buffer is in
+    /// the ring, otherwise give the staged transfer back. A buffer which does
+    /// not fit into the ring waits for BDs to be retrieved.
+    pub fn into_transfer(mut self) -> Result<Transfer<AxiDMAChannel>, Self> {
+        if !self.in_ring() {
+            if let Some(transfer) = self.transfer.as_ref() {
+                if let Err(err) = transfer.channel.flush_staged() {
+                    warn!("axidma::transfer: flush failed {:?}", err);
+                }
+            }
+        }
+        if !self.in_ring() {
+            return Err(self);
+        }
+        Ok(self
+            .transfer
+            .take()
+            .unwrap_or_else(|| unsafe { hint::unreachable_unchecked() }))
+    }
+}
+
+impl Drop for StagedTransfer {
+    fn drop(&mut self) {
+        // The staged buffer is still referenced by the channel, it is leaked
+        if let Some(transfer) = self.transfer.take() {
+            if !self.in_ring() {
+                core::mem::forget(transfer);
+            }
+        }
+    }
+}
+
 #[cfg(not(feature = "driver_test"))]
-impl Drop for Transfer {
+impl<C: TransferChannel> Drop for Transfer<C> {
     fn drop(&mut self) {
-        let mut bufptr = self.buffer.take().unwrap_or_else(|| unsafe { hint::unreachable_unchecked() });
+        if !self.owned {
+            return;
+        }
+        // The buffer has been taken by `wait`, `recycle` or the future
+        let Some(mut bufptr) = self.buffer.take() else {
+            return;
+        };
         let len = bufptr.len();
         let raw_ptr = bufptr.as_mut_ptr();
         let slice = unsafe {
@@ -65,7 +185,7 @@ impl Drop for Transfer {
 }
 
 #[cfg(feature = "async")]
-impl Unpin for Transfer {}
+impl<C: TransferChannel> Unpin for Transfer<C> {}
 
 #[cfg(feature = "async")]
 use core::{
@@ -77,19 +197,64 @@ use core::{
 use core::pin::Pin;
 
 #[cfg(feature = "async")]
-impl Future for Transfer {
+impl<C: TransferChannel> Future for Transfer<C> {
     type Output = BufPtr;
     fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
         if !self.flag {
             self.flag = true;
             return Poll::Pending;
         }
-        let _ = self.channel.from_hw().unwrap();
-        let _ = self.channel.intr_handler().unwrap();
+        self.channel.finish().unwrap();
         let buf = self
             .buffer
             .take()
             .unwrap_or_else(|| unsafe { hint::unreachable_unchecked() });
-        return Poll::Ready(buf);
+        Poll::Ready(buf)
+    }
+}
+
+/// The future of a [`Transfer`] with a timeout. It is only woken when the
+/// channel retrieves BDs: the deadline is checked when it is polled, but no
+/// timer is registered for it. The caller must supply one, e.g. race the
+/// future with a sleep of its executor, otherwise a stalled channel never
+/// wakes it. On timeout, the transfer stays in the future and is taken back by
+/// [`TransferTimeout::into_inner`].
+#[cfg(feature = "async")]
+pub struct TransferTimeout {
+    transfer: Option<Transfer<AxiDMAChannel>>,
+    deadline: Deadline,
+}
+
+#[cfg(feature = "async")]
+impl TransferTimeout {
+    /// Take back the transfer which is not done
+    pub fn into_inner(self) -> Option<Transfer<AxiDMAChannel>> {
+        self.transfer
+    }
+}
+
+#[cfg(feature = "async")]
+impl Future for TransferTimeout {
+    type Output = Result<BufPtr, AxiDMAErr>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(transfer) = self.transfer.as_ref() else {
+            error!("axidma::transfer: polled after completion");
+            return Poll::Ready(Err(AxiDMAErr::InValidParam));
+        };
+        let channel = transfer.channel.clone();
+        // Register before checking, a completion in between wakes the waker
+        channel.register_ready(cx);
+        if !channel.is_done() {
+            if self.deadline.expired() {
+                error!("axidma::transfer: timed out");
+                return Poll::Ready(Err(AxiDMAErr::Timeout(channel.snapshot())));
+            }
+            return Poll::Pending;
+        }
+        let Some(transfer) = self.transfer.take() else {
+            return Poll::Pending;
+        };
+        Poll::Ready(transfer.wait())
     }
 }
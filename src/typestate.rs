@@ -0,0 +1,169 @@
+//! The typestate API of the AxiDMA, which checks the lifecycle at compile time.
+//!
+//! ```text
+//! Engine<Uninitialized> --reset--> Engine<Initialized> --into_channels--> Channel<Initialized>
+//! Channel<Initialized> --create--> Channel<Created> --start--> Channel<Running>
+//! Channel<Running> --stop--> Channel<Halted> --start--> Channel<Running>
+//! ```
+//!
+//! Buffers can only be submitted to a `Channel<Running>`, so submitting before
+//! the reset or before the BD ring is created fails to compile. The dynamic API
+//! of [`AxiDma`] is kept for the FFI users, it checks the same sequence at
+//! runtime and reports an [`AxiDMAErr`]. The typestate handles never give
+//! out the dynamic API, only the operations valid in their state.
+//!
+
+use crate::channel::{AxiDMAChannel, ChannelSnapshot};
+use crate::{AxiDMAErr, AxiDMAResult, AxiDma, BufPtr, ChannelStats, Transfer, ValidAxiDmaConfig};
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+/// The engine has not been reset
+pub struct Uninitialized;
+/// The engine has been reset, the channel has no BD ring
+pub struct Initialized;
+/// The BD ring of the channel has been created
+pub struct Created;
+/// The channel is running
+pub struct Running;
+/// The channel has been stopped
+pub struct Halted;
+
+/// The AxiDMA engine in the state `S`
+pub struct Engine<S> {
+    dma: Arc<AxiDma>,
+    _state: PhantomData<S>,
+}
+
+/// A channel of the AxiDMA in the state `S`
+pub struct Channel<S> {
+    channel: Arc<AxiDMAChannel>,
+    _state: PhantomData<S>,
+}
+
+impl<S> Channel<S> {
+    fn into_state<T>(self) -> Channel<T> {
+        Channel {
+            channel: self.channel,
+            _state: PhantomData,
+        }
+    }
+
+    /// Take a snapshot of the registers and the ring of the channel
+    pub fn snapshot(&self) -> ChannelSnapshot {
+        self.channel.snapshot()
+    }
+
+    /// Take a snapshot of the statistics counters of the channel
+    pub fn stats(&self) -> ChannelStats {
+        self.channel.stats()
+    }
+}
+
+impl Engine<Uninitialized> {
+    /// Create the engine according to the configuration
+    pub fn new(cfg: ValidAxiDmaConfig) -> Self {
+        Self {
+            dma: Arc::new(AxiDma::new(cfg)),
+            _state: PhantomData,
+        }
+    }
+
+    /// Reset the engine
+    pub fn reset(self) -> Result<Engine<Initialized>, AxiDMAErr> {
+        self.dma.reset()?;
+        Ok(Engine {
+            dma: self.dma,
+            _state: PhantomData,
+        })
+    }
+}
+
+impl Engine<Initialized> {
+    /// Take the tx and the rx channels of the engine
+    pub fn into_channels(self) -> (Option<Channel<Initialized>>, Option<Channel<Initialized>>) {
+        let channel = |channel: &Arc<AxiDMAChannel>| Channel {
+            channel: channel.clone(),
+            _state: PhantomData,
+        };
+        (
+            self.dma.tx_channel.as_ref().map(channel),
+            self.dma.rx_channel.as_ref().map(channel),
+        )
+    }
+
+    /// Enable the interrupts of both channels
+    pub fn intr_enable(&self) {
+        self.dma.intr_enable()
+    }
+
+    /// Disable the interrupts of both channels
+    pub fn intr_disable(&self) {
+        self.dma.intr_disable()
+    }
+}
+
+impl Channel<Initialized> {
+    /// Creates and setup the BD ring
+    pub fn create(self, bd_count: usize) -> Result<Channel<Created>, AxiDMAErr> {
+        self.channel.intr_disable();
+        self.channel.create(bd_count)?;
+        Ok(self.into_state())
+    }
+}
+
+impl Channel<Created> {
+    /// Start the channel
+    pub fn start(self) -> Result<Channel<Running>, AxiDMAErr> {
+        self.channel.start()?;
+        Ok(self.into_state())
+    }
+}
+
+impl Channel<Running> {
+    /// Submit a buffer to the channel
+    pub fn submit(&mut self, buffer: BufPtr) -> Result<Transfer, AxiDMAErr> {
+        let transfer = Transfer::new(self.channel.submit(buffer)?, self.channel.clone());
+        self.channel.to_hw()?;
+        Ok(transfer)
+    }
+
+    /// Retrieve the completed BDs
+    pub fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        self.channel.from_hw()
+    }
+
+    /// Wait the channel completing a transaction synchronously
+    pub fn wait(&self) {
+        self.channel.wait()
+    }
+
+    /// Enable the interrupt of the channel
+    pub fn intr_enable(&self) {
+        self.channel.intr_enable()
+    }
+
+    /// Disable the interrupt of the channel
+    pub fn intr_disable(&self) {
+        self.channel.intr_disable()
+    }
+
+    /// The interrupt handler of the channel
+    pub fn intr_handler(&self) -> AxiDMAResult {
+        self.channel.intr_handler()
+    }
+
+    /// Stop the channel
+    pub fn stop(self) -> Result<Channel<Halted>, AxiDMAErr> {
+        self.channel.stop()?;
+        Ok(self.into_state())
+    }
+}
+
+impl Channel<Halted> {
+    /// Start the channel again
+    pub fn start(self) -> Result<Channel<Running>, AxiDMAErr> {
+        self.channel.start()?;
+        Ok(self.into_state())
+    }
+}
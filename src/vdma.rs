@@ -0,0 +1,549 @@
+//! This is the driver API for the AXI Video DMA (VDMA) engine.
+//! See [PG020](https://docs.xilinx.com/r/en-US/pg020_axi_vdma) for the
+//! hardware spec.
+//!
+//! The VDMA moves two-dimensional frames between the memory and an AXI4-Stream
+//! video interface. It has no BD ring, every channel owns a fixed set of frame
+//! stores instead, and a frame is described by:
+//!
+//! - `hsize`: the number of bytes of a line,
+//! - `vsize`: the number of lines of a frame,
+//! - `stride`: the number of bytes between the first pixels of two adjacent lines.
+//!
+//! The channel works in two modes:
+//!
+//! - Circular mode: the channel cycles through all the frame stores.
+//!
+//! - Park mode: the channel stays on the frame store selected by the park pointer.
+//!
+//! By default the frame count interrupt is raised after every frame, so that
+//! the completion of a frame is reported through a [`Transfer`] on the frame
+//! store being worked on.
+//!
+
+use crate::channel::{ChannelSnapshot, Direaction};
+use crate::clock::Deadline;
+use crate::lock::IrqMutex;
+use crate::transfer::TransferChannel;
+use crate::{io_fence, AxiDMAErr, AxiDMAResult, BufPtr, Transfer};
+use alloc::{sync::Arc, vec::Vec};
+use axidma_pac::{vdma, Instance};
+use core::sync::atomic::{compiler_fence, fence, AtomicBool, Ordering, Ordering::SeqCst};
+use core::time::Duration;
+
+/// The configuration structure for AXI VDMA engine.
+#[derive(Debug)]
+pub struct AxiVdmaConfig {
+    /// The base address of the AxiVDMA
+    pub base_address: usize,
+    /// The number of frame stores of the engine, 1 ~ 32
+    pub max_frame_stores: usize,
+    /// Has read channel
+    pub has_mm2s: bool,
+    /// Whether the read channel has enabled the data realignment
+    pub has_mm2s_dre: bool,
+    /// The data width of read channel
+    pub mm2s_data_width: usize,
+    /// Has write channel
+    pub has_s2mm: bool,
+    /// Whether the write channel has enabled the data realignment
+    pub has_s2mm_dre: bool,
+    /// The data width of write channel
+    pub s2mm_data_width: usize,
+    /// the width of address
+    pub addr_width: isize,
+}
+
+/// The geometry of the frames of a VDMA channel.
+#[derive(Debug, Clone, Copy)]
+pub struct VdmaFrameFormat {
+    /// The number of bytes of a line
+    pub hsize: usize,
+    /// The number of lines of a frame
+    pub vsize: usize,
+    /// The number of bytes between the first pixels of two adjacent lines
+    pub stride: usize,
+}
+
+impl VdmaFrameFormat {
+    /// The max number of bytes of a line and of the stride
+    const MAX_HSIZE: usize = 0xffff;
+    /// The max number of lines of a frame
+    const MAX_VSIZE: usize = 0x1fff;
+
+    /// The number of bytes a frame store must have at least
+    pub fn frame_len(&self) -> usize {
+        self.stride * (self.vsize - 1) + self.hsize
+    }
+}
+
+/// The mode of a VDMA channel walking through its frame stores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VdmaMode {
+    /// Cycle through all the frame stores
+    Circular,
+    /// Stay on the given frame store
+    Park(usize),
+}
+
+/// The AxiVdma driver instance structure. An instance must be allocated for each
+/// VDMA engine in use.
+pub struct AxiVdma {
+    /// The read channel, from the memory to the video stream
+    pub mm2s_channel: Option<Arc<AxiVdmaChannel>>,
+    /// The write channel, from the video stream to the memory
+    pub s2mm_channel: Option<Arc<AxiVdmaChannel>>,
+}
+
+impl AxiVdma {
+    /// Create the AxiVDMA instance according to the configuration
+    pub fn new(cfg: AxiVdmaConfig) -> Self {
+        let mm2s_channel = if cfg.has_mm2s {
+            Some(Arc::new(AxiVdmaChannel::new(Direaction::TX, &cfg)))
+        } else {
+            None
+        };
+        let s2mm_channel = if cfg.has_s2mm {
+            Some(Arc::new(AxiVdmaChannel::new(Direaction::RX, &cfg)))
+        } else {
+            None
+        };
+        Self {
+            mm2s_channel,
+            s2mm_channel,
+        }
+    }
+
+    /// Reset the AxiVDMA, both channels are reset.
+    pub fn reset(&self) -> AxiDMAResult {
+        if let Some(channel) = self.mm2s_channel.as_ref() {
+            channel.reset()?;
+        }
+        if let Some(channel) = self.s2mm_channel.as_ref() {
+            channel.reset()?;
+        }
+        Ok(())
+    }
+
+    /// Get the major and minor version of the engine
+    pub fn version(&self) -> Option<(u8, u8)> {
+        let channel = self.mm2s_channel.as_ref().or(self.s2mm_channel.as_ref())?;
+        let version = channel.hardware().version().read();
+        Some((version.major().bits(), version.minor().bits()))
+    }
+}
+
+/// A channel of the AxiVDMA
+pub struct AxiVdmaChannel {
+    // Immutable
+    /// The registers of the AxiVDMA
+    regs: Instance<vdma::RegisterBlock>,
+    /// The direction of the channel
+    direction: Direaction,
+    /// Whether the channel has enabled the data realignment
+    has_dre: bool,
+    /// The data width of the channel
+    data_width: usize,
+    /// The number of frame stores of the engine
+    max_frame_stores: usize,
+    /// The width of address
+    addr_width: isize,
+
+    // Mutable
+    /// Whether the channel is initialized
+    is_initialized: AtomicBool,
+    /// The frame stores of the channel
+    frames: IrqMutex<Vec<BufPtr>>,
+    /// The geometry of the frames
+    format: IrqMutex<Option<VdmaFrameFormat>>,
+}
+
+impl AxiVdmaChannel {
+    /// Reset time out
+    const RESET_TIMEOUT: Duration = Duration::from_millis(10);
+    /// The number of the start address registers of a channel, a frame store
+    /// takes two of them (LSB and MSB) when the address is wider than 32 bits
+    const START_ADDRESS_REGS: usize = 16;
+
+    /// Create a new channel
+    pub fn new(direction: Direaction, cfg: &AxiVdmaConfig) -> Self {
+        let (has_dre, data_width) = match direction {
+            Direaction::TX => (cfg.has_mm2s_dre, cfg.mm2s_data_width),
+            Direaction::RX => (cfg.has_s2mm_dre, cfg.s2mm_data_width),
+        };
+        Self {
+            regs: unsafe { Instance::from_base(cfg.base_address) },
+            direction,
+            has_dre,
+            data_width,
+            max_frame_stores: cfg.max_frame_stores,
+            addr_width: cfg.addr_width,
+            is_initialized: AtomicBool::new(false),
+            frames: IrqMutex::new(Vec::new()),
+            format: IrqMutex::new(None),
+        }
+    }
+
+    /// Get the registers of the AxiVDMA
+    #[inline]
+    fn hardware(&self) -> &vdma::RegisterBlock {
+        &self.regs
+    }
+
+    /// The control register of the channel
+    #[inline]
+    fn vdmacr(&self) -> &vdma::Mm2sVdmacr {
+        match self.direction {
+            Direaction::TX => self.hardware().mm2s_vdmacr(),
+            Direaction::RX => self.hardware().s2mm_vdmacr(),
+        }
+    }
+
+    /// The status register of the channel
+    #[inline]
+    fn vdmasr(&self) -> &vdma::Mm2sVdmasr {
+        match self.direction {
+            Direaction::TX => self.hardware().mm2s_vdmasr(),
+            Direaction::RX => self.hardware().s2mm_vdmasr(),
+        }
+    }
+
+    /// Reset the channel
+    pub fn reset(&self) -> AxiDMAResult {
+        let cr = self.vdmacr();
+        cr.modify(|_, w| w.reset().set_bit());
+        let mut deadline = Deadline::after(AxiVdmaChannel::RESET_TIMEOUT);
+        while cr.read().reset().bit_is_set() {
+            if deadline.expired() {
+                error!("AXIVDMA: failed reset in intialization");
+                return Err(AxiDMAErr::Timeout(self.snapshot()));
+            }
+            core::hint::spin_loop();
+        }
+        self.is_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Take a snapshot of the control and the status register of the channel,
+    /// the VDMA has no CURDESC, TAILDESC and ring
+    pub fn snapshot(&self) -> ChannelSnapshot {
+        ChannelSnapshot::registers(
+            self.vdmacr().read().bits(),
+            self.vdmasr().read().bits(),
+            0,
+            0,
+        )
+    }
+
+    /// Setup the frame stores and the geometry of the frames.
+    ///
+    /// Every frame store must stay valid until the channel is stopped.
+    pub fn configure(&self, frames: Vec<BufPtr>, format: VdmaFrameFormat) -> AxiDMAResult {
+        if !self.is_initialized.load(Ordering::Relaxed) {
+            error!("vdma::configure: Driver not initialized");
+            return Err(AxiDMAErr::NotInit);
+        }
+        if !self.is_halted() {
+            error!("vdma::configure: channel is running");
+            return Err(AxiDMAErr::DMAErr);
+        }
+        if format.hsize == 0
+            || format.hsize > VdmaFrameFormat::MAX_HSIZE
+            || format.vsize == 0
+            || format.vsize > VdmaFrameFormat::MAX_VSIZE
+            || format.stride < format.hsize
+            || format.stride > VdmaFrameFormat::MAX_HSIZE
+        {
+            error!("vdma::configure: invalid frame format {:?}", format);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let regs_per_frame = if self.addr_width > 32 { 2 } else { 1 };
+        let max_frames = self
+            .max_frame_stores
+            .min(AxiVdmaChannel::START_ADDRESS_REGS / regs_per_frame);
+        if frames.is_empty() || frames.len() > max_frames {
+            error!(
+                "vdma::configure: invalid frame store number {}, max: {}",
+                frames.len(),
+                max_frames
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let align = self.data_width / 8;
+        if !self.has_dre && ((format.hsize | format.stride) & (align - 1) != 0) {
+            error!(
+                "vdma::configure: hsize {} and stride {} must be multiple of {} without dre",
+                format.hsize, format.stride, align
+            );
+            return Err(AxiDMAErr::InValidParam);
+        }
+        let frame_len = format.frame_len();
+        for (i, frame) in frames.iter().enumerate() {
+            if frame.len() < frame_len {
+                error!(
+                    "vdma::configure: frame store {} is too small, len: {}, need: {}",
+                    i,
+                    frame.len(),
+                    frame_len
+                );
+                return Err(AxiDMAErr::InValidParam);
+            }
+            if self.addr_width <= 32 && frame.as_ptr() as u64 > u32::MAX as u64 {
+                error!(
+                    "vdma::configure: frame store {} is out of the 32-bit address space",
+                    i
+                );
+                return Err(AxiDMAErr::InValidParam);
+            }
+            if !self.has_dre && frame.as_ptr() as usize & (align - 1) != 0 {
+                error!(
+                    "vdma::configure: frame store {} is not aligned to {} without dre",
+                    i, align
+                );
+                return Err(AxiDMAErr::InValidParam);
+            }
+        }
+
+        let hw = self.hardware();
+        let (frmstore, hsize, frmdly_stride) = match self.direction {
+            Direaction::TX => (hw.mm2s_frmstore(), hw.mm2s_hsize(), hw.mm2s_frmdly_stride()),
+            Direaction::RX => (hw.s2mm_frmstore(), hw.s2mm_hsize(), hw.s2mm_frmdly_stride()),
+        };
+        unsafe {
+            frmstore.write(|w| w.num_frm_store().bits(frames.len() as _));
+            hsize.write(|w| w.horizontal_size().bits(format.hsize as _));
+            frmdly_stride.modify(|_, w| w.stride().bits(format.stride as _));
+            for (i, frame) in frames.iter().enumerate() {
+                let addr = frame.as_ptr() as u64;
+                // The LSB register is followed by the MSB one in 64-bit mode
+                for (slot, bits) in [addr as u32, (addr >> 32) as u32]
+                    .into_iter()
+                    .take(regs_per_frame)
+                    .enumerate()
+                {
+                    let reg = match self.direction {
+                        Direaction::TX => hw.mm2s_start_address(i * regs_per_frame + slot),
+                        Direaction::RX => hw.s2mm_start_address(i * regs_per_frame + slot),
+                    };
+                    reg.write(|w| w.start_address().bits(bits));
+                }
+            }
+        }
+        trace!(
+            "vdma::configure: {} frames, hsize: {}, vsize: {}, stride: {}",
+            frames.len(),
+            format.hsize,
+            format.vsize,
+            format.stride
+        );
+        *self.frames.lock() = frames;
+        *self.format.lock() = Some(format);
+        Ok(())
+    }
+
+    /// Start the channel in the given mode. Writing the vertical size
+    /// register at last starts the transfer of frames. The frame count
+    /// interrupt threshold set by [`AxiVdmaChannel::set_coalesce`] is kept.
+    pub fn start(&self, mode: VdmaMode) -> AxiDMAResult {
+        let format = match *self.format.lock() {
+            Some(format) => format,
+            None => {
+                error!("vdma::start: channel is not configured");
+                return Err(AxiDMAErr::NotInit);
+            }
+        };
+        self.set_mode(mode)?;
+        self.vdmacr().modify(|_, w| w.run_stop().set_bit());
+        compiler_fence(SeqCst);
+        fence(SeqCst);
+        io_fence();
+        let vsize = match self.direction {
+            Direaction::TX => self.hardware().mm2s_vsize(),
+            Direaction::RX => self.hardware().s2mm_vsize(),
+        };
+        unsafe {
+            vsize.write(|w| w.vertical_size().bits(format.vsize as _));
+        }
+        Ok(())
+    }
+
+    /// Stop the channel, it halts after the current frame.
+    pub fn stop(&self) {
+        self.vdmacr().modify(|_, w| w.run_stop().clear_bit());
+    }
+
+    /// Whether the channel is halted
+    pub fn is_halted(&self) -> bool {
+        self.vdmasr().read().halted().bit_is_set()
+    }
+
+    /// Switch between the circular mode and the park mode. It takes effect
+    /// at the next frame boundary if the channel is running.
+    pub fn set_mode(&self, mode: VdmaMode) -> AxiDMAResult {
+        match mode {
+            VdmaMode::Circular => {
+                self.vdmacr().modify(|_, w| w.circular_park().set_bit());
+            }
+            VdmaMode::Park(frame) => {
+                if frame >= self.frames.lock().len() {
+                    error!("vdma::set_mode: invalid park frame {}", frame);
+                    return Err(AxiDMAErr::InValidParam);
+                }
+                self.hardware().park_ptr_reg().modify(|_, w| unsafe {
+                    match self.direction {
+                        Direaction::TX => w.rd_frm_ptr_ref().bits(frame as _),
+                        Direaction::RX => w.wr_frm_ptr_ref().bits(frame as _),
+                    }
+                });
+                self.vdmacr().modify(|_, w| w.circular_park().clear_bit());
+            }
+        }
+        Ok(())
+    }
+
+    /// Enable or disable the genlock synchronization with the other channel
+    /// (`internal`) or with an external master.
+    pub fn set_genlock(&self, enable: bool, internal: bool) {
+        self.vdmacr()
+            .modify(|_, w| w.genlock_en().bit(enable).genlock_src().bit(internal));
+    }
+
+    /// The index of the frame store which is being worked on
+    pub fn current_frame(&self) -> usize {
+        let park_ptr = self.hardware().park_ptr_reg().read();
+        match self.direction {
+            Direaction::TX => park_ptr.rd_frm_store().bits() as _,
+            Direaction::RX => park_ptr.wr_frm_store().bits() as _,
+        }
+    }
+
+    /// Get the transfer of the frame store which is being worked on, it is
+    /// completed when the frame is done. The frame store stays owned by the channel.
+    pub fn next_frame(self: &Arc<Self>) -> Result<Transfer<AxiVdmaChannel>, AxiDMAErr> {
+        if self.is_halted() {
+            error!("vdma::next_frame: channel is halted");
+            return Err(AxiDMAErr::DMAErr);
+        }
+        let frame = self
+            .frames
+            .lock()
+            .get(self.current_frame())
+            .cloned()
+            .ok_or(AxiDMAErr::DMAErr)?;
+        Ok(Transfer::borrowed(frame, self.clone()))
+    }
+
+    /// Set interrupt coalescing parameters, the frame count interrupt is raised
+    /// after `threshold` frames. The valid threshold is 1 ~ 255
+    pub fn set_coalesce(&self, threshold: usize) -> AxiDMAResult {
+        if threshold == 0 || threshold > 0xff {
+            error!("invalid coalescing threshold {}", threshold);
+            return Err(AxiDMAErr::InValidParam);
+        }
+        self.vdmacr()
+            .modify(|_, w| unsafe { w.irq_frame_count().bits(threshold as _) });
+        Ok(())
+    }
+
+    /// Get the frame count interrupt threshold
+    pub fn get_coalesce(&self) -> usize {
+        self.vdmacr().read().irq_frame_count().bits() as _
+    }
+
+    /// Wait the channel completing a frame synchronously.
+    pub fn wait(&self) {
+        while !self.check_cmplt() && !self.is_halted() {}
+    }
+
+    /// Disable the interrupt of this channel.
+    pub fn intr_disable(&self) {
+        trace!("vdma intr_disable");
+        self.vdmacr().modify(|_, w| {
+            w.frm_cnt_irq_en()
+                .clear_bit()
+                .dly_cnt_irq_en()
+                .clear_bit()
+                .err_irq_en()
+                .clear_bit()
+        });
+    }
+
+    /// Enable the interrupt of this channel.
+    pub fn intr_enable(&self) {
+        trace!("vdma intr_enable");
+        self.vdmacr().modify(|_, w| {
+            w.frm_cnt_irq_en()
+                .set_bit()
+                .dly_cnt_irq_en()
+                .set_bit()
+                .err_irq_en()
+                .set_bit()
+        });
+    }
+
+    /// Check whether a frame is completed
+    pub fn check_cmplt(&self) -> bool {
+        let status = self.vdmasr().read();
+        status.err_irq().bit_is_set()
+            || status.dly_cnt_irq().bit_is_set()
+            || status.frm_cnt_irq().bit_is_set()
+    }
+
+    /// The interrupt handler
+    pub fn intr_handler(&self) -> AxiDMAResult {
+        let sr = self.vdmasr();
+        let status = sr.read();
+        if status.err_irq().bit_is_set() {
+            trace!("vdma_intr: err intr detected");
+            self.dump_regs();
+            // The error bits of the S2MM channel are cleared by writing 1.
+            sr.write(|w| unsafe { w.bits(status.bits()) });
+            return Err(AxiDMAErr::IntrErr);
+        }
+        if status.frm_cnt_irq().bit_is_set() {
+            trace!("vdma_intr: frame intr detected");
+            sr.modify(|_, w| w.frm_cnt_irq().set_bit());
+        }
+        if status.dly_cnt_irq().bit_is_set() {
+            trace!("vdma_intr: dly intr detected");
+            sr.modify(|_, w| w.dly_cnt_irq().set_bit());
+        }
+        Ok(())
+    }
+
+    /// Dump the register of the channel
+    pub fn dump_regs(&self) {
+        let hw = self.hardware();
+        info!(
+            "CR: 0b{:b}, SR: 0b{:b}, PARK_PTR: 0x{:x}",
+            self.vdmacr().read().bits(),
+            self.vdmasr().read().bits(),
+            hw.park_ptr_reg().read().bits()
+        );
+        let (vsize, hsize, frmdly_stride) = match self.direction {
+            Direaction::TX => (hw.mm2s_vsize(), hw.mm2s_hsize(), hw.mm2s_frmdly_stride()),
+            Direaction::RX => (hw.s2mm_vsize(), hw.s2mm_hsize(), hw.s2mm_frmdly_stride()),
+        };
+        info!(
+            "VSIZE: {}, HSIZE: {}, FRMDLY_STRIDE: 0x{:x}",
+            vsize.read().bits(),
+            hsize.read().bits(),
+            frmdly_stride.read().bits()
+        );
+    }
+}
+
+impl TransferChannel for AxiVdmaChannel {
+    fn wait(&self) {
+        AxiVdmaChannel::wait(self)
+    }
+
+    fn from_hw(&self) -> Result<usize, AxiDMAErr> {
+        // There is no BD to retrieve, the frame stores stay with the channel.
+        Ok(0)
+    }
+
+    fn intr_handler(&self) -> AxiDMAResult {
+        AxiVdmaChannel::intr_handler(self)
+    }
+}
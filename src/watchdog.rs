@@ -0,0 +1,145 @@
+//! The stall watchdog of an AxiDMA channel.
+//!
+//! A channel may get stuck with work pending, e.g. an S2MM channel whose
+//! stream source never asserts `tlast` stays "not idle" with CURDESC frozen.
+//! `AxiDma::watchdog_check` is called periodically, e.g. from a timer. For
+//! every channel with its watchdog on, it samples CURDESC, the head of the
+//! ring and the number of BDs in the hardware group, and reports a stall when none of them has changed during
+//! the window while the channel is running, not idle and has BDs pending.
+//!
+//! The reset of a channel resets the whole AxiDMA, so the recovery is done by
+//! `AxiDma::recover` for both channels at once: a single reset, then DMACR of
+//! both channels is restored and their rings are fixed up.
+//!
+
+use crate::channel::ChannelSnapshot;
+use crate::clock::Deadline;
+use core::time::Duration;
+
+/// What to do when a stall is detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StallRecovery {
+    /// Only report the stall
+    None,
+    /// Reset the channel and submit the packets of the hardware group again
+    ResetResubmit,
+    /// Reset the channel and abort all the pending packets
+    FailPending,
+}
+
+/// The configuration of the watchdog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchdogConfig {
+    /// The time without any progress after which the channel is stalled
+    pub window: Duration,
+    /// The recovery applied to a stalled channel
+    pub recovery: StallRecovery,
+}
+
+/// The report of a stalled channel
+#[derive(Debug, Clone, Copy)]
+pub struct StallReport {
+    /// The state of the channel when the stall was detected
+    pub snapshot: ChannelSnapshot,
+    /// The recovery applied
+    pub recovery: StallRecovery,
+}
+
+/// The progress of a channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Sample {
+    pub curdesc: u64,
+    pub bd_head: usize,
+    pub submit_cnt: usize,
+}
+
+/// The state of the watchdog
+pub(crate) struct Watchdog {
+    pub config: WatchdogConfig,
+    /// The last sample with a progress
+    last: Option<Sample>,
+    /// The end of the window started by the last progress
+    deadline: Deadline,
+}
+
+impl Watchdog {
+    pub fn new(config: WatchdogConfig) -> Self {
+        Self {
+            config,
+            last: None,
+            deadline: Deadline::after(config.window),
+        }
+    }
+
+    /// Take a new sample and return whether the channel is stalled. `busy`
+    /// tells whether the channel has work pending in the hardware. A stall is
+    /// reported once, the next one needs a full window again.
+    pub fn observe(&mut self, sample: Sample, busy: bool) -> bool {
+        if !busy || self.last != Some(sample) {
+            self.last = Some(sample);
+            self.deadline = Deadline::after(self.config.window);
+            return false;
+        }
+        if !self.deadline.expired() {
+            return false;
+        }
+        self.last = None;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // No clock is installed by the tests, a window of 3 us lasts 3 polls
+
+    fn watchdog() -> Watchdog {
+        Watchdog::new(WatchdogConfig {
+            window: Duration::from_micros(3),
+            recovery: StallRecovery::None,
+        })
+    }
+
+    fn sample(curdesc: u64) -> Sample {
+        Sample {
+            curdesc,
+            bd_head: 0,
+            submit_cnt: 1,
+        }
+    }
+
+    /// The number of unchanged observations until a stall is reported
+    fn observations_until_stall(watchdog: &mut Watchdog, sample: Sample) -> usize {
+        (1..10).find(|_| watchdog.observe(sample, true)).unwrap()
+    }
+
+    #[test]
+    fn stall_needs_a_full_window_without_progress() {
+        let mut watchdog = watchdog();
+        assert!(!watchdog.observe(sample(0x100), true));
+        assert_eq!(observations_until_stall(&mut watchdog, sample(0x100)), 4);
+        // A stall is reported once, the next one needs a full window again
+        assert!(!watchdog.observe(sample(0x100), true));
+        assert_eq!(observations_until_stall(&mut watchdog, sample(0x100)), 4);
+    }
+
+    #[test]
+    fn progress_restarts_the_window() {
+        let mut watchdog = watchdog();
+        assert!(!watchdog.observe(sample(0x100), true));
+        for _ in 0..3 {
+            assert!(!watchdog.observe(sample(0x100), true));
+        }
+        assert!(!watchdog.observe(sample(0x140), true));
+        assert_eq!(observations_until_stall(&mut watchdog, sample(0x140)), 4);
+    }
+
+    #[test]
+    fn idle_channel_never_stalls() {
+        let mut watchdog = watchdog();
+        for _ in 0..10 {
+            assert!(!watchdog.observe(sample(0x100), false));
+        }
+    }
+}
@@ -0,0 +1,100 @@
+#ifndef XPARAMETERS_H   /* prevent circular inclusions */
+#define XPARAMETERS_H   /* by using protection macros */
+
+/* Definition for CPU ID */
+#define XPAR_CPU_ID 0U
+
+/******************************************************************/
+
+/* Definitions for driver AXIDMA */
+#define XPAR_XAXIDMA_NUM_INSTANCES 2
+
+/* Definitions for peripheral AXI_DMA_0 */
+#define XPAR_AXI_DMA_0_DEVICE_ID 0
+#define XPAR_AXI_DMA_0_BASEADDR 0x40400000
+#define XPAR_AXI_DMA_0_HIGHADDR 0x4040FFFF
+#define XPAR_AXI_DMA_0_SG_INCLUDE_STSCNTRL_STRM 1
+#define XPAR_AXI_DMA_0_INCLUDE_MM2S_DRE 1
+#define XPAR_AXI_DMA_0_INCLUDE_S2MM_DRE 1
+#define XPAR_AXI_DMA_0_INCLUDE_MM2S 1
+#define XPAR_AXI_DMA_0_INCLUDE_S2MM 1
+#define XPAR_AXI_DMA_0_M_AXI_MM2S_DATA_WIDTH 32
+#define XPAR_AXI_DMA_0_M_AXI_S2MM_DATA_WIDTH 32
+#define XPAR_AXI_DMA_0_INCLUDE_SG 1
+#define XPAR_AXI_DMA_0_ENABLE_MULTI_CHANNEL 0
+#define XPAR_AXI_DMA_0_NUM_MM2S_CHANNELS 1
+#define XPAR_AXI_DMA_0_NUM_S2MM_CHANNELS 1
+#define XPAR_AXI_DMA_0_MM2S_BURST_SIZE 16
+#define XPAR_AXI_DMA_0_S2MM_BURST_SIZE 16
+#define XPAR_AXI_DMA_0_MICRO_DMA 0
+#define XPAR_AXI_DMA_0_c_addr_width 32
+#define XPAR_AXI_DMA_0_c_sg_length_width 23
+
+
+/* Definitions for peripheral AXI_DMA_1 */
+#define XPAR_AXI_DMA_1_DEVICE_ID 1
+#define XPAR_AXI_DMA_1_BASEADDR 0xA0010000
+#define XPAR_AXI_DMA_1_HIGHADDR 0xA001FFFF
+#define XPAR_AXI_DMA_1_SG_INCLUDE_STSCNTRL_STRM 0
+#define XPAR_AXI_DMA_1_INCLUDE_MM2S_DRE 0
+#define XPAR_AXI_DMA_1_INCLUDE_S2MM_DRE 0
+#define XPAR_AXI_DMA_1_INCLUDE_MM2S 0
+#define XPAR_AXI_DMA_1_INCLUDE_S2MM 1
+#define XPAR_AXI_DMA_1_M_AXI_MM2S_DATA_WIDTH 32
+#define XPAR_AXI_DMA_1_M_AXI_S2MM_DATA_WIDTH 128
+#define XPAR_AXI_DMA_1_INCLUDE_SG 1
+#define XPAR_AXI_DMA_1_ENABLE_MULTI_CHANNEL 0
+#define XPAR_AXI_DMA_1_NUM_MM2S_CHANNELS 1
+#define XPAR_AXI_DMA_1_NUM_S2MM_CHANNELS 1
+#define XPAR_AXI_DMA_1_MM2S_BURST_SIZE 16
+#define XPAR_AXI_DMA_1_S2MM_BURST_SIZE 64
+#define XPAR_AXI_DMA_1_MICRO_DMA 0
+#define XPAR_AXI_DMA_1_c_addr_width 64
+#define XPAR_AXI_DMA_1_c_sg_length_width 26
+
+
+/******************************************************************/
+
+/* Canonical definitions for peripheral AXI_DMA_0 */
+#define XPAR_AXIDMA_0_DEVICE_ID XPAR_AXI_DMA_0_DEVICE_ID
+#define XPAR_AXIDMA_0_BASEADDR 0x40400000
+#define XPAR_AXIDMA_0_SG_INCLUDE_STSCNTRL_STRM 1
+#define XPAR_AXIDMA_0_INCLUDE_MM2S 1
+#define XPAR_AXIDMA_0_INCLUDE_MM2S_DRE 1
+#define XPAR_AXIDMA_0_M_AXI_MM2S_DATA_WIDTH 32
+#define XPAR_AXIDMA_0_INCLUDE_S2MM 1
+#define XPAR_AXIDMA_0_INCLUDE_S2MM_DRE 1
+#define XPAR_AXIDMA_0_M_AXI_S2MM_DATA_WIDTH 32
+#define XPAR_AXIDMA_0_INCLUDE_SG 1
+#define XPAR_AXIDMA_0_ENABLE_MULTI_CHANNEL 0
+#define XPAR_AXIDMA_0_NUM_MM2S_CHANNELS 1
+#define XPAR_AXIDMA_0_NUM_S2MM_CHANNELS 1
+#define XPAR_AXIDMA_0_MM2S_BURST_SIZE 16
+#define XPAR_AXIDMA_0_S2MM_BURST_SIZE 16
+#define XPAR_AXIDMA_0_MICRO_DMA 0
+#define XPAR_AXIDMA_0_c_addr_width 32
+#define XPAR_AXIDMA_0_c_sg_length_width 23
+
+/* Canonical definitions for peripheral AXI_DMA_1 */
+#define XPAR_AXIDMA_1_DEVICE_ID XPAR_AXI_DMA_1_DEVICE_ID
+#define XPAR_AXIDMA_1_BASEADDR 0xA0010000U
+#define XPAR_AXIDMA_1_SG_INCLUDE_STSCNTRL_STRM 0
+#define XPAR_AXIDMA_1_INCLUDE_MM2S 0
+#define XPAR_AXIDMA_1_INCLUDE_MM2S_DRE 0
+#define XPAR_AXIDMA_1_M_AXI_MM2S_DATA_WIDTH 32
+#define XPAR_AXIDMA_1_INCLUDE_S2MM 1
+#define XPAR_AXIDMA_1_INCLUDE_S2MM_DRE 0
+#define XPAR_AXIDMA_1_M_AXI_S2MM_DATA_WIDTH XPAR_AXI_DMA_1_M_AXI_S2MM_DATA_WIDTH
+#define XPAR_AXIDMA_1_INCLUDE_SG 1
+#define XPAR_AXIDMA_1_ENABLE_MULTI_CHANNEL 0
+#define XPAR_AXIDMA_1_NUM_MM2S_CHANNELS 1
+#define XPAR_AXIDMA_1_NUM_S2MM_CHANNELS 1
+#define XPAR_AXIDMA_1_MM2S_BURST_SIZE 16
+#define XPAR_AXIDMA_1_S2MM_BURST_SIZE 64
+#define XPAR_AXIDMA_1_MICRO_DMA 0
+#define XPAR_AXIDMA_1_c_addr_width 64
+#define XPAR_AXIDMA_1_c_sg_length_width 26
+
+/******************************************************************/
+
+#endif  /* end of protection macro */
@@ -0,0 +1,106 @@
+//! Tests of the `xparameters.h` parser of `build.rs`
+
+#[path = "../src/limits.rs"]
+#[allow(dead_code)]
+mod limits;
+#[path = "../build/xparameters.rs"]
+mod xparameters;
+
+const HEADER: &str = include_str!("fixtures/xparameters.h");
+
+/// The generated configuration of the instance `id`
+fn instance(out: &str, id: usize) -> &str {
+    let start = out
+        .find(&format!("XPAR_AXIDMA_{}_CONFIG: ", id))
+        .expect("instance not generated");
+    let len = out[start..].find("});").unwrap();
+    &out[start..start + len]
+}
+
+/// The header without the canonical define of `param` of the instance 0
+fn without(param: &str) -> String {
+    let define = format!("#define XPAR_AXIDMA_0_{} ", param);
+    HEADER
+        .lines()
+        .filter(|line| !line.starts_with(&define))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn generates_every_instance() {
+    let out = xparameters::generate(HEADER);
+    assert!(out.contains("[ValidAxiDmaConfig; 2] = [XPAR_AXIDMA_0_CONFIG, XPAR_AXIDMA_1_CONFIG]"));
+
+    let dma0 = instance(&out, 0);
+    assert!(dma0.contains("base_address: 0x40400000,"));
+    assert!(dma0.contains("has_sts_cntrl_strm: true,"));
+    assert!(dma0.contains("has_mm2s_dre: true,"));
+    assert!(dma0.contains("rx_channel_offset: 0x30,"));
+    assert!(dma0.contains("tx_channel_offset: 0x0,"));
+    assert!(dma0.contains("sg_length_width: 23,"));
+    assert!(dma0.contains("addr_width: 32,"));
+}
+
+#[test]
+fn reads_lower_case_suffixes_and_indirect_defines() {
+    let dma1 = xparameters::generate(HEADER);
+    let dma1 = instance(&dma1, 1);
+    assert!(dma1.contains("base_address: 0xa0010000,"));
+    assert!(dma1.contains("has_mm2s: false,"));
+    assert!(dma1.contains("s2mm_data_width: 128,"));
+    assert!(dma1.contains("s2mm_burst_size: 64,"));
+    assert!(dma1.contains("sg_length_width: 26,"));
+    assert!(dma1.contains("addr_width: 64,"));
+}
+
+#[test]
+fn reads_the_channel_offsets() {
+    let header = HEADER.replace(
+        "#define XPAR_AXIDMA_0_c_addr_width",
+        "#define XPAR_AXIDMA_0_RX_CHANNEL_OFFSET 0x100\n#define XPAR_AXIDMA_0_c_addr_width",
+    );
+    let out = xparameters::generate(&header);
+    assert!(instance(&out, 0).contains("rx_channel_offset: 0x100,"));
+}
+
+#[test]
+#[should_panic(expected = "XPAR_AXIDMA_0_c_addr_width is missing")]
+fn missing_addr_width_breaks_the_build() {
+    xparameters::generate(&without("c_addr_width"));
+}
+
+#[test]
+#[should_panic(expected = "XPAR_AXIDMA_0_c_sg_length_width is missing")]
+fn missing_sg_length_width_breaks_the_build() {
+    xparameters::generate(&without("c_sg_length_width"));
+}
+
+#[test]
+#[should_panic(expected = "XPAR_AXIDMA_0_S2MM_BURST_SIZE is missing")]
+fn missing_burst_size_breaks_the_build() {
+    xparameters::generate(&without("S2MM_BURST_SIZE"));
+}
+
+#[test]
+#[should_panic(expected = "XPAR_AXIDMA_0 is invalid")]
+fn invalid_parameter_breaks_the_build() {
+    xparameters::generate(&HEADER.replace(
+        "XPAR_AXIDMA_0_MM2S_BURST_SIZE 16",
+        "XPAR_AXIDMA_0_MM2S_BURST_SIZE 512",
+    ));
+}
+
+#[test]
+#[should_panic(expected = "no XPAR_AXIDMA_0_* instance")]
+fn header_without_instance_breaks_the_build() {
+    xparameters::generate("#define XPAR_CPU_ID 0U\n");
+}
+
+#[test]
+#[should_panic(expected = "the driver needs the SG mode")]
+fn simple_mode_breaks_the_build() {
+    xparameters::generate(
+        &HEADER.replace("XPAR_AXIDMA_0_INCLUDE_SG 1", "XPAR_AXIDMA_0_INCLUDE_SG 0"),
+    );
+}